@@ -0,0 +1,148 @@
+//! # Build Script
+//!
+//! Lists the GraphQL operations in `src/graphql/schema/` for the persisted query
+//! manifest, and embeds the configuration file and fixtures.
+//!
+//! Three files are written to `OUT_DIR`:
+//!
+//! * `persisted_queries.rs` - The document of each operation, hashed by the client.
+//! * `config.json` - `config.toml` converted to JSON, or `{}` when there is none.
+//! * `fixtures.rs` - The recorded responses in `fixtures/`, by operation name.
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Directory containing the GraphQL operations
+const SCHEMA_DIR: &str = "src/graphql/schema";
+
+/// The schema itself, which is not an operation
+const SCHEMA_FILE: &str = "schema.graphql";
+
+/// The configuration file
+const CONFIG_FILE: &str = "config.toml";
+
+/// Directory containing the recorded responses
+const FIXTURES_DIR: &str = "fixtures";
+
+fn main() -> io::Result<()> {
+    println!("cargo:rerun-if-changed={SCHEMA_DIR}");
+    println!("cargo:rerun-if-changed={CONFIG_FILE}");
+    println!("cargo:rerun-if-changed={FIXTURES_DIR}");
+
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").expect("set by cargo"));
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("set by cargo"));
+
+    let mut operations: Vec<PathBuf> = Vec::new();
+    find_operations(&manifest_dir.join(SCHEMA_DIR), &mut operations)?;
+    operations.sort();
+
+    let mut entries: String = String::new();
+    for path in operations {
+        entries.push_str(&format!("    include_str!({:?}),\n", path.display()));
+    }
+
+    fs::write(
+        out_dir.join("persisted_queries.rs"),
+        format!(
+            "/// The document of every operation in `{SCHEMA_DIR}`\n\
+             pub const PERSISTED_QUERIES: &[&str] = &[\n{entries}];\n"
+        ),
+    )?;
+
+    fs::write(
+        out_dir.join("config.json"),
+        read_config(&manifest_dir.join(CONFIG_FILE))?,
+    )?;
+
+    fs::write(
+        out_dir.join("fixtures.rs"),
+        fixture_entries(&manifest_dir.join(FIXTURES_DIR))?,
+    )?;
+
+    Ok(())
+}
+
+/// Lists the recorded responses as a Rust constant.
+///
+/// **Arguments**
+///
+/// * `dir` - The fixtures directory.
+///
+/// **Returns**
+///
+/// * `io::Result<String>` - The `FIXTURES` constant.
+fn fixture_entries(dir: &Path) -> io::Result<String> {
+    let mut fixtures: Vec<PathBuf> = Vec::new();
+    if dir.exists() {
+        for entry in fs::read_dir(dir)? {
+            let path: PathBuf = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                fixtures.push(path);
+            }
+        }
+    }
+    fixtures.sort();
+
+    let mut entries: String = String::new();
+    for path in fixtures {
+        let Some(operation_name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        entries.push_str(&format!(
+            "    ({operation_name:?}, include_str!({:?})),\n",
+            path.display()
+        ));
+    }
+
+    Ok(format!(
+        "/// The operation name and recorded response of every fixture in `{FIXTURES_DIR}/`\n\
+         pub const FIXTURES: &[(&str, &str)] = &[\n{entries}];\n"
+    ))
+}
+
+/// Reads the configuration file as JSON.
+///
+/// Only the TOML syntax is checked here. The structure and values are validated
+/// by `AppConfig` when the app starts.
+///
+/// **Arguments**
+///
+/// * `path` - The path to `config.toml`.
+///
+/// **Returns**
+///
+/// * `io::Result<String>` - The configuration as JSON.
+fn read_config(path: &Path) -> io::Result<String> {
+    if !path.exists() {
+        return Ok("{}".to_string());
+    }
+
+    let config: toml::Table = fs::read_to_string(path)?.parse().map_err(|err| {
+        io::Error::new(io::ErrorKind::InvalidData, format!("{CONFIG_FILE}: {err}"))
+    })?;
+
+    Ok(serde_json::to_string(&config)?)
+}
+
+/// Finds every `.graphql` operation file in a directory, recursively.
+///
+/// **Arguments**
+///
+/// * `dir` - The directory to search.
+/// * `operations` - The list to add the operation files to.
+fn find_operations(dir: &Path, operations: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path: PathBuf = entry?.path();
+        if path.is_dir() {
+            find_operations(&path, operations)?;
+        } else if path.extension().is_some_and(|ext| ext == "graphql")
+            && path.file_name().is_some_and(|name| name != SCHEMA_FILE)
+        {
+            operations.push(path);
+        }
+    }
+
+    Ok(())
+}
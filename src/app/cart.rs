@@ -0,0 +1,975 @@
+use dioxus::prelude::*;
+use futures::{channel::mpsc, StreamExt};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+// Modules
+use crate::{
+    app::{
+        error::AppError,
+        state::{State, CART_KEY, SESSION_TOKEN_KEY, STATE},
+    },
+    controllers::{
+        cart::CartController, checkout::CheckoutController, shipping::ShippingController,
+    },
+    graphql::{cache::QueryCache, jwt, models::cart::cart_query, policy::sleep},
+    models::{
+        address::Address,
+        cart::{Cart, CartLine},
+    },
+};
+
+/// A change another tab made to local storage: the key, the old value and the new value
+type StorageChange = (Option<String>, Option<String>, Option<String>);
+
+/// How long a removed line can be restored
+const UNDO_WINDOW: Duration = Duration::from_secs(10);
+
+/// # Confirmed Cart
+///
+/// The cart as last returned by the backend, without the pending line edits.
+static CONFIRMED_CART: GlobalSignal<Cart> = Global::new(|| STATE.peek().cart.clone());
+
+/// # Pending Line Edits
+///
+/// The quantities set by the shopper that the backend hasn't confirmed yet. The
+/// cart state shows them over the confirmed cart.
+static PENDING_EDITS: GlobalSignal<EditQueue> = Global::new(EditQueue::default);
+
+/// # Line Errors
+///
+/// The error of the last edit of each cart line that was rolled back, by key.
+static LINE_ERRORS: GlobalSignal<HashMap<String, String>> = Global::new(HashMap::new);
+
+/// # Removed Lines
+///
+/// The lines removed by the shopper that can still be restored, oldest first.
+static REMOVED_LINES: GlobalSignal<Vec<RemovedLine>> = Global::new(Vec::new);
+
+/// The ID of the next removal, so an expired undo window doesn't drop a later removal of the same line
+static NEXT_REMOVAL: AtomicU64 = AtomicU64::new(0);
+
+/// A quantity set by the shopper, waiting to be confirmed
+#[derive(Clone, Debug, Default, PartialEq)]
+struct PendingEdit {
+    /// The latest quantity set, zero to remove the line
+    quantity: i64,
+    /// Whether a mutation for the line is in flight
+    sending: bool,
+    /// The line being restored, shown until the backend has restored it
+    restoring: Option<CartLine>,
+}
+
+/// The pending line edits, by cart line key
+///
+/// Each line with edits has a task sending them. Dropping the edits starts a new
+/// generation, so the tasks still sending the dropped edits stop without
+/// touching the edits queued since.
+#[derive(Clone, Debug, Default)]
+struct EditQueue {
+    /// The edits, by cart line key
+    edits: HashMap<String, PendingEdit>,
+    /// The generation of the edits
+    generation: u64,
+}
+
+/// What a task sending a line's edits does once the backend has answered
+#[derive(Debug, PartialEq)]
+enum SendOutcome {
+    /// The edits were dropped, so the answer is ignored
+    Dropped,
+    /// A newer quantity was set meanwhile, and is sent next
+    Resend,
+    /// The latest quantity was confirmed
+    Confirmed,
+    /// The latest quantity was rejected, so the line is rolled back
+    RolledBack(Box<PendingEdit>),
+}
+
+impl EditQueue {
+    /// Sets the quantity of a line.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    /// * `quantity` - The quantity, which removes the line when zero.
+    /// * `restoring` - The line, when it is being restored.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<u64>` - The generation to start a task sending the line's edits
+    ///   with, or `None` if a task is already sending them.
+    fn queue(&mut self, key: &str, quantity: i64, restoring: Option<CartLine>) -> Option<u64> {
+        let edit: &mut PendingEdit = self.edits.entry(key.to_string()).or_default();
+        edit.quantity = quantity.max(0);
+        if restoring.is_some() {
+            edit.restoring = restoring;
+        }
+        (!std::mem::replace(&mut edit.sending, true)).then_some(self.generation)
+    }
+
+    /// Gets the quantity to send for a line.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    /// * `generation` - The generation of the sending task.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<i64>` - The latest quantity, or `None` if the edits were dropped.
+    fn next(&self, key: &str, generation: u64) -> Option<i64> {
+        if generation != self.generation {
+            return None;
+        }
+        self.edits.get(key).map(|edit| edit.quantity)
+    }
+
+    /// Handles the backend's confirmation of a line's edit.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    /// * `generation` - The generation of the sending task.
+    /// * `sent` - The quantity sent, or `None` for a restore, which brings the
+    ///   line back with its old quantity.
+    /// * `returned` - The line's quantity in the returned cart.
+    ///
+    /// **Returns**
+    ///
+    /// * `SendOutcome` - `Confirmed` when the latest quantity was confirmed.
+    fn confirm(
+        &mut self,
+        key: &str,
+        generation: u64,
+        sent: Option<i64>,
+        returned: i64,
+    ) -> SendOutcome {
+        if generation != self.generation {
+            return SendOutcome::Dropped;
+        }
+        match self.edits.get(key) {
+            None => SendOutcome::Dropped,
+            Some(edit) if edit.quantity != returned && Some(edit.quantity) != sent => {
+                SendOutcome::Resend
+            }
+            Some(_) => {
+                self.edits.remove(key);
+                SendOutcome::Confirmed
+            }
+        }
+    }
+
+    /// Handles the backend's rejection of a line's edit.
+    ///
+    /// Only the rejected quantity is dropped: a newer quantity set meanwhile is
+    /// still sent.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    /// * `generation` - The generation of the sending task.
+    /// * `sent` - The quantity sent.
+    ///
+    /// **Returns**
+    ///
+    /// * `SendOutcome` - `RolledBack` with the dropped edit when it was the latest.
+    fn fail(&mut self, key: &str, generation: u64, sent: i64) -> SendOutcome {
+        if generation != self.generation {
+            return SendOutcome::Dropped;
+        }
+        match self.edits.get(key) {
+            None => SendOutcome::Dropped,
+            Some(edit) if edit.quantity != sent => SendOutcome::Resend,
+            Some(_) => self.edits.remove(key).map_or(SendOutcome::Dropped, |edit| {
+                SendOutcome::RolledBack(Box::new(edit))
+            }),
+        }
+    }
+
+    /// Drops every edit, stopping the tasks sending them.
+    fn clear(&mut self) {
+        self.edits.clear();
+        self.generation += 1;
+    }
+}
+
+/// A line removed by the shopper, as it was before the removal
+#[derive(Clone, Debug)]
+struct RemovedLine {
+    /// The line
+    line: CartLine,
+    /// The ID of the removal
+    removal: u64,
+}
+
+/// # Cart Service
+///
+/// Changes the shopper's cart, and is the only writer of the cart state.
+///
+/// Each change applies the cart returned by its mutation, so the cart isn't
+/// refetched. Every component using the service gets its own loading and error
+/// state, for the changes it makes.
+///
+/// Line edits are optimistic: the cart state changes at once, and each line's
+/// mutations are queued, one in flight at a time, so rapid edits are sent in
+/// order and the last one wins. The queue outlives the component that started
+/// it. When the backend rejects the latest edit, the line is rolled back to
+/// the confirmed cart and the error is kept for the line; a rejected edit that
+/// was already replaced is dropped for the newer one. The totals are updated
+/// when the backend confirms.
+///
+/// Removed lines can be restored for a while, with `restoreCartItems`.
+///
+/// The cart follows the changes made in the shopper's other tabs.
+#[derive(Clone, Copy, PartialEq)]
+pub struct CartService {
+    /// Whether a change is in progress
+    loading: Signal<bool>,
+    /// The message of the last change's error
+    error: Signal<Option<String>>,
+}
+
+/// Gets the cart service, as a hook.
+///
+/// **Returns**
+///
+/// * `CartService` - The service, which keeps its loading and error state across renders.
+pub fn use_cart() -> CartService {
+    CartService {
+        loading: use_signal(|| false),
+        error: use_signal(|| None),
+    }
+}
+
+impl CartService {
+    /// Checks whether a change is in progress.
+    ///
+    /// **Returns**
+    ///
+    /// * `bool` - Whether a change is in progress.
+    pub fn is_loading(&self) -> bool {
+        *self.loading.read()
+    }
+
+    /// Gets the error of the last change.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<String>` - The error message, if the last change failed.
+    pub fn error(&self) -> Option<String> {
+        self.error.read().clone()
+    }
+
+    /// Adds a product to the cart.
+    ///
+    /// **Arguments**
+    ///
+    /// * `product_id` - The ID of the product to add.
+    /// * `quantity` - The quantity to add.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the product was added.
+    pub async fn add(self, product_id: i64, quantity: i64) -> Result<(), AppError> {
+        self.apply(CartController::new().add_to_cart(product_id, quantity))
+            .await
+    }
+
+    /// Applies a coupon to the cart.
+    ///
+    /// **Arguments**
+    ///
+    /// * `code` - The coupon code.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the coupon was applied.
+    pub async fn apply_coupon(self, code: String) -> Result<(), AppError> {
+        self.apply(CartController::new().apply_coupon(code)).await
+    }
+
+    /// Removes a coupon from the cart.
+    ///
+    /// **Arguments**
+    ///
+    /// * `code` - The coupon code.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the coupon was removed.
+    pub async fn remove_coupon(self, code: String) -> Result<(), AppError> {
+        self.apply(CartController::new().remove_coupon(code)).await
+    }
+
+    /// Chooses the shipping rate of each shipping package, which changes the totals.
+    ///
+    /// **Arguments**
+    ///
+    /// * `shipping_methods` - The chosen rate ID of each shipping package.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the rates were chosen.
+    pub async fn select_shipping_method(
+        self,
+        shipping_methods: Vec<String>,
+    ) -> Result<(), AppError> {
+        self.apply(CheckoutController::new().update_shipping_method(shipping_methods))
+            .await
+    }
+
+    /// Sets the shipping destination the cart's shipping rates are calculated for.
+    ///
+    /// The customer mutation doesn't return the cart, so the cart is refetched
+    /// as part of the change.
+    ///
+    /// **Arguments**
+    ///
+    /// * `destination` - The destination, usually only a country, state and postcode.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the destination was set.
+    pub async fn update_shipping_address(self, destination: Address) -> Result<(), AppError> {
+        self.apply(async move {
+            ShippingController::new()
+                .update_shipping_address(&destination)
+                .await?;
+            CartController::new().get_cart().await
+        })
+        .await
+    }
+
+    /// Sets the quantity of a cart line, optimistically.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    /// * `quantity` - The quantity, which removes the line when zero.
+    pub fn update(&self, key: String, quantity: i64) {
+        if quantity <= 0 {
+            let removed: Option<CartLine> = STATE
+                .peek()
+                .cart
+                .lines
+                .iter()
+                .find(|line| line.key == key)
+                .cloned();
+            if let Some(line) = removed {
+                Self::keep_removed(line);
+            }
+        }
+        Self::queue(key, quantity, None);
+    }
+
+    /// Queues a quantity for a cart line, and shows it at once.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    /// * `quantity` - The quantity, which removes the line when zero.
+    /// * `restoring` - The line, when it is being restored.
+    fn queue(key: String, quantity: i64, restoring: Option<CartLine>) {
+        LINE_ERRORS.write().remove(&key);
+        let generation: Option<u64> = PENDING_EDITS.write().queue(&key, quantity, restoring);
+        Self::show();
+
+        // A line already being sent picks up the new quantity when its mutation returns
+        if let Some(generation) = generation {
+            spawn_forever(Self::send_edits(key, generation));
+        }
+    }
+
+    /// Changes the quantity of a cart line by an amount, optimistically.
+    ///
+    /// The change is made to the quantity shown, including pending edits, so
+    /// rapid clicks add up.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    /// * `change` - The amount to add, or subtract when negative.
+    pub fn adjust(&self, key: String, change: i64) {
+        let quantity: i64 = STATE
+            .peek()
+            .cart
+            .lines
+            .iter()
+            .find(|line| line.key == key)
+            .map(|line| line.quantity)
+            .unwrap_or_default();
+        self.update(key, quantity + change);
+    }
+
+    /// Removes a line from the cart, optimistically.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    pub fn remove(&self, key: String) {
+        self.update(key, 0);
+    }
+
+    /// Restores a removed line, with the quantity it had, optimistically.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    pub fn undo_remove(&self, key: &str) {
+        let removed: Option<RemovedLine> = {
+            let mut removed_lines = REMOVED_LINES.write();
+            removed_lines
+                .iter()
+                .position(|removed| removed.line.key == key)
+                .map(|index| removed_lines.remove(index))
+        };
+        if let Some(RemovedLine { line, .. }) = removed {
+            Self::queue(line.key.clone(), line.quantity, Some(line));
+        }
+    }
+
+    /// Gets the removed lines that can still be restored.
+    ///
+    /// **Returns**
+    ///
+    /// * `Vec<CartLine>` - The lines, as they were before their removal, oldest first.
+    pub fn removed_lines(&self) -> Vec<CartLine> {
+        REMOVED_LINES
+            .read()
+            .iter()
+            .map(|removed| removed.line.clone())
+            .collect()
+    }
+
+    /// Checks whether a cart line has edits the backend hasn't confirmed yet.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    ///
+    /// **Returns**
+    ///
+    /// * `bool` - Whether the line has pending edits.
+    pub fn is_pending(&self, key: &str) -> bool {
+        PENDING_EDITS.read().edits.contains_key(key)
+    }
+
+    /// Gets the error of a cart line's last edit, if it was rolled back.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<String>` - The error message.
+    pub fn line_error(&self, key: &str) -> Option<String> {
+        LINE_ERRORS.read().get(key).cloned()
+    }
+
+    /// Empties the cart.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the cart was emptied.
+    pub async fn empty(self) -> Result<(), AppError> {
+        // The pending edits are dropped, so their mutations' carts aren't applied
+        Self::drop_edits();
+        self.apply(CartController::new().empty_cart()).await
+    }
+
+    /// Restores the cart when the app starts.
+    ///
+    /// The cart saved in local storage is shown until the session's cart is
+    /// fetched, which replaces it. Without a session there is no cart to fetch.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the cart was restored.
+    pub async fn restore(self) -> Result<(), AppError> {
+        if State::get_session_token().is_none() {
+            return Ok(());
+        }
+        self.refresh().await
+    }
+
+    /// Keeps the cart in sync with the shopper's other tabs, for as long as the
+    /// app runs.
+    ///
+    /// A cart saved by another tab is shown at once. When another tab changes
+    /// the session, such as by signing in, the session's cart is refetched. A
+    /// session token another tab only renewed is left alone.
+    pub fn sync_tabs() {
+        // Storage events arrive outside the Dioxus runtime, so they are handled in a task
+        let (sender, mut changes) = mpsc::unbounded::<StorageChange>();
+        State::watch_storage(move |key, old_value, new_value| {
+            let _ = sender.unbounded_send((key, old_value, new_value));
+        });
+
+        spawn_forever(async move {
+            while let Some((key, old_value, new_value)) = changes.next().await {
+                match key.as_deref() {
+                    Some(CART_KEY) => Self::sync_cart(new_value),
+                    // A renewed token is the same session, and refetching its cart
+                    // would renew it again, echoing between the tabs
+                    Some(SESSION_TOKEN_KEY)
+                        if is_same_session(old_value.as_deref(), new_value.as_deref()) => {}
+                    Some(SESSION_TOKEN_KEY) => Self::sync_session(new_value).await,
+                    // The storage was cleared, with the session
+                    None => Self::sync_session(None).await,
+                    Some(_) => {}
+                }
+            }
+        });
+    }
+
+    /// Refetches the cart, after it was changed by something other than a cart
+    /// mutation, such as signing in.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the cart was refetched.
+    pub async fn refresh(self) -> Result<(), AppError> {
+        self.apply(CartController::new().get_cart()).await
+    }
+
+    /// Resets the cart to an empty one, and saves it to local storage.
+    ///
+    /// Used when the session's cart is replaced without a cart mutation, such
+    /// as by signing out or checking out. The pending edits, the removed lines
+    /// and the line errors are dropped with the old cart, so their mutations
+    /// aren't sent to the new session.
+    pub fn reset() {
+        Self::drop_edits();
+        Self::set_cart(Cart::default());
+    }
+
+    /// Replaces the cart state, and saves it to local storage.
+    ///
+    /// **Arguments**
+    ///
+    /// * `cart` - The new cart.
+    fn set_cart(cart: Cart) {
+        *CONFIRMED_CART.write() = cart;
+        Self::show();
+    }
+
+    /// Drops the pending line edits, the removed lines and the line errors.
+    ///
+    /// The edit queue's generation is bumped, so the tasks still sending the
+    /// dropped edits stop without applying their mutations' carts.
+    fn drop_edits() {
+        PENDING_EDITS.write().clear();
+        LINE_ERRORS.write().clear();
+        REMOVED_LINES.write().clear();
+    }
+
+    /// Shows the confirmed cart with the pending line edits in the cart state,
+    /// and saves it to local storage.
+    fn show() {
+        let mut state = STATE.write();
+        state.cart = Self::shown_cart();
+        state.save_cart();
+    }
+
+    /// Gets the confirmed cart with the pending line edits.
+    ///
+    /// **Returns**
+    ///
+    /// * `Cart` - The cart to show.
+    fn shown_cart() -> Cart {
+        let mut cart: Cart = CONFIRMED_CART.peek().clone();
+        for (key, edit) in PENDING_EDITS.peek().edits.iter() {
+            match cart.lines.iter_mut().find(|line| line.key == *key) {
+                Some(line) => line.quantity = edit.quantity,
+                None => {
+                    if let Some(line) = &edit.restoring {
+                        cart.lines.push(CartLine {
+                            quantity: edit.quantity,
+                            ..line.clone()
+                        });
+                    }
+                }
+            }
+        }
+        cart.lines.retain(|line| line.quantity > 0);
+        cart
+    }
+
+    /// Shows the cart another tab saved, without saving it again.
+    ///
+    /// **Arguments**
+    ///
+    /// * `stored` - The saved cart, or `None` if it was removed.
+    fn sync_cart(stored: Option<String>) {
+        let cart: Option<Cart> = match stored {
+            Some(json) => serde_json::from_str(&json).ok().and_then(Cart::from_stored),
+            None => Some(Cart::default()),
+        };
+        // A cart saved by another version of the app is left to the session's cart
+        let Some(cart) = cart else {
+            return;
+        };
+
+        QueryCache::invalidate(cart_query::OPERATION_NAME);
+        *CONFIRMED_CART.write() = cart;
+        STATE.write().cart = Self::shown_cart();
+    }
+
+    /// Follows a session changed by another tab, refetching its cart.
+    ///
+    /// **Arguments**
+    ///
+    /// * `token` - The new session token, or `None` if the session was cleared.
+    async fn sync_session(token: Option<String>) {
+        QueryCache::invalidate(cart_query::OPERATION_NAME);
+
+        // Without a session there is no cart, and fetching one would start a new
+        // session. The pending edits and removed lines belong to the cleared one.
+        if token.is_none() {
+            Self::drop_edits();
+            *CONFIRMED_CART.write() = Cart::default();
+            STATE.write().cart = Cart::default();
+            return;
+        }
+
+        // The pending edits are kept, and sent to the new session
+        match CartController::new().get_cart().await {
+            Ok(cart) => Self::set_cart(cart),
+            Err(e) => tracing::error!("Error refetching the cart of another tab's session: {}", e),
+        }
+    }
+
+    /// Sends the pending edits of a cart line, one mutation at a time, until the
+    /// backend has confirmed or rejected the latest quantity.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The key of the line.
+    /// * `generation` - The generation of the edits, which stops the task once
+    ///   they are dropped.
+    async fn send_edits(key: String, generation: u64) {
+        loop {
+            // The edits are dropped when the cart is emptied
+            let Some(quantity) = PENDING_EDITS.peek().next(&key, generation) else {
+                return;
+            };
+            let in_cart: bool = CONFIRMED_CART
+                .peek()
+                .lines
+                .iter()
+                .any(|line| line.key == key);
+
+            // A line missing from the confirmed cart was removed, so it is restored
+            let cart_controller = CartController::new();
+            let result: Result<Cart, AppError> = match (quantity, in_cart) {
+                (0, false) => Ok(CONFIRMED_CART.peek().clone()),
+                (0, true) => cart_controller.remove_items(vec![key.clone()]).await,
+                (_, false) => cart_controller.restore_items(vec![key.clone()]).await,
+                (_, true) => {
+                    cart_controller
+                        .update_item_quantity(key.clone(), quantity)
+                        .await
+                }
+            };
+
+            match result {
+                Ok(cart) => {
+                    let returned: i64 = cart
+                        .lines
+                        .iter()
+                        .find(|line| line.key == key)
+                        .map(|line| line.quantity)
+                        .unwrap_or_default();
+                    // A restore brings the line back with its old quantity, which
+                    // may not be the latest one
+                    let sent: Option<i64> = in_cart.then_some(quantity);
+                    let outcome: SendOutcome = PENDING_EDITS
+                        .write()
+                        .confirm(&key, generation, sent, returned);
+                    if outcome == SendOutcome::Dropped {
+                        return;
+                    }
+                    Self::set_cart(cart);
+                    if outcome == SendOutcome::Confirmed {
+                        return;
+                    }
+                }
+                Err(e) => {
+                    let outcome: SendOutcome =
+                        PENDING_EDITS.write().fail(&key, generation, quantity);
+                    let edit: Box<PendingEdit> = match outcome {
+                        SendOutcome::RolledBack(edit) => edit,
+                        // A newer quantity replaces the rejected one, and is sent instead
+                        SendOutcome::Resend => continue,
+                        _ => return,
+                    };
+
+                    // Roll the line back to the confirmed cart
+                    if in_cart {
+                        REMOVED_LINES
+                            .write()
+                            .retain(|removed| removed.line.key != key);
+                    } else if let Some(line) = edit.restoring {
+                        // The line can be restored again, such as after a network error
+                        Self::keep_removed(line);
+                    }
+                    LINE_ERRORS.write().insert(key, error_message(&e));
+                    Self::show();
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Keeps a removed line, so it can be restored until the undo window ends.
+    ///
+    /// **Arguments**
+    ///
+    /// * `line` - The line, as it was before its removal.
+    fn keep_removed(line: CartLine) {
+        let removal: u64 = NEXT_REMOVAL.fetch_add(1, Ordering::Relaxed);
+        let mut removed_lines = REMOVED_LINES.write();
+        removed_lines.retain(|removed| removed.line.key != line.key);
+        removed_lines.push(RemovedLine { line, removal });
+
+        spawn_forever(async move {
+            sleep(UNDO_WINDOW).await;
+            REMOVED_LINES
+                .write()
+                .retain(|removed| removed.removal != removal);
+        });
+    }
+
+    /// Runs a change, applying the cart it returns.
+    ///
+    /// **Arguments**
+    ///
+    /// * `change` - The change, which returns the updated cart.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the change was made.
+    async fn apply(
+        mut self,
+        change: impl Future<Output = Result<Cart, AppError>>,
+    ) -> Result<(), AppError> {
+        self.loading.set(true);
+        self.error.set(None);
+
+        let result: Result<Cart, AppError> = change.await;
+        self.loading.set(false);
+        match result {
+            Ok(cart) => {
+                Self::set_cart(cart);
+                Ok(())
+            }
+            Err(e) => {
+                self.error.set(Some(error_message(&e)));
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Checks whether two session tokens are of the same session.
+///
+/// WooGraphQL renews the session token as it is used, which keeps the session's
+/// customer ID, so tokens are compared by subject. Tokens without a readable
+/// subject are only the same session when they are the same token.
+///
+/// **Arguments**
+///
+/// * `old` - The old token, or `None` if there was no session.
+/// * `new` - The new token, or `None` if the session was cleared.
+///
+/// **Returns**
+///
+/// * `bool` - Whether both tokens are of the same session.
+fn is_same_session(old: Option<&str>, new: Option<&str>) -> bool {
+    match (old, new) {
+        (Some(old), Some(new)) => {
+            old == new
+                || jwt::subject(old).is_some_and(|subject| jwt::subject(new) == Some(subject))
+        }
+        (old, new) => old == new,
+    }
+}
+
+/// Gets the message to show the shopper for a failed cart change.
+///
+/// **Arguments**
+///
+/// * `error` - The error.
+///
+/// **Returns**
+///
+/// * `String` - The advice for the error, or its public message.
+fn error_message(error: &AppError) -> String {
+    error
+        .kind
+        .advice()
+        .map(str::to_string)
+        .unwrap_or_else(|| error.public_message.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const KEY: &str = "a1b2";
+
+    #[test]
+    fn rapid_edits_are_coalesced_into_the_latest() {
+        let mut queue: EditQueue = EditQueue::default();
+
+        let generation: u64 = queue.queue(KEY, 2, None).unwrap();
+        assert_eq!(queue.next(KEY, generation), Some(2));
+
+        // The line is being sent, so no other task is started
+        assert_eq!(queue.queue(KEY, 3, None), None);
+        assert_eq!(queue.queue(KEY, 4, None), None);
+
+        assert_eq!(
+            queue.confirm(KEY, generation, Some(2), 2),
+            SendOutcome::Resend
+        );
+        assert_eq!(queue.next(KEY, generation), Some(4));
+        assert_eq!(
+            queue.confirm(KEY, generation, Some(4), 4),
+            SendOutcome::Confirmed
+        );
+        assert_eq!(queue.next(KEY, generation), None);
+    }
+
+    #[test]
+    fn a_restored_line_is_confirmed_with_the_latest_quantity() {
+        let mut queue: EditQueue = EditQueue::default();
+        let generation: u64 = queue.queue(KEY, 3, None).unwrap();
+
+        // The restore brings the line back with its old quantity
+        assert_eq!(queue.confirm(KEY, generation, None, 1), SendOutcome::Resend);
+        assert_eq!(
+            queue.confirm(KEY, generation, Some(3), 3),
+            SendOutcome::Confirmed
+        );
+    }
+
+    #[test]
+    fn a_rejected_edit_is_rolled_back() {
+        let mut queue: EditQueue = EditQueue::default();
+        let line: CartLine = CartLine {
+            key: KEY.to_string(),
+            quantity: 2,
+            ..CartLine::default()
+        };
+
+        let generation: u64 = queue.queue(KEY, 2, Some(line.clone())).unwrap();
+
+        assert_eq!(
+            queue.fail(KEY, generation, 2),
+            SendOutcome::RolledBack(Box::new(PendingEdit {
+                quantity: 2,
+                sending: true,
+                restoring: Some(line),
+            }))
+        );
+        assert_eq!(queue.next(KEY, generation), None);
+
+        // The next edit starts a new task
+        assert_eq!(queue.queue(KEY, 1, None), Some(generation));
+    }
+
+    #[test]
+    fn a_rejected_edit_gives_way_to_a_newer_one() {
+        let mut queue: EditQueue = EditQueue::default();
+
+        let generation: u64 = queue.queue(KEY, 2, None).unwrap();
+        assert_eq!(queue.queue(KEY, 5, None), None);
+
+        assert_eq!(queue.fail(KEY, generation, 2), SendOutcome::Resend);
+        assert_eq!(queue.next(KEY, generation), Some(5));
+        assert_eq!(
+            queue.confirm(KEY, generation, Some(5), 5),
+            SendOutcome::Confirmed
+        );
+    }
+
+    #[test]
+    fn emptying_stops_the_tasks_sending_the_dropped_edits() {
+        let mut queue: EditQueue = EditQueue::default();
+
+        let dropped: u64 = queue.queue(KEY, 2, None).unwrap();
+        queue.clear();
+        assert_eq!(queue.next(KEY, dropped), None);
+
+        // An edit made after emptying the cart gets a task of its own
+        let generation: u64 = queue.queue(KEY, 5, None).unwrap();
+        assert_ne!(generation, dropped);
+
+        // The dropped edit's answers don't touch the new edit
+        assert_eq!(queue.next(KEY, dropped), None);
+        assert_eq!(
+            queue.confirm(KEY, dropped, Some(2), 2),
+            SendOutcome::Dropped
+        );
+        assert_eq!(queue.fail(KEY, dropped, 2), SendOutcome::Dropped);
+        assert_eq!(queue.next(KEY, generation), Some(5));
+        assert_eq!(
+            queue.confirm(KEY, generation, Some(5), 5),
+            SendOutcome::Confirmed
+        );
+    }
+
+    #[test]
+    fn a_reset_drops_the_pending_edits() {
+        let dom: VirtualDom = VirtualDom::new(|| rsx! {});
+        dom.in_runtime(|| {
+            let generation: u64 = PENDING_EDITS.write().queue(KEY, 2, None).unwrap();
+            LINE_ERRORS
+                .write()
+                .insert(KEY.to_string(), "Out of stock".to_string());
+            REMOVED_LINES.write().push(RemovedLine {
+                line: CartLine {
+                    key: "c3d4".to_string(),
+                    ..CartLine::default()
+                },
+                removal: 0,
+            });
+
+            // The rest of a reset uses local storage, which is only in the browser
+            CartService::drop_edits();
+
+            // The task sending the edit stops, and restoring the line does nothing
+            assert_eq!(PENDING_EDITS.read().next(KEY, generation), None);
+            assert!(LINE_ERRORS.read().is_empty());
+            assert!(REMOVED_LINES.read().is_empty());
+        });
+    }
+
+    #[test]
+    fn a_renewed_session_token_is_the_same_session() {
+        let token = |customer_id: &str, exp: i64| {
+            jwt::encode(&json!({ "exp": exp, "data": { "customer_id": customer_id } }))
+        };
+        let session: String = token("t_guest", 1_000);
+
+        assert!(is_same_session(Some(&session), Some(&session)));
+        assert!(is_same_session(
+            Some(&session),
+            Some(&token("t_guest", 2_000))
+        ));
+        assert!(is_same_session(None, None));
+
+        assert!(!is_same_session(Some(&session), Some(&token("7", 2_000))));
+        assert!(!is_same_session(None, Some(&session)));
+        assert!(!is_same_session(Some(&session), None));
+        assert!(!is_same_session(Some("opaque"), Some("other")));
+    }
+}
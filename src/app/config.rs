@@ -1,58 +1,571 @@
 use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+    sync::LazyLock,
+};
 
-/// Application configuration constants and settings
+/// # Configuration File
 ///
-/// This structure holds configuration values that can be used throughout the application.
-#[derive(Debug)]
+/// The project's `config.toml`, converted to JSON by `build.rs`.
+const CONFIG_FILE: &str = include_str!(concat!(env!("OUT_DIR"), "/config.json"));
+
+/// # Runtime Configuration Key
+///
+/// The global holding runtime overrides: `window.__WEPT_CONFIG__` in the browser,
+/// or the `__WEPT_CONFIG__` environment variable elsewhere.
+const RUNTIME_CONFIG_KEY: &str = "__WEPT_CONFIG__";
+
+/// # Fallback Backend Host
+///
+/// Only used by the `dev` profile. Every other profile must set a host.
+const FALLBACK_BACKEND_HOST: &str = "http://localhost:8080";
+
+/// # Fallback Backend Path
+const FALLBACK_BACKEND_PATH: &str = "graphql";
+
+/// # Configuration
+///
+/// The configuration is resolved once, on first use.
+static CONFIG: LazyLock<Result<AppConfig, ConfigError>> = LazyLock::new(AppConfig::load);
+
+/// Application configuration
+///
+/// Resolved from the following sources, where later sources take precedence:
+///
+/// 1. Built-in fallbacks.
+/// 2. `config.toml`, first its `[default]` table, then the selected profile's table.
+/// 3. The `BACKEND_HOST` and `BACKEND_PATH` environment variables at build time.
+/// 4. Runtime overrides in `window.__WEPT_CONFIG__`.
+///
+/// The profile is chosen by the runtime overrides, then the `WEPT_PROFILE`
+/// environment variable at build time, then `config.toml`. Otherwise it is `dev`.
+#[derive(Clone, Debug, PartialEq)]
 pub struct AppConfig {
     /// Application name from Cargo.toml
-    _name: &'static str,
+    pub name: &'static str,
     /// Application version from Cargo.toml
-    _version: &'static str,
+    pub version: &'static str,
+    /// The selected profile
+    pub profile: Profile,
+    /// The WordPress host, without a trailing slash
+    pub backend_host: String,
+    /// The path to the WordPress GraphQL API, without surrounding slashes
+    pub backend_path: String,
+}
+
+impl AppConfig {
+    /// Gets the application configuration.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<&AppConfig, &ConfigError>` - The configuration, or why it is invalid.
+    pub fn get() -> Result<&'static AppConfig, &'static ConfigError> {
+        CONFIG.as_ref()
+    }
+
+    /// Loads and validates the configuration from every source.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<AppConfig, ConfigError>` - The configuration.
+    fn load() -> Result<Self, ConfigError> {
+        let file: ConfigFile =
+            serde_json::from_str(CONFIG_FILE).map_err(|err| ConfigError::File(err.to_string()))?;
+        let build_profile: Option<Profile> =
+            option_env!("WEPT_PROFILE").map(str::parse).transpose()?;
+        let config: Self = Self::resolve(
+            file,
+            build_profile,
+            ConfigValues::build_env(),
+            RuntimeConfig::load()?,
+        )?;
+
+        tracing::info!(
+            "{} v{} using the {} profile, backend {}/{}",
+            config.name,
+            config.version,
+            config.profile,
+            config.backend_host,
+            config.backend_path
+        );
+
+        Ok(config)
+    }
+
+    /// Selects the profile and layers the sources, in order of precedence.
+    ///
+    /// **Arguments**
+    ///
+    /// * `file` - The configuration file.
+    /// * `build_profile` - The profile selected at build time.
+    /// * `build` - The values set at build time.
+    /// * `runtime` - The runtime overrides.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<AppConfig, ConfigError>` - The configuration.
+    fn resolve(
+        mut file: ConfigFile,
+        build_profile: Option<Profile>,
+        build: ConfigValues,
+        runtime: RuntimeConfig,
+    ) -> Result<Self, ConfigError> {
+        let profile: Profile = runtime
+            .profile
+            .or(build_profile)
+            .or(file.profile)
+            .unwrap_or_default();
+
+        let values: ConfigValues = ConfigValues::fallback(profile)
+            .merge(file.default)
+            .merge(file.profiles.remove(&profile).unwrap_or_default())
+            .merge(build)
+            .merge(runtime.values());
+
+        Self::validate(profile, values)
+    }
+
+    /// Validates the resolved values and normalises them.
+    ///
+    /// **Arguments**
+    ///
+    /// * `profile` - The selected profile.
+    /// * `values` - The resolved values.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<AppConfig, ConfigError>` - The configuration.
+    fn validate(profile: Profile, values: ConfigValues) -> Result<Self, ConfigError> {
+        let backend_host: String = values
+            .backend_host
+            .ok_or(ConfigError::Missing {
+                field: "backend_host",
+                profile,
+            })?
+            .trim()
+            .trim_end_matches('/')
+            .to_string();
+        let host: &str = backend_host
+            .strip_prefix("https://")
+            .or_else(|| backend_host.strip_prefix("http://"))
+            .ok_or_else(|| ConfigError::InvalidHost(backend_host.clone()))?;
+        if host.is_empty() || host.contains(char::is_whitespace) {
+            return Err(ConfigError::InvalidHost(backend_host));
+        }
+        if profile == Profile::Prod && !backend_host.starts_with("https://") {
+            return Err(ConfigError::InsecureHost(backend_host));
+        }
+
+        let backend_path: String = values
+            .backend_path
+            .ok_or(ConfigError::Missing {
+                field: "backend_path",
+                profile,
+            })?
+            .trim()
+            .trim_matches('/')
+            .to_string();
+        if backend_path.is_empty() || backend_path.contains(char::is_whitespace) {
+            return Err(ConfigError::InvalidPath(backend_path));
+        }
+
+        Ok(Self {
+            name: env!("CARGO_PKG_NAME"),
+            version: env!("CARGO_PKG_VERSION"),
+            profile,
+            backend_host,
+            backend_path,
+        })
+    }
+}
+
+/// # Profile
+///
+/// A named deployment target, with its own section in `config.toml`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Profile {
+    /// Local development
+    Dev,
+    /// Pre-release testing
+    Staging,
+    /// Production, which requires an HTTPS backend
+    Prod,
 }
 
-impl Default for AppConfig {
-    /// # Default Configuration
+impl Default for Profile {
+    /// # Default Profile
     ///
-    /// Create a default configuration instance.
+    /// `dev`, for debug and release builds alike. Deployments select their profile,
+    /// as `prod` needs a backend host that only the deployment knows.
     fn default() -> Self {
-        let config: Config = Config::default();
-        Self {
-            _name: config.name,
-            _version: config.version,
+        Self::Dev
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Profile::Dev => write!(f, "dev"),
+            Profile::Staging => write!(f, "staging"),
+            Profile::Prod => write!(f, "prod"),
+        }
+    }
+}
+
+impl FromStr for Profile {
+    type Err = ConfigError;
+
+    fn from_str(profile: &str) -> Result<Self, Self::Err> {
+        match profile {
+            "dev" => Ok(Profile::Dev),
+            "staging" => Ok(Profile::Staging),
+            "prod" => Ok(Profile::Prod),
+            _ => Err(ConfigError::UnknownProfile(profile.to_string())),
         }
     }
 }
 
-/// # Config
+/// # Configuration Values
 ///
-/// The configuration for the application.
+/// One layer of configuration. Unset values fall through to the layer below.
 #[derive(Deserialize, Default, Debug)]
-struct Config {
-    /// Application name from Cargo.toml
-    #[serde(default = "Config::name_default")]
-    name: &'static str,
-    /// Application version from Cargo.toml
-    #[serde(default = "Config::version_default")]
-    version: &'static str,
+#[serde(default, deny_unknown_fields)]
+struct ConfigValues {
+    /// The WordPress host
+    backend_host: Option<String>,
+    /// The path to the WordPress GraphQL API
+    backend_path: Option<String>,
 }
 
-impl Config {
-    /// # Default Configuration Variables
-    fn default() -> Self {
+impl ConfigValues {
+    /// # Fallback Values
+    ///
+    /// Only `dev` falls back to a local backend host.
+    fn fallback(profile: Profile) -> Self {
+        Self {
+            backend_host: (profile == Profile::Dev).then(|| FALLBACK_BACKEND_HOST.to_string()),
+            backend_path: Some(FALLBACK_BACKEND_PATH.to_string()),
+        }
+    }
+
+    /// # Build Environment Values
+    ///
+    /// The `BACKEND_HOST` and `BACKEND_PATH` environment variables at build time.
+    fn build_env() -> Self {
         Self {
-            name: Self::name_default(),
-            version: Self::version_default(),
+            backend_host: option_env!("BACKEND_HOST").map(str::to_string),
+            backend_path: option_env!("BACKEND_PATH").map(str::to_string),
         }
     }
 
-    /// # Application Name
-    fn name_default() -> &'static str {
-        env!("CARGO_PKG_NAME")
+    /// Layers values on top of these ones.
+    ///
+    /// **Arguments**
+    ///
+    /// * `overrides` - The values that take precedence.
+    ///
+    /// **Returns**
+    ///
+    /// * `ConfigValues` - The merged values.
+    fn merge(self, overrides: Self) -> Self {
+        Self {
+            backend_host: overrides.backend_host.or(self.backend_host),
+            backend_path: overrides.backend_path.or(self.backend_path),
+        }
+    }
+}
+
+/// # Configuration File
+///
+/// The structure of `config.toml`.
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+struct ConfigFile {
+    /// The profile to use when none is selected otherwise
+    profile: Option<Profile>,
+    /// Values shared by every profile
+    default: ConfigValues,
+    /// Values for each profile
+    profiles: HashMap<Profile, ConfigValues>,
+}
+
+/// # Runtime Configuration
+///
+/// Overrides injected into the page, so one build can be deployed against
+/// different backends.
+#[derive(Deserialize, Default, Debug)]
+#[serde(default, deny_unknown_fields)]
+struct RuntimeConfig {
+    /// The profile to use
+    profile: Option<Profile>,
+    /// The WordPress host
+    backend_host: Option<String>,
+    /// The path to the WordPress GraphQL API
+    backend_path: Option<String>,
+}
+
+impl RuntimeConfig {
+    /// Reads the runtime overrides from `window.__WEPT_CONFIG__`.
+    ///
+    /// The overrides may be an object, or a JSON string.
+    #[cfg(target_family = "wasm")]
+    fn load() -> Result<Self, ConfigError> {
+        use wasm_bindgen::JsValue;
+
+        let value: JsValue =
+            js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str(RUNTIME_CONFIG_KEY))
+                .unwrap_or(JsValue::UNDEFINED);
+        if value.is_undefined() || value.is_null() {
+            return Ok(Self::default());
+        }
+
+        let json: String = match value.as_string() {
+            Some(json) => json,
+            None => js_sys::JSON::stringify(&value)
+                .map(String::from)
+                .map_err(|_| ConfigError::Runtime("not serializable to JSON".to_string()))?,
+        };
+
+        serde_json::from_str(&json).map_err(|err| ConfigError::Runtime(err.to_string()))
+    }
+
+    /// Reads the runtime overrides from the `__WEPT_CONFIG__` environment variable.
+    #[cfg(not(target_family = "wasm"))]
+    fn load() -> Result<Self, ConfigError> {
+        match std::env::var(RUNTIME_CONFIG_KEY) {
+            Ok(json) => {
+                serde_json::from_str(&json).map_err(|err| ConfigError::Runtime(err.to_string()))
+            }
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// # Runtime Values
+    ///
+    /// The overridden values, without the profile.
+    fn values(self) -> ConfigValues {
+        ConfigValues {
+            backend_host: self.backend_host,
+            backend_path: self.backend_path,
+        }
+    }
+}
+
+/// # Configuration Error
+///
+/// Why the configuration could not be resolved.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigError {
+    /// `config.toml` does not match the expected structure.
+    File(String),
+    /// The runtime overrides are not valid JSON, or contain unknown keys.
+    Runtime(String),
+    /// The selected profile does not exist.
+    UnknownProfile(String),
+    /// A required value is not set for the selected profile.
+    Missing {
+        field: &'static str,
+        profile: Profile,
+    },
+    /// The backend host is not an HTTP or HTTPS URL.
+    InvalidHost(String),
+    /// The `prod` profile uses a backend host without HTTPS.
+    InsecureHost(String),
+    /// The backend path is empty or malformed.
+    InvalidPath(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(err) => write!(f, "Invalid config.toml: {err}"),
+            Self::Runtime(err) => write!(f, "Invalid {RUNTIME_CONFIG_KEY}: {err}"),
+            Self::UnknownProfile(profile) => {
+                write!(
+                    f,
+                    "Unknown profile `{profile}`, expected dev, staging or prod"
+                )
+            }
+            Self::Missing { field, profile } => {
+                write!(f, "`{field}` is not set for the {profile} profile")
+            }
+            Self::InvalidHost(host) => {
+                write!(
+                    f,
+                    "Backend host `{host}` must be an http:// or https:// URL"
+                )
+            }
+            Self::InsecureHost(host) => {
+                write!(
+                    f,
+                    "Backend host `{host}` must use https:// in the prod profile"
+                )
+            }
+            Self::InvalidPath(path) => write!(f, "Backend path `{path}` is not a valid path"),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Values with a host and path
+    fn values(host: &str, path: &str) -> ConfigValues {
+        ConfigValues {
+            backend_host: Some(host.to_string()),
+            backend_path: Some(path.to_string()),
+        }
+    }
+
+    /// A configuration file with a host for the dev and prod profiles
+    fn file() -> ConfigFile {
+        serde_json::from_value(json!({
+            "default": { "backend_path": "file-path" },
+            "profiles": {
+                "dev": { "backend_host": "http://dev.test" },
+                "prod": { "backend_host": "https://prod.test" },
+            },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn values_are_normalised() {
+        let config =
+            AppConfig::validate(Profile::Dev, values(" http://host.test/ ", "/graphql/")).unwrap();
+
+        assert_eq!(config.backend_host, "http://host.test");
+        assert_eq!(config.backend_path, "graphql");
     }
 
-    /// # Application Version
-    fn version_default() -> &'static str {
-        env!("CARGO_PKG_VERSION")
+    #[test]
+    fn invalid_values_are_rejected() {
+        let validate = |profile, host: &str, path: &str| {
+            AppConfig::validate(profile, values(host, path)).unwrap_err()
+        };
+
+        assert_eq!(
+            validate(Profile::Dev, "host.test", "graphql"),
+            ConfigError::InvalidHost("host.test".to_string())
+        );
+        assert_eq!(
+            validate(Profile::Dev, "https://", "graphql"),
+            ConfigError::InvalidHost("https:".to_string())
+        );
+        assert_eq!(
+            validate(Profile::Dev, "http://host test", "graphql"),
+            ConfigError::InvalidHost("http://host test".to_string())
+        );
+        assert_eq!(
+            validate(Profile::Dev, "http://host.test", "/"),
+            ConfigError::InvalidPath(String::new())
+        );
+        assert_eq!(
+            validate(Profile::Prod, "http://host.test", "graphql"),
+            ConfigError::InsecureHost("http://host.test".to_string())
+        );
+        assert_eq!(
+            AppConfig::validate(Profile::Staging, ConfigValues::default()).unwrap_err(),
+            ConfigError::Missing {
+                field: "backend_host",
+                profile: Profile::Staging
+            }
+        );
+    }
+
+    #[test]
+    fn the_dev_profile_works_without_configuration() {
+        let config = AppConfig::resolve(
+            ConfigFile::default(),
+            None,
+            ConfigValues::default(),
+            RuntimeConfig::default(),
+        )
+        .unwrap();
+
+        assert_eq!(config.profile, Profile::Dev);
+        assert_eq!(config.backend_host, FALLBACK_BACKEND_HOST);
+        assert_eq!(config.backend_path, FALLBACK_BACKEND_PATH);
+    }
+
+    #[test]
+    fn the_profile_is_selected_by_the_runtime_then_the_build_then_the_file() {
+        let profile = |file: ConfigFile, build: Option<Profile>, runtime: Option<Profile>| {
+            let runtime = RuntimeConfig {
+                profile: runtime,
+                ..RuntimeConfig::default()
+            };
+            AppConfig::resolve(file, build, ConfigValues::default(), runtime)
+                .unwrap()
+                .profile
+        };
+        let file_with_profile = |selected: Profile| ConfigFile {
+            profile: Some(selected),
+            ..file()
+        };
+
+        assert_eq!(profile(file(), None, None), Profile::Dev);
+        assert_eq!(
+            profile(file_with_profile(Profile::Prod), None, None),
+            Profile::Prod
+        );
+        assert_eq!(
+            profile(file_with_profile(Profile::Prod), Some(Profile::Dev), None),
+            Profile::Dev
+        );
+        assert_eq!(
+            profile(
+                file_with_profile(Profile::Dev),
+                Some(Profile::Dev),
+                Some(Profile::Prod)
+            ),
+            Profile::Prod
+        );
+    }
+
+    #[test]
+    fn later_sources_take_precedence() {
+        let resolve = |build: ConfigValues, runtime: RuntimeConfig| {
+            AppConfig::resolve(file(), None, build, runtime).unwrap()
+        };
+
+        let config = resolve(ConfigValues::default(), RuntimeConfig::default());
+        assert_eq!(config.backend_host, "http://dev.test");
+        assert_eq!(config.backend_path, "file-path");
+
+        let build = || ConfigValues {
+            backend_host: Some("http://build.test".to_string()),
+            backend_path: None,
+        };
+        let config = resolve(build(), RuntimeConfig::default());
+        assert_eq!(config.backend_host, "http://build.test");
+        assert_eq!(config.backend_path, "file-path");
+
+        let runtime = RuntimeConfig {
+            backend_path: Some("runtime-path".to_string()),
+            ..RuntimeConfig::default()
+        };
+        let config = resolve(build(), runtime);
+        assert_eq!(config.backend_host, "http://build.test");
+        assert_eq!(config.backend_path, "runtime-path");
+    }
+
+    #[test]
+    fn the_selected_profile_uses_its_own_table() {
+        let runtime = RuntimeConfig {
+            profile: Some(Profile::Prod),
+            ..RuntimeConfig::default()
+        };
+        let config = AppConfig::resolve(file(), None, ConfigValues::default(), runtime).unwrap();
+
+        assert_eq!(config.backend_host, "https://prod.test");
     }
 }
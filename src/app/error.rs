@@ -1,9 +1,10 @@
 use chrono::{DateTime, Utc};
 use dioxus::prelude::*;
 use graphql_client::Error as GraphQLError;
-use reqwest::Error as ReqwestError;
+use reqwest::{Error as ReqwestError, StatusCode};
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashMap,
     error::Error,
     fmt::{self, Display},
 };
@@ -11,7 +12,11 @@ use tracing::error;
 use uuid::Uuid;
 
 // Modules
-use crate::views::pages::errors::{GenericErrorPage, GenericErrorPageProps, NotFoundPage};
+use crate::{
+    app::html,
+    graphql::error::{error_category, error_code, error_debug_message, GraphQLClientError},
+    views::pages::errors::{GenericErrorPage, GenericErrorPageProps, NotFoundPage},
+};
 
 /// Represents an application error.
 ///
@@ -25,7 +30,7 @@ pub struct AppError {
 }
 
 /// Represents the kind of application error.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum AppErrorKind {
     /// API error
     Api,
@@ -34,14 +39,51 @@ pub enum AppErrorKind {
     /// Not found error
     NotFound,
     /// Unknown error
+    #[default]
     Unknown,
     /// JSON error
     Json,
+    /// Network error, the backend could not be reached
+    Network,
+    /// Timeout error, the backend took too long to respond
+    Timeout,
+    /// Unauthorized error, the request was rejected by the backend
+    Unauthorized,
+    /// Validation error, the request was invalid
+    Validation,
+    /// Rate limited error, too many requests were made
+    RateLimited,
+    /// Retries exhausted error, the backend kept failing after every retry
+    RetriesExhausted,
+    /// Configuration error, the application is not configured correctly
+    Config,
+    /// Payment error, the payment was declined or could not be taken
+    Payment,
 }
 
-impl Default for AppErrorKind {
-    fn default() -> Self {
-        AppErrorKind::Unknown
+impl AppErrorKind {
+    /// Gets advice for the user on how to recover from the error.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<&'static str>` - The advice, if there is any.
+    pub fn advice(&self) -> Option<&'static str> {
+        match self {
+            AppErrorKind::Network => Some("Please check your internet connection and try again."),
+            AppErrorKind::Timeout => {
+                Some("The store is taking too long to respond. Please try again shortly.")
+            }
+            AppErrorKind::Unauthorized => Some("Please sign in again to continue."),
+            AppErrorKind::Validation => Some("Please check the details you entered and try again."),
+            AppErrorKind::RateLimited => {
+                Some("Too many requests were made. Please wait a moment and try again.")
+            }
+            AppErrorKind::RetriesExhausted => {
+                Some("The store is currently unavailable. Please try again later.")
+            }
+            AppErrorKind::Config => Some("Please contact the store owner."),
+            _ => None,
+        }
     }
 }
 
@@ -53,10 +95,91 @@ impl Display for AppErrorKind {
             AppErrorKind::Json => write!(f, "JSON Error"),
             AppErrorKind::NotFound => write!(f, "Not Found Error"),
             AppErrorKind::Unknown => write!(f, "Unknown Error"),
+            AppErrorKind::Network => write!(f, "Network Error"),
+            AppErrorKind::Timeout => write!(f, "Timeout Error"),
+            AppErrorKind::Unauthorized => write!(f, "Unauthorized Error"),
+            AppErrorKind::Validation => write!(f, "Validation Error"),
+            AppErrorKind::RateLimited => write!(f, "Rate Limited Error"),
+            AppErrorKind::RetriesExhausted => write!(f, "Retries Exhausted Error"),
+            AppErrorKind::Config => write!(f, "Configuration Error"),
+            AppErrorKind::Payment => write!(f, "Payment Error"),
         }
     }
 }
 
+impl From<&GraphQLClientError> for AppErrorKind {
+    /// Classifies a GraphQL client error.
+    ///
+    /// Rate limiting takes precedence, so a 429 that outlasts the retries is
+    /// still reported as such, then exhausted retries and HTTP statuses, then
+    /// the WPGraphQL `extensions.category` of the returned GraphQL errors. Only
+    /// the `user` category is the shopper's input: `graphql` errors are invalid
+    /// queries, which are the app's fault.
+    ///
+    /// **Arguments**
+    ///
+    /// * `error` - The GraphQL client error to classify.
+    ///
+    /// **Returns**
+    ///
+    /// * `AppErrorKind` - The kind of application error.
+    fn from(error: &GraphQLClientError) -> Self {
+        let status: Option<StatusCode> = error.status();
+
+        match error {
+            _ if status == Some(StatusCode::TOO_MANY_REQUESTS) => AppErrorKind::RateLimited,
+            GraphQLClientError::RetriesExhausted { .. } => AppErrorKind::RetriesExhausted,
+            _ if matches!(
+                status,
+                Some(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+            ) =>
+            {
+                AppErrorKind::Unauthorized
+            }
+            GraphQLClientError::Network(_) => AppErrorKind::Network,
+            GraphQLClientError::Timeout(_) => AppErrorKind::Timeout,
+            GraphQLClientError::Http { .. } => AppErrorKind::Api,
+            GraphQLClientError::Decode(_) => AppErrorKind::Json,
+            GraphQLClientError::GraphQL { errors, .. } => {
+                if errors.iter().any(is_unauthorized_error) {
+                    AppErrorKind::Unauthorized
+                } else if errors.iter().any(|err| error_category(err) == Some("user")) {
+                    AppErrorKind::Validation
+                } else {
+                    AppErrorKind::GraphQL
+                }
+            }
+            GraphQLClientError::NoData => AppErrorKind::GraphQL,
+        }
+    }
+}
+
+/// Checks whether a GraphQL error reports a missing or rejected authorization.
+///
+/// WPGraphQL and WPGraphQL JWT Authentication report these as regular errors,
+/// so the message is the only reliable signal.
+///
+/// **Arguments**
+///
+/// * `error` - The GraphQL error to check.
+///
+/// **Returns**
+///
+/// * `bool` - Whether the error is an authorization error.
+fn is_unauthorized_error(error: &GraphQLError) -> bool {
+    let message = error_debug_message(error)
+        .unwrap_or(&error.message)
+        .to_lowercase();
+    [
+        "not allowed",
+        "not authorized",
+        "invalid-jwt",
+        "expired token",
+    ]
+    .iter()
+    .any(|needle| message.contains(needle))
+}
+
 impl AppError {
     /// Creates a new AppError and logs it.
     ///
@@ -227,27 +350,355 @@ impl From<ReqwestError> for AppError {
     }
 }
 
-impl From<GraphQLErrorWrapper> for AppError {
-    fn from(error: GraphQLErrorWrapper) -> Self {
-        Self::new(
-            AppErrorKind::GraphQL,
+impl From<GraphQLClientError> for AppError {
+    /// Converts a GraphQL client error into an AppError.
+    ///
+    /// **Arguments**
+    ///
+    /// * `error` - The GraphQL client error to convert.
+    ///
+    /// **Returns**
+    ///
+    /// * `AppError` - The converted AppError, classified by `AppErrorKind`.
+    fn from(error: GraphQLClientError) -> Self {
+        Self::new_with_source(
+            AppErrorKind::from(&error),
             "An error occurred while making a GraphQL request.".to_string(),
             Some(error.to_string()),
-            Some(Box::new(error)),
+            error,
         )
     }
 }
 
-/// Wraps a GraphQL error message.
+/// # Error Field
 ///
-/// This struct is used to wrap a GraphQL error.
-#[derive(Debug)]
-pub struct GraphQLErrorWrapper(pub String);
+/// A form field, as the errors about it refer to it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ErrorField {
+    /// The field name
+    pub name: &'static str,
+    /// The `extensions.code` of the errors about the field
+    pub codes: &'static [&'static str],
+    /// The keywords of the messages about the field, for errors without a code
+    pub keywords: &'static [&'static str],
+}
 
-impl Display for GraphQLErrorWrapper {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+impl ErrorField {
+    /// Creates a form field.
+    ///
+    /// **Arguments**
+    ///
+    /// * `name` - The field name.
+    /// * `codes` - The error codes of the errors about the field.
+    /// * `keywords` - The keywords of the messages about the field.
+    ///
+    /// **Returns**
+    ///
+    /// * `ErrorField` - The form field.
+    pub const fn new(
+        name: &'static str,
+        codes: &'static [&'static str],
+        keywords: &'static [&'static str],
+    ) -> Self {
+        Self {
+            name,
+            codes,
+            keywords,
+        }
+    }
+}
+
+/// # Field Errors
+///
+/// The errors of a submitted form, reported against the fields they belong to.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldErrors {
+    /// The first error of each field, by field name
+    pub fields: HashMap<&'static str, String>,
+    /// The errors that don't belong to any field
+    pub form: Vec<String>,
+}
+
+impl FieldErrors {
+    /// Sorts the messages of an error by the form field they are about.
+    ///
+    /// An error belongs to the field whose codes include its `extensions.code`.
+    /// WPGraphQL and WooGraphQL report most invalid input as plain GraphQL errors
+    /// without a code though, so the message of an error without a matching code
+    /// is matched against the keywords of each field in turn instead. Unmatched
+    /// messages are form errors. Errors without GraphQL errors, such as network
+    /// errors, are form errors.
+    ///
+    /// **Arguments**
+    ///
+    /// * `error` - The error returned for the submission.
+    /// * `fields` - The form fields.
+    ///
+    /// **Returns**
+    ///
+    /// * `FieldErrors` - The errors, by field.
+    pub fn from_error(error: &AppError, fields: &[ErrorField]) -> Self {
+        let graphql_errors: Option<&Vec<GraphQLError>> = error
+            .source
+            .as_ref()
+            .and_then(|source| source.downcast_ref::<GraphQLClientError>())
+            .and_then(|source| match source {
+                GraphQLClientError::GraphQL { errors, .. } if !errors.is_empty() => Some(errors),
+                _ => None,
+            });
+        let Some(graphql_errors) = graphql_errors else {
+            let message: &str = error.kind.advice().unwrap_or(&error.public_message);
+            return Self {
+                fields: HashMap::new(),
+                form: vec![message.to_string()],
+            };
+        };
+
+        let mut field_errors: Self = Self::default();
+        for graphql_error in graphql_errors {
+            let message: String = plain_text(&graphql_error.message);
+            let lowercase: String = message.to_lowercase();
+            let by_code: Option<&ErrorField> = error_code(graphql_error)
+                .and_then(|code| fields.iter().find(|field| field.codes.contains(&code)));
+            let field: Option<&'static str> = by_code
+                .or_else(|| {
+                    fields.iter().find(|field| {
+                        field
+                            .keywords
+                            .iter()
+                            .any(|keyword| lowercase.contains(keyword))
+                    })
+                })
+                .map(|field| field.name);
+            match field {
+                Some(field) => {
+                    field_errors.fields.entry(field).or_insert(message);
+                }
+                None => field_errors.form.push(message),
+            }
+        }
+
+        field_errors
+    }
+
+    /// Reports an error against a single field.
+    ///
+    /// **Arguments**
+    ///
+    /// * `field` - The field name.
+    /// * `error` - The error.
+    ///
+    /// **Returns**
+    ///
+    /// * `FieldErrors` - The errors, with the error's message against the field.
+    pub fn for_field(field: &'static str, error: &AppError) -> Self {
+        let message: &str = error.kind.advice().unwrap_or(&error.public_message);
+        Self {
+            fields: HashMap::from([(field, message.to_string())]),
+            form: Vec::new(),
+        }
+    }
+
+    /// Gets the error of a field.
+    ///
+    /// **Arguments**
+    ///
+    /// * `field` - The field name.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<String>` - The error, if the field has one.
+    pub fn field(&self, field: &str) -> Option<String> {
+        self.fields.get(field).cloned()
     }
 }
 
-impl Error for GraphQLErrorWrapper {}
+/// Converts a WordPress error message to plain text.
+///
+/// WordPress messages may contain HTML, such as a bold "Error:" prefix or a login
+/// link, and HTML entities.
+///
+/// **Arguments**
+///
+/// * `message` - The message.
+///
+/// **Returns**
+///
+/// * `String` - The message without HTML or the "Error:" prefix.
+fn plain_text(message: &str) -> String {
+    html::to_text(message)
+        .trim_start_matches("Error:")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    const FIELDS: &[ErrorField] = &[
+        ErrorField::new("email", &["existing_user_email"], &["email"]),
+        ErrorField::new("password", &["password_reset_mismatch"], &["password"]),
+    ];
+
+    /// Builds the error returned for GraphQL `errors[]`.
+    fn graphql_error(errors: Value) -> AppError {
+        AppError::from(GraphQLClientError::GraphQL {
+            status: StatusCode::OK,
+            errors: serde_json::from_value(errors).unwrap(),
+        })
+    }
+
+    /// Classifies the error returned for GraphQL `errors[]`.
+    fn kind(errors: Value) -> AppErrorKind {
+        graphql_error(errors).kind
+    }
+
+    #[test]
+    fn authorization_errors_are_unauthorized() {
+        for status in [StatusCode::UNAUTHORIZED, StatusCode::FORBIDDEN] {
+            let error = GraphQLClientError::Http {
+                status,
+                body: String::new(),
+            };
+            assert_eq!(AppErrorKind::from(&error), AppErrorKind::Unauthorized);
+        }
+
+        assert_eq!(
+            kind(json!([{ "message": "Sorry, you are not allowed to view this order." }])),
+            AppErrorKind::Unauthorized
+        );
+        assert_eq!(
+            kind(json!([{ "message": "You are NOT AUTHORIZED to do that." }])),
+            AppErrorKind::Unauthorized
+        );
+        assert_eq!(
+            kind(json!([{
+                "message": "Internal server error",
+                "extensions": { "debugMessage": "invalid-jwt | Expired token" },
+            }])),
+            AppErrorKind::Unauthorized
+        );
+    }
+
+    #[test]
+    fn user_errors_are_validation_errors() {
+        assert_eq!(
+            kind(json!([
+                { "message": "Cannot query field" , "extensions": { "category": "graphql" } },
+                { "message": "Invalid email.", "extensions": { "category": "user" } },
+            ])),
+            AppErrorKind::Validation
+        );
+    }
+
+    #[test]
+    fn query_errors_are_graphql_errors() {
+        assert_eq!(
+            kind(json!([{
+                "message": "Cannot query field \"nme\" on type \"Product\".",
+                "extensions": { "category": "graphql" },
+            }])),
+            AppErrorKind::GraphQL
+        );
+        assert_eq!(
+            kind(json!([{ "message": "Something went wrong." }])),
+            AppErrorKind::GraphQL
+        );
+        assert_eq!(
+            AppErrorKind::from(&GraphQLClientError::NoData),
+            AppErrorKind::GraphQL
+        );
+    }
+
+    #[test]
+    fn errors_are_matched_by_code() {
+        let error: AppError = graphql_error(json!([{
+            "message": "Sorry, that password is already in use.",
+            "extensions": { "code": "existing_user_email" },
+        }]));
+
+        let errors: FieldErrors = FieldErrors::from_error(&error, FIELDS);
+
+        assert_eq!(
+            errors.field("email").as_deref(),
+            Some("Sorry, that password is already in use.")
+        );
+        assert_eq!(errors.field("password"), None);
+        assert!(errors.form.is_empty());
+    }
+
+    #[test]
+    fn errors_without_a_known_code_are_matched_by_keyword() {
+        let error: AppError = graphql_error(json!([
+            { "message": "The password is too short." },
+            {
+                "message": "<strong>Error:</strong> The email address isn&#039;t correct.",
+                "extensions": { "code": "invalid_email" },
+            },
+        ]));
+
+        let errors: FieldErrors = FieldErrors::from_error(&error, FIELDS);
+
+        assert_eq!(
+            errors.field("password").as_deref(),
+            Some("The password is too short.")
+        );
+        assert_eq!(
+            errors.field("email").as_deref(),
+            Some("The email address isn't correct.")
+        );
+    }
+
+    #[test]
+    fn unmatched_errors_are_form_errors() {
+        let error: AppError = graphql_error(json!([
+            { "message": "Sorry, you are not allowed to do that." },
+            { "message": "The email is taken." },
+            { "message": "The email is invalid." },
+        ]));
+
+        let errors: FieldErrors = FieldErrors::from_error(&error, FIELDS);
+
+        assert_eq!(errors.form, vec!["Sorry, you are not allowed to do that."]);
+        assert_eq!(
+            errors.field("email").as_deref(),
+            Some("The email is taken.")
+        );
+    }
+
+    #[test]
+    fn errors_without_graphql_errors_are_form_errors() {
+        let exhausted: AppError = AppError::from(GraphQLClientError::RetriesExhausted {
+            attempts: 3,
+            last: Box::new(GraphQLClientError::NoData),
+        });
+        let errors: FieldErrors = FieldErrors::from_error(&exhausted, FIELDS);
+        assert!(errors.fields.is_empty());
+        assert_eq!(
+            errors.form,
+            vec![AppErrorKind::RetriesExhausted.advice().unwrap()]
+        );
+
+        let http: AppError = AppError::from(GraphQLClientError::Http {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            body: String::new(),
+        });
+        let errors: FieldErrors = FieldErrors::from_error(&http, FIELDS);
+        assert_eq!(errors.form, vec![http.public_message.clone()]);
+    }
+
+    #[test]
+    fn plain_text_strips_html_and_the_error_prefix() {
+        assert_eq!(
+            plain_text("<strong>Error:</strong> Invalid username. <a href=\"/lost\">Lost your password?</a>"),
+            "Invalid username. Lost your password?"
+        );
+        assert_eq!(plain_text("Error: Already plain.  "), "Already plain.");
+        assert_eq!(
+            plain_text("The Error: prefix only goes first."),
+            "The Error: prefix only goes first."
+        );
+    }
+}
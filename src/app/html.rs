@@ -0,0 +1,153 @@
+/// The tags that end a line of text
+const LINE_BREAKS: &[&str] = &["br", "br/", "/p", "/div", "/li", "/h1", "/h2", "/h3"];
+
+/// The named HTML entities WordPress and WooCommerce escape text with, and the
+/// characters they stand for
+///
+/// Numeric entities, such as the `&#36;` of a formatted price, are decoded by
+/// their code point.
+const ENTITIES: &[(&str, char)] = &[
+    ("quot", '"'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("nbsp", ' '),
+    ("amp", '&'),
+    ("euro", '€'),
+    ("pound", '£'),
+    ("yen", '¥'),
+];
+
+/// Converts HTML from WordPress to plain text.
+///
+/// Tags are dropped, the tags that end a line of text become line breaks, and
+/// entities are decoded. Each line is trimmed, and blank lines are dropped. The
+/// text is safe to render as text, without the markup it was given with.
+///
+/// **Arguments**
+///
+/// * `html` - The HTML.
+///
+/// **Returns**
+///
+/// * `String` - The text, with a line break between lines.
+pub fn to_text(html: &str) -> String {
+    let mut text: String = String::with_capacity(html.len());
+    let mut tag: Option<String> = None;
+    for c in html.chars() {
+        match (&mut tag, c) {
+            (None, '<') => tag = Some(String::new()),
+            (Some(name), '>') => {
+                let name: String = name
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+                    .to_lowercase();
+                if LINE_BREAKS.contains(&name.as_str()) {
+                    text.push('\n');
+                }
+                tag = None;
+            }
+            (Some(name), c) => name.push(c),
+            (None, c) => text.push(c),
+        }
+    }
+
+    decode_entities(&text)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+/// Decodes the HTML entities of text.
+///
+/// The text is decoded in one pass, so escaped entities such as `&amp;lt;` stay
+/// escaped. Unknown entities are kept as they are.
+///
+/// **Arguments**
+///
+/// * `text` - The text.
+///
+/// **Returns**
+///
+/// * `String` - The decoded text.
+fn decode_entities(text: &str) -> String {
+    let mut decoded: String = String::with_capacity(text.len());
+    let mut rest: &str = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        let entity: Option<(char, usize)> = rest.find(';').and_then(|end| {
+            let name: &str = &rest[..end];
+            let c: Option<char> = match name.strip_prefix('#') {
+                Some(code) => match code.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => code.parse::<u32>().ok(),
+                }
+                .and_then(char::from_u32),
+                None => ENTITIES
+                    .iter()
+                    .find(|(entity, _)| *entity == name)
+                    .map(|(_, c)| *c),
+            };
+            c.map(|c| (c, end))
+        });
+        match entity {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => decoded.push('&'),
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tags_are_dropped() {
+        assert_eq!(
+            to_text("<strong>Invalid</strong> username. <a href=\"/lost\">Lost your password?</a>"),
+            "Invalid username. Lost your password?"
+        );
+        assert_eq!(
+            to_text("Pay by card.<script>alert(1)</script><img src=x onerror=alert(1)>"),
+            "Pay by card.alert(1)"
+        );
+    }
+
+    #[test]
+    fn line_ending_tags_become_line_breaks() {
+        assert_eq!(
+            to_text(
+                "<p>Account name: Wept</p>\n<p>Sort code: 12-34-56<br />Account number: 1234</p>"
+            ),
+            "Account name: Wept\nSort code: 12-34-56\nAccount number: 1234"
+        );
+    }
+
+    #[test]
+    fn entities_are_decoded() {
+        assert_eq!(
+            to_text("Coupon &quot;summer&quot; doesn&#039;t apply to Salt &amp; Pepper."),
+            "Coupon \"summer\" doesn't apply to Salt & Pepper."
+        );
+        assert_eq!(to_text("&amp;lt;b&amp;gt; &lt; 2"), "&lt;b&gt; < 2");
+    }
+
+    #[test]
+    fn formatted_prices_are_decoded() {
+        assert_eq!(to_text("&#36;27.00"), "$27.00");
+        assert_eq!(to_text("&#x20b9;1,500.00"), "₹1,500.00");
+        assert_eq!(to_text("12,50&nbsp;&euro;"), "12,50 €");
+        assert_eq!(
+            to_text("&amp;#36; &#xzz; & &unknown;"),
+            "&#36; &#xzz; & &unknown;"
+        );
+    }
+}
@@ -1,9 +1,74 @@
 use dioxus::prelude::*;
 use gloo_storage::{LocalStorage, Storage};
 use serde::{Deserialize, Serialize};
+#[cfg(not(target_family = "wasm"))]
+use std::{cell::RefCell, collections::HashMap};
+use wasm_bindgen::prelude::*;
 
 // Modules
-use crate::graphql::models::cart::cart_query;
+use crate::models::{cart::Cart, customer::Customer, order::Order};
+
+#[wasm_bindgen]
+extern "C" {
+    // LocalStorage setItem
+    #[cfg(target_family = "wasm")]
+    #[wasm_bindgen(js_namespace = localStorage, js_name = setItem)]
+    fn set_item(key: &str, value: &str);
+
+    // LocalStorage getItem
+    #[cfg(target_family = "wasm")]
+    #[wasm_bindgen(js_namespace = localStorage, js_name = getItem)]
+    fn get_item(key: &str) -> Option<String>;
+
+    // LocalStorage removeItem
+    #[cfg(target_family = "wasm")]
+    #[wasm_bindgen(js_namespace = localStorage, js_name = removeItem)]
+    fn remove_item(key: &str);
+
+    // A storage event, fired when another tab changes local storage
+    type StorageEvent;
+
+    // StorageEvent key, which is null when the storage was cleared
+    #[wasm_bindgen(method, getter)]
+    fn key(this: &StorageEvent) -> Option<String>;
+
+    // StorageEvent oldValue, which is null when the key was added
+    #[wasm_bindgen(method, getter, js_name = oldValue)]
+    fn old_value(this: &StorageEvent) -> Option<String>;
+
+    // StorageEvent newValue, which is null when the key was removed
+    #[wasm_bindgen(method, getter, js_name = newValue)]
+    fn new_value(this: &StorageEvent) -> Option<String>;
+
+    // Window addEventListener
+    #[wasm_bindgen(js_name = addEventListener)]
+    fn add_storage_listener(event: &str, listener: &Closure<dyn FnMut(StorageEvent)>);
+}
+
+#[cfg(not(target_family = "wasm"))]
+thread_local! {
+    /// Native builds, which run the command-line tools and tests, have no local
+    /// storage, so the raw values are kept in memory for the life of the thread.
+    static MEMORY_STORAGE: RefCell<HashMap<String, String>> = RefCell::new(HashMap::new());
+}
+
+/// Sets a raw value in the in-memory storage of native builds.
+#[cfg(not(target_family = "wasm"))]
+fn set_item(key: &str, value: &str) {
+    MEMORY_STORAGE.with_borrow_mut(|storage| storage.insert(key.to_string(), value.to_string()));
+}
+
+/// Gets a raw value from the in-memory storage of native builds.
+#[cfg(not(target_family = "wasm"))]
+fn get_item(key: &str) -> Option<String> {
+    MEMORY_STORAGE.with_borrow(|storage| storage.get(key).cloned())
+}
+
+/// Removes a raw value from the in-memory storage of native builds.
+#[cfg(not(target_family = "wasm"))]
+fn remove_item(key: &str) {
+    MEMORY_STORAGE.with_borrow_mut(|storage| storage.remove(key));
+}
 
 /// # Global State Signal
 ///
@@ -11,27 +76,69 @@ use crate::graphql::models::cart::cart_query;
 /// Designed to be accessed from any component in the application.
 pub static STATE: GlobalSignal<State> = Global::new(State::default);
 
+/// # Cart Key
+///
+/// The key for the cart in local storage.
+pub const CART_KEY: &str = "cart";
+
 /// # Session Token Key
 ///
 /// The key for the WooCommerce session token in local storage.
 pub const SESSION_TOKEN_KEY: &str = "woocommerce-session";
 
-#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
-pub struct Cart {
-    pub items: Vec<cart_query::CartQueryCartContentsNodes>,
-    pub total: String,
-    pub subtotal: String,
+/// # Auth Token Key
+///
+/// The key for the signed-in customer's JWT auth token in local storage.
+pub const AUTH_TOKEN_KEY: &str = "wept-auth-token";
+
+/// # Refresh Token Key
+///
+/// The key for the signed-in customer's JWT refresh token in local storage.
+pub const REFRESH_TOKEN_KEY: &str = "wept-refresh-token";
+
+/// # Customer Signal
+///
+/// A global signal that holds the signed-in customer, or `None` for guests.
+/// Kept in sync with the stored auth tokens by `AuthController`.
+pub static CUSTOMER: GlobalSignal<Option<Customer>> = Global::new(|| None);
+
+/// # Customer Restore Signal
+///
+/// A global signal that holds how restoring the signed-in customer from the
+/// stored auth token went, so the account area can tell waiting from failing.
+pub static CUSTOMER_RESTORE: GlobalSignal<RestoreStatus> = Global::new(RestoreStatus::default);
+
+/// # Restore Status
+///
+/// How restoring the signed-in customer went.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum RestoreStatus {
+    /// The customer is being restored
+    #[default]
+    Pending,
+    /// The customer was restored, or the stored tokens were removed
+    Done,
+    /// The customer could not be fetched, with why; the stored tokens are kept
+    Failed(String),
 }
 
+/// # Placed Order Signal
+///
+/// A global signal that holds the order placed at checkout, so the Order
+/// Received page can show it without fetching it. Guests can't fetch orders.
+pub static PLACED_ORDER: GlobalSignal<Option<Order>> = Global::new(|| None);
+
+/// # Mini Cart Signal
+///
+/// A global signal that holds whether the mini cart drawer is open, so it can
+/// be opened after adding to the cart.
+pub static MINI_CART_OPEN: GlobalSignal<bool> = Global::new(|| false);
+
 /// # Global State
 ///
 /// The global state of the application.
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct State {
-    /// Backend host
-    backend_host: String,
-    /// Backend path
-    backend_path: String,
     /// Cart
     pub cart: Cart,
 }
@@ -39,101 +146,107 @@ pub struct State {
 impl State {
     /// # Default State
     ///
-    /// Create a default state instance.
+    /// Create a default state instance, with the cart saved in local storage.
+    ///
+    /// A saved cart in a format that can't be migrated is discarded.
     pub fn default() -> Self {
-        let cart = LocalStorage::get("cart").unwrap_or_default();
+        let cart: Option<Cart> = LocalStorage::get(CART_KEY).ok().and_then(Cart::from_stored);
+        if cart.is_none() {
+            LocalStorage::delete(CART_KEY);
+        }
 
         Self {
-            backend_host: Env::backend_host_default(),
-            backend_path: Env::backend_path_default(),
-            cart,
+            cart: cart.unwrap_or_default(),
         }
     }
 
-    /// # Get Backend Host
+    /// # Get Session Token
     ///
-    /// Get the backend host from the state.
-    pub fn get_backend_host() -> String {
-        #[cfg(target_family = "wasm")]
-        {
-            // In WASM context, use the GlobalSignal or fallback
-            let state = STATE.try_read();
-            match state {
-                Ok(state) => state.backend_host.clone(),
-                Err(err) => {
-                    tracing::error!("Error reading state for backend host: {err}");
-                    Env::backend_host_default()
-                }
-            }
-        }
+    /// Get the WooCommerce session token from local storage.
+    ///
+    /// The token is stored as a raw string, not JSON, so it bypasses `gloo_storage`.
+    pub fn get_session_token() -> Option<String> {
+        get_item(SESSION_TOKEN_KEY)
+    }
 
-        #[cfg(not(target_family = "wasm"))]
-        {
-            let value = STATE.read();
-            value.backend_host.clone()
-        }
+    /// # Set Session Token
+    ///
+    /// Persist the WooCommerce session token to local storage.
+    pub fn set_session_token(token: &str) {
+        set_item(SESSION_TOKEN_KEY, token);
     }
 
-    /// # Get Backend Path
+    /// # Clear Session Token
     ///
-    /// Get the backend path from the state.
-    pub fn get_backend_path() -> String {
-        #[cfg(target_family = "wasm")]
-        {
-            // In WASM context, use the GlobalSignal or fallback
-            let state = STATE.try_read();
-            match state {
-                Ok(state) => state.backend_path.clone(),
-                Err(err) => {
-                    tracing::error!("Error reading state for backend path: {err}");
-                    Env::backend_path_default()
-                }
-            }
-        }
+    /// Remove the WooCommerce session token from local storage, so the next
+    /// request starts a new session.
+    pub fn clear_session_token() {
+        remove_item(SESSION_TOKEN_KEY);
+    }
 
-        #[cfg(not(target_family = "wasm"))]
-        {
-            let value = STATE.signal();
-            let value = value.read();
-            value.backend_path.clone()
-        }
+    /// # Get Auth Token
+    ///
+    /// Get the signed-in customer's JWT auth token from local storage.
+    pub fn get_auth_token() -> Option<String> {
+        get_item(AUTH_TOKEN_KEY)
     }
 
-    /// # Save Cart
+    /// # Set Auth Token
     ///
-    /// Save the cart to local storage. This should be called
-    /// whenever the cart state is modified.
-    pub fn save_cart(&self) {
-        if let Err(e) = LocalStorage::set("cart", &self.cart) {
-            tracing::error!("Failed to save cart to local storage: {}", e);
-        }
+    /// Persist the signed-in customer's JWT auth token to local storage.
+    pub fn set_auth_token(token: &str) {
+        set_item(AUTH_TOKEN_KEY, token);
     }
-}
 
-/// # Environment Variables
-///
-/// The environment variables for the application.
-#[derive(Deserialize, Default, Debug)]
-struct Env {}
+    /// # Get Refresh Token
+    ///
+    /// Get the signed-in customer's JWT refresh token from local storage.
+    pub fn get_refresh_token() -> Option<String> {
+        get_item(REFRESH_TOKEN_KEY)
+    }
 
-impl Env {
-    /// # Fallback Backend Host
-    const FALLBACK_BACKEND_HOST: &str = "http://localhost:8080";
+    /// # Set Refresh Token
+    ///
+    /// Persist the signed-in customer's JWT refresh token to local storage.
+    pub fn set_refresh_token(token: &str) {
+        set_item(REFRESH_TOKEN_KEY, token);
+    }
 
-    /// # Fallback Backend Path
-    const FALLBACK_BACKEND_PATH: &str = "graphql";
+    /// # Clear Auth Tokens
+    ///
+    /// Remove the JWT auth and refresh tokens from local storage, so later
+    /// requests are sent as a guest.
+    pub fn clear_auth_tokens() {
+        remove_item(AUTH_TOKEN_KEY);
+        remove_item(REFRESH_TOKEN_KEY);
+    }
 
-    /// # Default Backend Host
+    /// # Watch Storage
     ///
-    /// Provides a fallback for the backend WordPress host.
-    fn backend_host_default() -> String {
-        Self::FALLBACK_BACKEND_HOST.to_string()
+    /// Call a handler whenever another tab changes local storage, for as long as
+    /// the app runs. The handler gets the key, or `None` when the storage was
+    /// cleared, the old value, or `None` when the key was added, and the new
+    /// value, or `None` when the key was removed.
+    ///
+    /// Browsers don't notify the tab that made the change.
+    pub fn watch_storage(
+        mut handler: impl FnMut(Option<String>, Option<String>, Option<String>) + 'static,
+    ) {
+        let listener: Closure<dyn FnMut(StorageEvent)> =
+            Closure::new(move |event: StorageEvent| {
+                handler(event.key(), event.old_value(), event.new_value())
+            });
+        add_storage_listener("storage", &listener);
+        listener.forget();
     }
 
-    /// # Default Backend Path
+    /// # Save Cart
     ///
-    /// Provides a fallback for the backend GraphQL API path.
-    fn backend_path_default() -> String {
-        Self::FALLBACK_BACKEND_PATH.to_string()
+    /// Save the cart to local storage. This should be called
+    /// whenever the cart state is modified.
+    pub fn save_cart(&self) {
+        if let Err(e) = LocalStorage::set(CART_KEY, self.cart.to_stored()) {
+            tracing::error!("Failed to save cart to local storage: {}", e);
+        }
     }
 }
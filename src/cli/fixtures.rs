@@ -0,0 +1,633 @@
+use graphql_client::{GraphQLQuery, Response};
+use reqwest::{header::AUTHORIZATION, Method, StatusCode};
+use serde_json::{json, Value};
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::PathBuf,
+};
+
+// Modules
+use crate::{
+    app::state::SESSION_TOKEN_KEY,
+    graphql::{
+        models::{
+            cart::{
+                add_to_cart, apply_coupon, cart_query, empty_cart, remove_coupons,
+                remove_items_from_cart, restore_cart_items, update_item_quantities, AddToCart,
+                ApplyCoupon, CartQuery, EmptyCart, RemoveCoupons, RemoveItemsFromCart,
+                RestoreCartItems, UpdateItemQuantities,
+            },
+            category::{product_categories, product_category, ProductCategories, ProductCategory},
+            checkout::{
+                checkout, checkout_query, update_shipping_method, Checkout, CheckoutQuery,
+                UpdateShippingMethod,
+            },
+            customer::{
+                customer_query, forget_session, login, refresh_jwt_auth_token, register_customer,
+                reset_user_password, send_password_reset_email, update_customer, CustomerQuery,
+                ForgetSession, Login, RefreshJwtAuthToken, RegisterCustomer, ResetUserPassword,
+                SendPasswordResetEmail, UpdateCustomer,
+            },
+            navigation::{navigation_query, NavigationQuery},
+            order::{customer_orders_query, order_query, CustomerOrdersQuery, OrderQuery},
+            page::{page_query, pages_query, PageQuery, PagesQuery},
+            post::{post_query, posts_query, PostQuery, PostsQuery},
+            product::{
+                product_query, products_query, search_products_query, ProductQuery, ProductsQuery,
+                SearchProductsQuery,
+            },
+            settings::{wept_settings_query, WeptSettingsQuery},
+            shipping::{
+                countries_query, country_states_query, update_shipping_address, CountriesQuery,
+                CountryStatesQuery, UpdateShippingAddress,
+            },
+        },
+        transport::{fixture::FixtureTransport, Transport, TransportRequest},
+    },
+};
+
+/// Default directory of the recorded responses
+const DEFAULT_DIR: &str = "fixtures";
+
+/// Default address to serve the fixtures on, matching the `dev` profile
+const DEFAULT_ADDR: &str = "127.0.0.1:8080";
+
+/// Path the fixtures are served on
+const GRAPHQL_PATH: &str = "/graphql";
+
+/// Session used to check the cart operations
+const CHECK_SESSION: &str = "fixtures-check";
+
+/// Checks that a response decodes into an operation's generated response type.
+type Validator = fn(Value) -> Result<(), String>;
+
+/// Every operation answered by a recorded fixture, with its validator
+const FIXTURE_OPERATIONS: &[(&str, Validator)] = &[
+    (products_query::OPERATION_NAME, validate::<ProductsQuery>),
+    (product_query::OPERATION_NAME, validate::<ProductQuery>),
+    (
+        search_products_query::OPERATION_NAME,
+        validate::<SearchProductsQuery>,
+    ),
+    (
+        product_categories::OPERATION_NAME,
+        validate::<ProductCategories>,
+    ),
+    (
+        product_category::OPERATION_NAME,
+        validate::<ProductCategory>,
+    ),
+    (
+        navigation_query::OPERATION_NAME,
+        validate::<NavigationQuery>,
+    ),
+    (page_query::OPERATION_NAME, validate::<PageQuery>),
+    (pages_query::OPERATION_NAME, validate::<PagesQuery>),
+    (post_query::OPERATION_NAME, validate::<PostQuery>),
+    (posts_query::OPERATION_NAME, validate::<PostsQuery>),
+    (
+        wept_settings_query::OPERATION_NAME,
+        validate::<WeptSettingsQuery>,
+    ),
+    (customer_query::OPERATION_NAME, validate::<CustomerQuery>),
+    (login::OPERATION_NAME, validate::<Login>),
+    (
+        refresh_jwt_auth_token::OPERATION_NAME,
+        validate::<RefreshJwtAuthToken>,
+    ),
+    (forget_session::OPERATION_NAME, validate::<ForgetSession>),
+    (
+        register_customer::OPERATION_NAME,
+        validate::<RegisterCustomer>,
+    ),
+    (
+        send_password_reset_email::OPERATION_NAME,
+        validate::<SendPasswordResetEmail>,
+    ),
+    (
+        reset_user_password::OPERATION_NAME,
+        validate::<ResetUserPassword>,
+    ),
+    (update_customer::OPERATION_NAME, validate::<UpdateCustomer>),
+    (
+        customer_orders_query::OPERATION_NAME,
+        validate::<CustomerOrdersQuery>,
+    ),
+    (order_query::OPERATION_NAME, validate::<OrderQuery>),
+    (checkout_query::OPERATION_NAME, validate::<CheckoutQuery>),
+    (checkout::OPERATION_NAME, validate::<Checkout>),
+    (countries_query::OPERATION_NAME, validate::<CountriesQuery>),
+    (
+        country_states_query::OPERATION_NAME,
+        validate::<CountryStatesQuery>,
+    ),
+];
+
+/// Runs the `fixtures` command.
+///
+/// * `wept fixtures serve [--dir DIR] [--addr ADDR]` - Serves the fixtures over HTTP.
+/// * `wept fixtures check [--dir DIR]` - Validates the fixtures against the schema.
+///
+/// **Arguments**
+///
+/// * `args` - The arguments after `fixtures`.
+///
+/// **Returns**
+///
+/// * `i32` - The exit code.
+pub fn run(mut args: impl Iterator<Item = String>) -> i32 {
+    let subcommand: Option<String> = args.next();
+    let mut dir: PathBuf = PathBuf::from(DEFAULT_DIR);
+    let mut addr: String = DEFAULT_ADDR.to_string();
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--dir", Some(value)) => dir = PathBuf::from(value),
+            ("--addr", Some(value)) => addr = value,
+            _ => {
+                eprintln!("Unknown argument: {arg}\n\n{}", super::USAGE);
+                return 2;
+            }
+        }
+    }
+
+    let transport: FixtureTransport = match FixtureTransport::from_dir(&dir) {
+        Ok(transport) => transport,
+        Err(err) => {
+            eprintln!("Failed to read fixtures: {err}");
+            return 1;
+        }
+    };
+
+    match subcommand.as_deref() {
+        Some("serve") => serve(&transport, &addr),
+        Some("check") => check(&transport),
+        _ => {
+            eprintln!("{}", super::USAGE);
+            2
+        }
+    }
+}
+
+/// Validates every fixture, and the cart responses built from them.
+///
+/// Responses are decoded into the types `graphql_client` generates from
+/// `schema.graphql`, so a fixture that doesn't match an operation's selection fails.
+///
+/// **Arguments**
+///
+/// * `transport` - The fixture transport.
+///
+/// **Returns**
+///
+/// * `i32` - The exit code.
+fn check(transport: &FixtureTransport) -> i32 {
+    let mut failures: Vec<String> = Vec::new();
+    let mut report = |operation_name: &str, result: Result<(), String>| match result {
+        Ok(()) => println!("ok       {operation_name}"),
+        Err(err) => {
+            println!("FAILED   {operation_name}: {err}");
+            failures.push(operation_name.to_string());
+        }
+    };
+
+    for (operation_name, validator) in FIXTURE_OPERATIONS {
+        let response: Value = respond(transport, operation_name, json!({}));
+        report(operation_name, validator(response));
+    }
+
+    for operation_name in transport.operation_names() {
+        if !FIXTURE_OPERATIONS
+            .iter()
+            .any(|(name, _)| *name == operation_name)
+        {
+            report(operation_name, Err("no such operation".to_string()));
+        }
+    }
+
+    // Walk a cart through every cart operation
+    let product_id: Value = respond(transport, products_query::OPERATION_NAME, json!({}))["data"]
+        ["products"]["nodes"][0]["databaseId"]
+        .clone();
+    let response: Value = respond(
+        transport,
+        add_to_cart::OPERATION_NAME,
+        json!({ "productId": product_id, "quantity": 2 }),
+    );
+    report(add_to_cart::OPERATION_NAME, validate::<AddToCart>(response));
+
+    let response: Value = respond(
+        transport,
+        apply_coupon::OPERATION_NAME,
+        json!({ "code": "WELCOME10" }),
+    );
+    report(
+        apply_coupon::OPERATION_NAME,
+        validate::<ApplyCoupon>(response),
+    );
+
+    let response: Value = respond(
+        transport,
+        update_shipping_address::OPERATION_NAME,
+        json!({ "shipping": { "country": "AU", "state": "NSW", "postcode": "2000" } }),
+    );
+    report(
+        update_shipping_address::OPERATION_NAME,
+        validate::<UpdateShippingAddress>(response),
+    );
+
+    let response: Value = respond(
+        transport,
+        update_shipping_method::OPERATION_NAME,
+        json!({ "shippingMethods": ["local_pickup:2"] }),
+    );
+    report(
+        update_shipping_method::OPERATION_NAME,
+        validate::<UpdateShippingMethod>(response),
+    );
+
+    let response: Value = respond(transport, cart_query::OPERATION_NAME, json!({}));
+    let key: Value = response["data"]["cart"]["contents"]["nodes"][0]["key"].clone();
+    report(cart_query::OPERATION_NAME, validate::<CartQuery>(response));
+
+    let response: Value = respond(
+        transport,
+        update_item_quantities::OPERATION_NAME,
+        json!({ "items": [{ "key": key, "quantity": 1 }] }),
+    );
+    report(
+        update_item_quantities::OPERATION_NAME,
+        validate::<UpdateItemQuantities>(response),
+    );
+
+    let response: Value = respond(
+        transport,
+        remove_coupons::OPERATION_NAME,
+        json!({ "codes": ["welcome10"] }),
+    );
+    report(
+        remove_coupons::OPERATION_NAME,
+        validate::<RemoveCoupons>(response),
+    );
+
+    let response: Value = respond(
+        transport,
+        remove_items_from_cart::OPERATION_NAME,
+        json!({ "keys": [key] }),
+    );
+    report(
+        remove_items_from_cart::OPERATION_NAME,
+        validate::<RemoveItemsFromCart>(response),
+    );
+
+    let response: Value = respond(
+        transport,
+        restore_cart_items::OPERATION_NAME,
+        json!({ "keys": [key] }),
+    );
+    report(
+        restore_cart_items::OPERATION_NAME,
+        validate::<RestoreCartItems>(response),
+    );
+
+    let response: Value = respond(transport, empty_cart::OPERATION_NAME, json!({}));
+    report(empty_cart::OPERATION_NAME, validate::<EmptyCart>(response));
+
+    if failures.is_empty() {
+        0
+    } else {
+        eprintln!("\n{} fixture(s) failed validation", failures.len());
+        1
+    }
+}
+
+/// Answers an operation as the check session.
+///
+/// **Arguments**
+///
+/// * `transport` - The fixture transport.
+/// * `operation_name` - The operation name.
+/// * `variables` - The operation's variables.
+///
+/// **Returns**
+///
+/// * `Value` - The GraphQL response.
+fn respond(transport: &FixtureTransport, operation_name: &str, variables: Value) -> Value {
+    transport.respond(
+        CHECK_SESSION,
+        &json!({ "operationName": operation_name, "variables": variables }),
+    )
+}
+
+/// Checks that a response decodes into an operation's generated response type.
+///
+/// **Arguments**
+///
+/// * `response` - The GraphQL response.
+///
+/// **Returns**
+///
+/// * `Result<(), String>` - Why the response is invalid.
+fn validate<Q: GraphQLQuery>(response: Value) -> Result<(), String> {
+    let response: Response<Q::ResponseData> =
+        serde_json::from_value(response).map_err(|err| err.to_string())?;
+
+    match (response.data, response.errors) {
+        (_, Some(errors)) if !errors.is_empty() => Err(errors
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")),
+        (Some(_), _) => Ok(()),
+        (None, _) => Err("no data".to_string()),
+    }
+}
+
+/// Serves the fixtures over HTTP, one connection at a time.
+///
+/// **Arguments**
+///
+/// * `transport` - The fixture transport.
+/// * `addr` - The address to listen on.
+///
+/// **Returns**
+///
+/// * `i32` - The exit code.
+fn serve(transport: &FixtureTransport, addr: &str) -> i32 {
+    let listener: TcpListener = match TcpListener::bind(addr) {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("Failed to listen on {addr}: {err}");
+            return 1;
+        }
+    };
+    println!("Serving fixtures on http://{addr}{GRAPHQL_PATH}");
+
+    for stream in listener.incoming() {
+        let result = stream.and_then(|stream| handle(transport, stream));
+        if let Err(err) = result {
+            tracing::warn!("Failed to handle request: {err}");
+        }
+    }
+
+    0
+}
+
+/// Handles a single HTTP request.
+///
+/// **Arguments**
+///
+/// * `transport` - The fixture transport.
+/// * `stream` - The connection.
+fn handle(transport: &FixtureTransport, stream: TcpStream) -> io::Result<()> {
+    let mut reader: BufReader<&TcpStream> = BufReader::new(&stream);
+
+    let mut request_line: String = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method: &str = parts.next().unwrap_or_default();
+    let target: &str = parts.next().unwrap_or_default();
+
+    let mut content_length: usize = 0;
+    let mut session_token: Option<String> = None;
+    let mut auth_token: Option<String> = None;
+    loop {
+        let mut header: String = String::new();
+        reader.read_line(&mut header)?;
+        let Some((name, value)) = header.trim_end().split_once(':') else {
+            break;
+        };
+        let value: &str = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.parse().unwrap_or_default();
+        } else if name.eq_ignore_ascii_case(SESSION_TOKEN_KEY) {
+            session_token = Some(value.trim_start_matches("Session ").to_string());
+        } else if name.eq_ignore_ascii_case(AUTHORIZATION.as_str()) {
+            auth_token = Some(value.trim_start_matches("Bearer ").to_string());
+        }
+    }
+
+    let mut body: Vec<u8> = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let (status, session_token, body) = match Method::from_bytes(method.as_bytes()) {
+        Ok(Method::OPTIONS) => (StatusCode::NO_CONTENT, None, String::new()),
+        _ if path != GRAPHQL_PATH => (StatusCode::NOT_FOUND, None, String::new()),
+        Ok(method @ (Method::GET | Method::POST)) => {
+            let params: Vec<(&'static str, String)> = query
+                .split('&')
+                .filter_map(|pair| {
+                    let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    let key: String = decode_component(key);
+                    let key: &'static str = ["operationName", "variables", "extensions"]
+                        .into_iter()
+                        .find(|name| *name == key)?;
+                    Some((key, decode_component(value)))
+                })
+                .collect();
+            let body: Option<Value> = match serde_json::from_slice(&body) {
+                Ok(body) => Some(body),
+                Err(_) if method == Method::GET => None,
+                Err(err) => {
+                    let error = json!({ "errors": [{ "message": err.to_string() }] });
+                    return respond_http(
+                        &stream,
+                        StatusCode::BAD_REQUEST,
+                        None,
+                        &error.to_string(),
+                    );
+                }
+            };
+
+            let request = TransportRequest {
+                method,
+                params,
+                body,
+                session_token,
+                auth_token,
+                timeout: None,
+            };
+            match futures::executor::block_on(transport.send(request)) {
+                Ok(response) => (response.status, response.session_token, response.body),
+                Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, None, err.to_string()),
+            }
+        }
+        _ => (StatusCode::METHOD_NOT_ALLOWED, None, String::new()),
+    };
+
+    respond_http(&stream, status, session_token.as_deref(), &body)
+}
+
+/// Decodes a component of a query string, as in `application/x-www-form-urlencoded`.
+///
+/// **Arguments**
+///
+/// * `component` - The key or value to decode.
+///
+/// **Returns**
+///
+/// * `String` - The decoded text, with invalid UTF-8 replaced.
+fn decode_component(component: &str) -> String {
+    let mut bytes: Vec<u8> = Vec::with_capacity(component.len());
+    let mut input = component.bytes();
+    while let Some(byte) = input.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = input.clone().take(2).collect();
+                match std::str::from_utf8(&hex)
+                    .ok()
+                    .filter(|hex| hex.len() == 2 && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    Some(decoded) => {
+                        bytes.push(decoded);
+                        input.nth(1);
+                    }
+                    None => bytes.push(b'%'),
+                }
+            }
+            byte => bytes.push(byte),
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Writes an HTTP response, with the CORS headers the app needs.
+///
+/// **Arguments**
+///
+/// * `stream` - The connection.
+/// * `status` - The HTTP status.
+/// * `session_token` - The WooCommerce session token to issue, if any.
+/// * `body` - The response body.
+fn respond_http(
+    mut stream: &TcpStream,
+    status: StatusCode,
+    session_token: Option<&str>,
+    body: &str,
+) -> io::Result<()> {
+    let mut response: String = format!(
+        "HTTP/1.1 {} {}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Access-Control-Allow-Methods: GET, POST, OPTIONS\r\n\
+         Access-Control-Allow-Headers: content-type, authorization, {SESSION_TOKEN_KEY}\r\n\
+         Access-Control-Expose-Headers: {SESSION_TOKEN_KEY}\r\n\
+         Connection: close\r\n",
+        status.as_u16(),
+        status.canonical_reason().unwrap_or_default(),
+        body.len(),
+    );
+    if let Some(token) = session_token {
+        response.push_str(&format!("{SESSION_TOKEN_KEY}: {token}\r\n"));
+    }
+    response.push_str("\r\n");
+    response.push_str(body);
+
+    stream.write_all(response.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    /// Answers a cart operation as a session
+    fn cart_response(
+        transport: &FixtureTransport,
+        session: &str,
+        operation_name: &str,
+        variables: Value,
+    ) -> Value {
+        transport.respond(
+            session,
+            &json!({ "operationName": operation_name, "variables": variables }),
+        )
+    }
+
+    /// The product IDs and quantities of a cart in a response
+    fn lines(cart: &Value) -> Vec<(i64, i64)> {
+        cart["contents"]["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|line| {
+                (
+                    line["product"]["node"]["databaseId"]
+                        .as_i64()
+                        .unwrap_or_default(),
+                    line["quantity"].as_i64().unwrap_or_default(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn the_recorded_fixtures_pass_the_check() {
+        let transport = FixtureTransport::from_dir(Path::new(DEFAULT_DIR)).unwrap();
+
+        assert_eq!(check(&transport), 0);
+        assert_eq!(check(&FixtureTransport::embedded()), 0);
+    }
+
+    #[test]
+    fn each_session_has_its_own_cart() {
+        let transport = FixtureTransport::embedded();
+        let query = |session: &str| {
+            cart_response(&transport, session, cart_query::OPERATION_NAME, json!({}))["data"]
+                ["cart"]
+                .clone()
+        };
+
+        let added: Value = cart_response(
+            &transport,
+            "first",
+            add_to_cart::OPERATION_NAME,
+            json!({ "productId": 11, "quantity": 2 }),
+        );
+        validate::<AddToCart>(added.clone()).unwrap();
+        assert_eq!(lines(&added["data"]["addToCart"]["cart"]), [(11, 2)]);
+
+        assert_eq!(lines(&query("first")), [(11, 2)]);
+        assert_eq!(lines(&query("second")), []);
+
+        let key: Value = query("first")["contents"]["nodes"][0]["key"].clone();
+        let removed: Value = cart_response(
+            &transport,
+            "first",
+            remove_items_from_cart::OPERATION_NAME,
+            json!({ "keys": [key] }),
+        );
+        assert_eq!(lines(&removed["data"]["removeItemsFromCart"]["cart"]), []);
+
+        let restored: Value = cart_response(
+            &transport,
+            "first",
+            restore_cart_items::OPERATION_NAME,
+            json!({ "keys": [key] }),
+        );
+        validate::<RestoreCartItems>(restored.clone()).unwrap();
+        assert_eq!(
+            lines(&restored["data"]["restoreCartItems"]["cart"]),
+            [(11, 2)]
+        );
+
+        cart_response(&transport, "first", empty_cart::OPERATION_NAME, json!({}));
+        assert_eq!(lines(&query("first")), []);
+    }
+
+    #[test]
+    fn query_string_components_are_decoded() {
+        assert_eq!(
+            decode_component("%7B%22first%22%3A12%7D"),
+            r#"{"first":12}"#
+        );
+        assert_eq!(decode_component("a+b%20c"), "a b c");
+        assert_eq!(decode_component("caf%C3%A9"), "café");
+        assert_eq!(decode_component("100%+%zz%4"), "100% %zz%4");
+    }
+}
@@ -0,0 +1,24 @@
+// Modules
+use crate::graphql::persisted::PersistedQueries;
+
+/// Prints the persisted query manifest.
+///
+/// The manifest maps the sha256 hash of every operation in `src/graphql/schema/`
+/// to its document. Loading it into the backend allows it to only accept those
+/// operations.
+///
+/// **Returns**
+///
+/// * `i32` - The exit code.
+pub fn run() -> i32 {
+    match serde_json::to_string_pretty(&PersistedQueries::manifest()) {
+        Ok(manifest) => {
+            println!("{manifest}");
+            0
+        }
+        Err(err) => {
+            eprintln!("Failed to write the manifest: {err}");
+            1
+        }
+    }
+}
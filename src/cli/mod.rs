@@ -0,0 +1,40 @@
+pub mod fixtures;
+pub mod manifest;
+pub mod schema;
+
+/// Usage of the command-line tools
+const USAGE: &str = "Usage: wept [command]
+
+Commands:
+  manifest                                    Print the persisted query manifest as JSON
+  fixtures serve [--dir DIR] [--addr ADDR]    Serve the recorded fixtures as a GraphQL backend
+  fixtures check [--dir DIR]                  Validate the recorded fixtures against the schema
+  schema check [--endpoint URL] [--schema PATH]
+                                              Report how the backend's schema differs from schema.graphql
+  schema update [--endpoint URL] [--schema PATH]
+                                              Replace schema.graphql, if every operation is valid against it";
+
+/// Runs a command-line tool, if one was requested.
+///
+/// Without arguments the app is launched as usual.
+///
+/// **Arguments**
+///
+/// * `args` - The command-line arguments, without the program name.
+///
+/// **Returns**
+///
+/// * `Option<i32>` - The exit code of the tool, or `None` to launch the app.
+pub fn run(mut args: impl Iterator<Item = String>) -> Option<i32> {
+    let command: String = args.next()?;
+
+    Some(match command.as_str() {
+        "manifest" => manifest::run(),
+        "fixtures" => fixtures::run(args),
+        "schema" => schema::run(args),
+        _ => {
+            eprintln!("Unknown command: {command}\n\n{USAGE}");
+            2
+        }
+    })
+}
@@ -0,0 +1,455 @@
+use graphql_parser::schema::{Field, InputValue, Type, TypeDefinition};
+use std::fmt::{self, Display};
+
+// Modules
+use crate::cli::schema::index::{is_required, SchemaIndex};
+
+/// # Schema Change
+///
+/// A difference between two versions of a schema.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaChange {
+    /// Whether operations written against the old schema may fail
+    pub breaking: bool,
+    /// What changed
+    pub message: String,
+}
+
+impl SchemaChange {
+    /// A change that may break existing operations
+    fn breaking(message: String) -> Self {
+        Self {
+            breaking: true,
+            message,
+        }
+    }
+
+    /// A change that existing operations are unaffected by
+    fn safe(message: String) -> Self {
+        Self {
+            breaking: false,
+            message,
+        }
+    }
+}
+
+impl Display for SchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label: &str = if self.breaking { "BREAKING" } else { "safe" };
+        write!(f, "{label:<8} {}", self.message)
+    }
+}
+
+/// Lists the changes between two versions of a schema.
+///
+/// Descriptions are ignored. Additions are safe, except for required arguments and
+/// input fields. Removals are breaking, as are type changes other than making an
+/// output non-null or an input nullable.
+///
+/// **Arguments**
+///
+/// * `old` - The current schema.
+/// * `new` - The updated schema.
+///
+/// **Returns**
+///
+/// * `Vec<SchemaChange>` - The changes.
+pub fn diff(old: &SchemaIndex, new: &SchemaIndex) -> Vec<SchemaChange> {
+    let mut changes: Vec<SchemaChange> = Vec::new();
+
+    if old.query != new.query {
+        changes.push(SchemaChange::breaking(format!(
+            "Query root type changed from {} to {}",
+            old.query, new.query
+        )));
+    }
+    if old.mutation != new.mutation {
+        changes.push(SchemaChange::breaking(format!(
+            "Mutation root type changed from {} to {}",
+            old.mutation.unwrap_or("none"),
+            new.mutation.unwrap_or("none")
+        )));
+    }
+
+    for (name, old_type) in &old.types {
+        let Some(new_type) = new.types.get(name) else {
+            changes.push(SchemaChange::breaking(format!("Type {name} was removed")));
+            continue;
+        };
+
+        match (old_type, new_type) {
+            (TypeDefinition::Scalar(_), TypeDefinition::Scalar(_)) => {}
+            (TypeDefinition::Object(old_object), TypeDefinition::Object(new_object)) => {
+                diff_members(
+                    &mut changes,
+                    name,
+                    "interface",
+                    &old_object.implements_interfaces,
+                    &new_object.implements_interfaces,
+                );
+                diff_fields(&mut changes, name, &old_object.fields, &new_object.fields);
+            }
+            (
+                TypeDefinition::Interface(old_interface),
+                TypeDefinition::Interface(new_interface),
+            ) => {
+                diff_members(
+                    &mut changes,
+                    name,
+                    "interface",
+                    &old_interface.implements_interfaces,
+                    &new_interface.implements_interfaces,
+                );
+                diff_fields(
+                    &mut changes,
+                    name,
+                    &old_interface.fields,
+                    &new_interface.fields,
+                );
+            }
+            (TypeDefinition::Union(old_union), TypeDefinition::Union(new_union)) => {
+                diff_members(
+                    &mut changes,
+                    name,
+                    "member",
+                    &old_union.types,
+                    &new_union.types,
+                );
+            }
+            (TypeDefinition::Enum(old_enum), TypeDefinition::Enum(new_enum)) => {
+                let old_values: Vec<String> = old_enum
+                    .values
+                    .iter()
+                    .map(|value| value.name.clone())
+                    .collect();
+                let new_values: Vec<String> = new_enum
+                    .values
+                    .iter()
+                    .map(|value| value.name.clone())
+                    .collect();
+                diff_members(&mut changes, name, "value", &old_values, &new_values);
+            }
+            (TypeDefinition::InputObject(old_input), TypeDefinition::InputObject(new_input)) => {
+                diff_inputs(
+                    &mut changes,
+                    &format!("Input field {name}"),
+                    &old_input.fields,
+                    &new_input.fields,
+                );
+            }
+            _ => changes.push(SchemaChange::breaking(format!("Type {name} changed kind"))),
+        }
+    }
+
+    for name in new.types.keys() {
+        if !old.types.contains_key(name) {
+            changes.push(SchemaChange::safe(format!("Type {name} was added")));
+        }
+    }
+
+    changes
+}
+
+/// Lists the changes to the fields of an object or interface.
+///
+/// **Arguments**
+///
+/// * `changes` - The changes to add to.
+/// * `type_name` - The type's name.
+/// * `old` - The current fields.
+/// * `new` - The updated fields.
+fn diff_fields(
+    changes: &mut Vec<SchemaChange>,
+    type_name: &str,
+    old: &[Field<'static, String>],
+    new: &[Field<'static, String>],
+) {
+    for old_field in old {
+        let path: String = format!("{type_name}.{}", old_field.name);
+        let Some(new_field) = new.iter().find(|field| field.name == old_field.name) else {
+            changes.push(SchemaChange::breaking(format!("Field {path} was removed")));
+            continue;
+        };
+
+        if old_field.field_type != new_field.field_type {
+            let message: String = format!(
+                "Field {path} changed type from {} to {}",
+                old_field.field_type, new_field.field_type
+            );
+            changes.push(
+                if is_safe_output_change(&old_field.field_type, &new_field.field_type) {
+                    SchemaChange::safe(message)
+                } else {
+                    SchemaChange::breaking(message)
+                },
+            );
+        }
+        diff_inputs(
+            changes,
+            &format!("Argument {path}"),
+            &old_field.arguments,
+            &new_field.arguments,
+        );
+    }
+
+    for new_field in new {
+        if !old.iter().any(|field| field.name == new_field.name) {
+            changes.push(SchemaChange::safe(format!(
+                "Field {type_name}.{} was added",
+                new_field.name
+            )));
+        }
+    }
+}
+
+/// Lists the changes to the arguments of a field, or the fields of an input.
+///
+/// **Arguments**
+///
+/// * `changes` - The changes to add to.
+/// * `prefix` - Describes the owner, e.g. `Argument RootQuery.products`.
+/// * `old` - The current inputs.
+/// * `new` - The updated inputs.
+fn diff_inputs(
+    changes: &mut Vec<SchemaChange>,
+    prefix: &str,
+    old: &[InputValue<'static, String>],
+    new: &[InputValue<'static, String>],
+) {
+    for old_input in old {
+        let path: String = format!("{prefix}.{}", old_input.name);
+        let Some(new_input) = new.iter().find(|input| input.name == old_input.name) else {
+            changes.push(SchemaChange::breaking(format!("{path} was removed")));
+            continue;
+        };
+
+        if old_input.value_type != new_input.value_type {
+            let message: String = format!(
+                "{path} changed type from {} to {}",
+                old_input.value_type, new_input.value_type
+            );
+            changes.push(
+                if is_safe_input_change(&old_input.value_type, &new_input.value_type) {
+                    SchemaChange::safe(message)
+                } else {
+                    SchemaChange::breaking(message)
+                },
+            );
+        }
+    }
+
+    for new_input in new {
+        if old.iter().any(|input| input.name == new_input.name) {
+            continue;
+        }
+        if is_required(new_input) {
+            changes.push(SchemaChange::breaking(format!(
+                "{prefix}.{} was added, and is required",
+                new_input.name
+            )));
+        } else {
+            changes.push(SchemaChange::safe(format!(
+                "{prefix}.{} was added",
+                new_input.name
+            )));
+        }
+    }
+}
+
+/// Lists the changes to a list of names, like enum values or union members.
+///
+/// **Arguments**
+///
+/// * `changes` - The changes to add to.
+/// * `type_name` - The type's name.
+/// * `kind` - What the names are, e.g. `value`.
+/// * `old` - The current names.
+/// * `new` - The updated names.
+fn diff_members(
+    changes: &mut Vec<SchemaChange>,
+    type_name: &str,
+    kind: &str,
+    old: &[String],
+    new: &[String],
+) {
+    for name in old.iter().filter(|name| !new.contains(name)) {
+        changes.push(SchemaChange::breaking(format!(
+            "{type_name} {kind} {name} was removed"
+        )));
+    }
+    for name in new.iter().filter(|name| !old.contains(name)) {
+        changes.push(SchemaChange::safe(format!(
+            "{type_name} {kind} {name} was added"
+        )));
+    }
+}
+
+/// Checks whether a field's new type returns a subset of the old one's values.
+///
+/// **Arguments**
+///
+/// * `old` - The current type.
+/// * `new` - The updated type.
+///
+/// **Returns**
+///
+/// * `bool` - Whether the change is safe.
+fn is_safe_output_change(old: &Type<'static, String>, new: &Type<'static, String>) -> bool {
+    match (old, new) {
+        (Type::NamedType(old), Type::NamedType(new)) => old == new,
+        (Type::ListType(old), Type::ListType(new))
+        | (Type::NonNullType(old), Type::NonNullType(new)) => is_safe_output_change(old, new),
+        (old, Type::NonNullType(new)) => is_safe_output_change(old, new),
+        _ => false,
+    }
+}
+
+/// Checks whether an input's new type accepts every value the old one did.
+///
+/// **Arguments**
+///
+/// * `old` - The current type.
+/// * `new` - The updated type.
+///
+/// **Returns**
+///
+/// * `bool` - Whether the change is safe.
+fn is_safe_input_change(old: &Type<'static, String>, new: &Type<'static, String>) -> bool {
+    match (old, new) {
+        (Type::NamedType(old), Type::NamedType(new)) => old == new,
+        (Type::ListType(old), Type::ListType(new))
+        | (Type::NonNullType(old), Type::NonNullType(new)) => is_safe_input_change(old, new),
+        (Type::NonNullType(old), new) => is_safe_input_change(old, new),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::schema::SchemaDocument;
+
+    /// The changes between two schemas, as `(breaking, message)` pairs
+    fn changes(old: &str, new: &str) -> Vec<(bool, String)> {
+        let old: SchemaDocument = graphql_parser::parse_schema::<String>(old)
+            .unwrap()
+            .into_static();
+        let new: SchemaDocument = graphql_parser::parse_schema::<String>(new)
+            .unwrap()
+            .into_static();
+
+        diff(&SchemaIndex::new(&old), &SchemaIndex::new(&new))
+            .into_iter()
+            .map(|change| (change.breaking, change.message))
+            .collect()
+    }
+
+    #[test]
+    fn an_identical_schema_has_no_changes() {
+        let schema = "type Query { product(id: ID!): Product }\ntype Product { name: String }";
+
+        assert_eq!(changes(schema, schema), []);
+    }
+
+    #[test]
+    fn added_removed_and_changed_types_are_listed() {
+        assert_eq!(
+            changes(
+                "type Query { a: Int }\ntype Old { a: Int }\nscalar Kind",
+                "type Query { a: Int }\ntype New { a: Int }\nenum Kind { A }",
+            ),
+            [
+                (true, "Type Kind changed kind".to_string()),
+                (true, "Type Old was removed".to_string()),
+                (false, "Type New was added".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn added_removed_and_changed_fields_are_listed() {
+        assert_eq!(
+            changes(
+                "type Query { kept: Int, removed: Int, stricter: Int, looser: Int!, other: Int }",
+                "type Query { kept: Int, added: Int, stricter: Int!, looser: Int, other: String }",
+            ),
+            [
+                (true, "Field Query.removed was removed".to_string()),
+                (
+                    false,
+                    "Field Query.stricter changed type from Int to Int!".to_string()
+                ),
+                (
+                    true,
+                    "Field Query.looser changed type from Int! to Int".to_string()
+                ),
+                (
+                    true,
+                    "Field Query.other changed type from Int to String".to_string()
+                ),
+                (false, "Field Query.added was added".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn added_removed_and_changed_arguments_are_listed() {
+        assert_eq!(
+            changes(
+                "type Query { products(first: Int, after: String, id: ID!, tag: String): Int }",
+                "type Query { products(first: Int, id: ID, tag: String!, search: String, page: Int!): Int }",
+            ),
+            [
+                (
+                    true,
+                    "Argument Query.products.after was removed".to_string()
+                ),
+                (
+                    false,
+                    "Argument Query.products.id changed type from ID! to ID".to_string()
+                ),
+                (
+                    true,
+                    "Argument Query.products.tag changed type from String to String!".to_string()
+                ),
+                (
+                    false,
+                    "Argument Query.products.search was added".to_string()
+                ),
+                (
+                    true,
+                    "Argument Query.products.page was added, and is required".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn a_required_argument_with_a_default_is_safe_to_add() {
+        assert_eq!(
+            changes(
+                "type Query { products: Int }",
+                "type Query { products(first: Int! = 10): Int }",
+            ),
+            [(false, "Argument Query.products.first was added".to_string())]
+        );
+    }
+
+    #[test]
+    fn enum_values_and_input_fields_are_compared() {
+        assert_eq!(
+            changes(
+                "type Query { a: Int }\nenum Status { OPEN CLOSED }\ninput Filter { status: Status }",
+                "type Query { a: Int }\nenum Status { OPEN PENDING }\ninput Filter { status: Status, search: String! }",
+            ),
+            [
+                (
+                    true,
+                    "Input field Filter.search was added, and is required".to_string()
+                ),
+                (true, "Status value CLOSED was removed".to_string()),
+                (false, "Status value PENDING was added".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use graphql_parser::schema::{Definition, Field, InputValue, Type, TypeDefinition};
+use std::collections::BTreeMap;
+
+// Modules
+use crate::cli::schema::SchemaDocument;
+
+/// Scalars every schema has, without declaring them
+const BUILT_IN_SCALARS: &[&str] = &["String", "Int", "Float", "Boolean", "ID"];
+
+/// # Schema Index
+///
+/// A schema's types and root operation types, looked up by name.
+#[derive(Debug)]
+pub struct SchemaIndex<'a> {
+    /// The types, by name
+    pub types: BTreeMap<&'a str, &'a TypeDefinition<'static, String>>,
+    /// The query root type
+    pub query: &'a str,
+    /// The mutation root type
+    pub mutation: Option<&'a str>,
+}
+
+impl<'a> SchemaIndex<'a> {
+    /// Indexes a schema.
+    ///
+    /// Without a `schema` definition, the root types are `Query` and `Mutation`.
+    ///
+    /// **Arguments**
+    ///
+    /// * `document` - The schema.
+    ///
+    /// **Returns**
+    ///
+    /// * `SchemaIndex` - The index.
+    pub fn new(document: &'a SchemaDocument) -> Self {
+        let mut types = BTreeMap::new();
+        let mut query: &str = "Query";
+        let mut mutation: Option<&str> = None;
+        for definition in &document.definitions {
+            match definition {
+                Definition::SchemaDefinition(schema) => {
+                    query = schema.query.as_deref().unwrap_or(query);
+                    mutation = schema.mutation.as_deref();
+                }
+                Definition::TypeDefinition(type_definition) => {
+                    types.insert(type_name(type_definition), type_definition);
+                }
+                _ => {}
+            }
+        }
+        if mutation.is_none() && types.contains_key("Mutation") {
+            mutation = Some("Mutation");
+        }
+
+        Self {
+            types,
+            query,
+            mutation,
+        }
+    }
+
+    /// Gets the fields of an object or interface type.
+    ///
+    /// **Arguments**
+    ///
+    /// * `name` - The type name.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<&[Field]>` - The fields, or `None` for other types.
+    pub fn fields(&self, name: &str) -> Option<&'a [Field<'static, String>]> {
+        match self.types.get(name)? {
+            TypeDefinition::Object(object) => Some(&object.fields),
+            TypeDefinition::Interface(interface) => Some(&interface.fields),
+            _ => None,
+        }
+    }
+
+    /// Checks whether a type is a scalar or enum, which can't have a selection.
+    ///
+    /// **Arguments**
+    ///
+    /// * `name` - The type name.
+    ///
+    /// **Returns**
+    ///
+    /// * `bool` - Whether the type is a leaf.
+    pub fn is_leaf(&self, name: &str) -> bool {
+        BUILT_IN_SCALARS.contains(&name)
+            || matches!(
+                self.types.get(name),
+                Some(TypeDefinition::Scalar(_) | TypeDefinition::Enum(_))
+            )
+    }
+}
+
+/// Gets the name of a type definition.
+///
+/// **Arguments**
+///
+/// * `type_definition` - The type definition.
+///
+/// **Returns**
+///
+/// * `&str` - The type name.
+pub fn type_name<'a>(type_definition: &'a TypeDefinition<'static, String>) -> &'a str {
+    match type_definition {
+        TypeDefinition::Scalar(scalar) => &scalar.name,
+        TypeDefinition::Object(object) => &object.name,
+        TypeDefinition::Interface(interface) => &interface.name,
+        TypeDefinition::Union(union) => &union.name,
+        TypeDefinition::Enum(enumeration) => &enumeration.name,
+        TypeDefinition::InputObject(input) => &input.name,
+    }
+}
+
+/// Gets the named type inside any list or non-null wrappers.
+///
+/// **Arguments**
+///
+/// * `field_type` - The type.
+///
+/// **Returns**
+///
+/// * `&str` - The named type.
+pub fn base_type<'a>(field_type: &'a Type<'static, String>) -> &'a str {
+    match field_type {
+        Type::NamedType(name) => name,
+        Type::ListType(inner) | Type::NonNullType(inner) => base_type(inner),
+    }
+}
+
+/// Checks whether an argument or input field must be provided.
+///
+/// **Arguments**
+///
+/// * `input` - The argument or input field.
+///
+/// **Returns**
+///
+/// * `bool` - Whether it is non-null without a default.
+pub fn is_required(input: &InputValue<'static, String>) -> bool {
+    matches!(input.value_type, Type::NonNullType(_)) && input.default_value.is_none()
+}
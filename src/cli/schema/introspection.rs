@@ -0,0 +1,350 @@
+use graphql_parser::{
+    schema::{
+        Definition, Directive, EnumType, EnumValue, Field, InputObjectType, InputValue,
+        InterfaceType, ObjectType, ScalarType, SchemaDefinition, Type, TypeDefinition, UnionType,
+        Value as GraphQLValue,
+    },
+    Pos,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+// Modules
+use crate::cli::schema::SchemaDocument;
+
+/// Introspects every type, with enough nesting for any list or non-null wrapper
+const INTROSPECTION_QUERY: &str = r#"
+query IntrospectionQuery {
+  __schema {
+    queryType { name }
+    mutationType { name }
+    types {
+      kind
+      name
+      description
+      fields(includeDeprecated: true) {
+        name
+        description
+        args { ...InputValue }
+        type { ...TypeRef }
+        isDeprecated
+        deprecationReason
+      }
+      inputFields { ...InputValue }
+      interfaces { name }
+      enumValues(includeDeprecated: true) {
+        name
+        description
+        isDeprecated
+        deprecationReason
+      }
+      possibleTypes { name }
+    }
+  }
+}
+
+fragment InputValue on __InputValue {
+  name
+  description
+  type { ...TypeRef }
+  defaultValue
+}
+
+fragment TypeRef on __Type {
+  kind
+  name
+  ofType { kind name ofType { kind name ofType { kind name ofType { kind name
+    ofType { kind name ofType { kind name ofType { kind name } } } } } } }
+}
+"#;
+
+/// Scalars every schema has, which are left out of the SDL
+const BUILT_IN_SCALARS: &[&str] = &["String", "Int", "Float", "Boolean", "ID"];
+
+/// An introspection response
+#[derive(Deserialize, Debug)]
+struct IntrospectionResponse {
+    data: Option<IntrospectionData>,
+    #[serde(default)]
+    errors: Vec<Value>,
+}
+
+/// The data of an introspection response
+#[derive(Deserialize, Debug)]
+struct IntrospectionData {
+    #[serde(rename = "__schema")]
+    schema: IntrospectionSchema,
+}
+
+/// An introspected schema
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionSchema {
+    query_type: NamedRef,
+    mutation_type: Option<NamedRef>,
+    types: Vec<IntrospectionType>,
+}
+
+/// A reference to a named type
+#[derive(Deserialize, Debug)]
+struct NamedRef {
+    name: String,
+}
+
+/// An introspected type
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionType {
+    kind: String,
+    name: String,
+    description: Option<String>,
+    fields: Option<Vec<IntrospectionField>>,
+    input_fields: Option<Vec<IntrospectionInputValue>>,
+    interfaces: Option<Vec<NamedRef>>,
+    enum_values: Option<Vec<IntrospectionEnumValue>>,
+    possible_types: Option<Vec<NamedRef>>,
+}
+
+/// An introspected field
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionField {
+    name: String,
+    description: Option<String>,
+    args: Vec<IntrospectionInputValue>,
+    #[serde(rename = "type")]
+    field_type: TypeRef,
+    deprecation_reason: Option<String>,
+}
+
+/// An introspected argument or input field
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionInputValue {
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "type")]
+    value_type: TypeRef,
+    default_value: Option<String>,
+}
+
+/// An introspected enum value
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionEnumValue {
+    name: String,
+    description: Option<String>,
+    deprecation_reason: Option<String>,
+}
+
+/// A reference to a type, possibly wrapped in lists or non-null
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct TypeRef {
+    kind: String,
+    name: Option<String>,
+    of_type: Option<Box<TypeRef>>,
+}
+
+/// Introspects an endpoint's schema.
+///
+/// **Arguments**
+///
+/// * `endpoint` - The GraphQL endpoint.
+///
+/// **Returns**
+///
+/// * `Result<SchemaDocument, String>` - The schema, or why it could not be introspected.
+pub fn fetch(endpoint: &str) -> Result<SchemaDocument, String> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|err| err.to_string())?;
+    let response: IntrospectionResponse = runtime
+        .block_on(async {
+            reqwest::Client::new()
+                .post(endpoint)
+                .json(&json!({ "query": INTROSPECTION_QUERY }))
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await
+        })
+        .map_err(|err| format!("Failed to introspect {endpoint}: {err}"))?;
+
+    match response.data {
+        Some(data) if response.errors.is_empty() => into_document(data.schema),
+        _ => Err(format!(
+            "Failed to introspect {endpoint}, is public introspection enabled? {}",
+            Value::from(response.errors)
+        )),
+    }
+}
+
+/// Converts an introspected schema into a schema document.
+///
+/// **Arguments**
+///
+/// * `schema` - The introspected schema.
+///
+/// **Returns**
+///
+/// * `Result<SchemaDocument, String>` - The schema, or which default value is invalid.
+fn into_document(schema: IntrospectionSchema) -> Result<SchemaDocument, String> {
+    let mut definitions = vec![Definition::SchemaDefinition(SchemaDefinition {
+        query: Some(schema.query_type.name),
+        mutation: schema.mutation_type.map(|mutation| mutation.name),
+        ..Default::default()
+    })];
+
+    for introspected in schema.types {
+        if introspected.name.starts_with("__")
+            || BUILT_IN_SCALARS.contains(&introspected.name.as_str())
+        {
+            continue;
+        }
+
+        let type_definition = match introspected.kind.as_str() {
+            "SCALAR" => TypeDefinition::Scalar(ScalarType {
+                description: introspected.description,
+                ..ScalarType::new(introspected.name)
+            }),
+            "OBJECT" => TypeDefinition::Object(ObjectType {
+                description: introspected.description,
+                implements_interfaces: names(introspected.interfaces),
+                fields: fields(introspected.fields)?,
+                ..ObjectType::new(introspected.name)
+            }),
+            "INTERFACE" => TypeDefinition::Interface(InterfaceType {
+                description: introspected.description,
+                implements_interfaces: names(introspected.interfaces),
+                fields: fields(introspected.fields)?,
+                ..InterfaceType::new(introspected.name)
+            }),
+            "UNION" => TypeDefinition::Union(UnionType {
+                description: introspected.description,
+                types: names(introspected.possible_types),
+                ..UnionType::new(introspected.name)
+            }),
+            "ENUM" => TypeDefinition::Enum(EnumType {
+                description: introspected.description,
+                values: introspected
+                    .enum_values
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|value| EnumValue {
+                        description: value.description,
+                        directives: deprecated(value.deprecation_reason),
+                        ..EnumValue::new(value.name)
+                    })
+                    .collect(),
+                ..EnumType::new(introspected.name)
+            }),
+            "INPUT_OBJECT" => TypeDefinition::InputObject(InputObjectType {
+                description: introspected.description,
+                fields: input_values(introspected.input_fields.unwrap_or_default())?,
+                ..InputObjectType::new(introspected.name)
+            }),
+            kind => {
+                return Err(format!(
+                    "Type {} has unknown kind {kind}",
+                    introspected.name
+                ))
+            }
+        };
+        definitions.push(Definition::TypeDefinition(type_definition));
+    }
+
+    Ok(SchemaDocument { definitions })
+}
+
+/// Converts introspected fields.
+fn fields(fields: Option<Vec<IntrospectionField>>) -> Result<Vec<Field<'static, String>>, String> {
+    fields
+        .unwrap_or_default()
+        .into_iter()
+        .map(|field| {
+            Ok(Field {
+                position: Pos::default(),
+                description: field.description,
+                name: field.name,
+                arguments: input_values(field.args)?,
+                field_type: type_ref(field.field_type)?,
+                directives: deprecated(field.deprecation_reason),
+            })
+        })
+        .collect()
+}
+
+/// Converts introspected arguments or input fields.
+fn input_values(
+    inputs: Vec<IntrospectionInputValue>,
+) -> Result<Vec<InputValue<'static, String>>, String> {
+    inputs
+        .into_iter()
+        .map(|input| {
+            Ok(InputValue {
+                position: Pos::default(),
+                description: input.description,
+                default_value: input
+                    .default_value
+                    .map(|literal| default_value(&input.name, &literal))
+                    .transpose()?,
+                name: input.name,
+                value_type: type_ref(input.value_type)?,
+                directives: Vec::new(),
+            })
+        })
+        .collect()
+}
+
+/// Converts an introspected type reference.
+fn type_ref(type_ref: TypeRef) -> Result<Type<'static, String>, String> {
+    match (type_ref.kind.as_str(), type_ref.name, type_ref.of_type) {
+        ("NON_NULL", _, Some(inner)) => Ok(Type::NonNullType(Box::new(self::type_ref(*inner)?))),
+        ("LIST", _, Some(inner)) => Ok(Type::ListType(Box::new(self::type_ref(*inner)?))),
+        (_, Some(name), _) => Ok(Type::NamedType(name)),
+        (kind, None, _) => Err(format!("Type reference of kind {kind} has no name")),
+    }
+}
+
+/// Collects the names of introspected type references.
+fn names(refs: Option<Vec<NamedRef>>) -> Vec<String> {
+    refs.unwrap_or_default()
+        .into_iter()
+        .map(|named| named.name)
+        .collect()
+}
+
+/// Builds the `@deprecated` directive, for deprecated fields and enum values.
+fn deprecated(reason: Option<String>) -> Vec<Directive<'static, String>> {
+    reason
+        .map(|reason| Directive {
+            position: Pos::default(),
+            name: "deprecated".to_string(),
+            arguments: vec![("reason".to_string(), GraphQLValue::String(reason))],
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Parses a default value, which introspection returns as a GraphQL literal.
+///
+/// The parser only reads literals as part of a document, so the literal is parsed
+/// as the default of a placeholder input.
+fn default_value(name: &str, literal: &str) -> Result<GraphQLValue<'static, String>, String> {
+    let source: String = format!("input Default {{ value: String = {literal} }}");
+    let document = graphql_parser::parse_schema::<String>(&source)
+        .map_err(|err| format!("Invalid default value for {name}: {err}"))?;
+
+    match document.definitions.first() {
+        Some(Definition::TypeDefinition(TypeDefinition::InputObject(input))) => input
+            .fields
+            .first()
+            .and_then(|field| field.default_value.as_ref())
+            .map(GraphQLValue::into_static)
+            .ok_or_else(|| format!("Invalid default value for {name}")),
+        _ => Err(format!("Invalid default value for {name}")),
+    }
+}
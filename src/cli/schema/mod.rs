@@ -0,0 +1,315 @@
+pub mod diff;
+pub mod index;
+pub mod introspection;
+pub mod print;
+pub mod validate;
+
+use graphql_parser::schema::Document;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// Modules
+use crate::{
+    app::config::AppConfig,
+    cli::schema::{diff::SchemaChange, index::SchemaIndex},
+};
+
+/// A parsed GraphQL schema
+pub type SchemaDocument = Document<'static, String>;
+
+/// Default path of the checked-in schema
+const DEFAULT_SCHEMA: &str = "src/graphql/schema/schema.graphql";
+
+/// Runs the `schema` command.
+///
+/// * `wept schema check [--endpoint URL] [--schema PATH]` - Reports how the endpoint's
+///   schema differs from the checked-in one.
+/// * `wept schema update [--endpoint URL] [--schema PATH]` - Replaces the checked-in
+///   schema with the endpoint's, if every operation is still valid against it.
+///
+/// The endpoint defaults to the configured backend. Every operation next to the
+/// checked-in schema is validated against the endpoint's schema.
+///
+/// **Arguments**
+///
+/// * `args` - The arguments after `schema`.
+///
+/// **Returns**
+///
+/// * `i32` - The exit code.
+pub fn run(mut args: impl Iterator<Item = String>) -> i32 {
+    let subcommand: Option<String> = args.next();
+    let mut endpoint: Option<String> = None;
+    let mut schema_path: PathBuf = PathBuf::from(DEFAULT_SCHEMA);
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--endpoint", Some(value)) => endpoint = Some(value),
+            ("--schema", Some(value)) => schema_path = PathBuf::from(value),
+            _ => {
+                eprintln!("Unknown argument: {arg}\n\n{}", super::USAGE);
+                return 2;
+            }
+        }
+    }
+
+    let update: bool = match subcommand.as_deref() {
+        Some("check") => false,
+        Some("update") => true,
+        _ => {
+            eprintln!("{}", super::USAGE);
+            return 2;
+        }
+    };
+
+    let endpoint: String = match endpoint {
+        Some(endpoint) => endpoint,
+        None => match AppConfig::get() {
+            Ok(config) => format!("{}/{}", config.backend_host, config.backend_path),
+            Err(err) => {
+                eprintln!("{err}");
+                return 1;
+            }
+        },
+    };
+
+    match compare(&endpoint, &schema_path, update) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("{err}");
+            1
+        }
+    }
+}
+
+/// Compares the endpoint's schema with the checked-in one.
+///
+/// **Arguments**
+///
+/// * `endpoint` - The GraphQL endpoint to introspect.
+/// * `schema_path` - The checked-in schema.
+/// * `update` - Whether to replace the checked-in schema.
+///
+/// **Returns**
+///
+/// * `Result<i32, String>` - The exit code, or why the schemas could not be compared.
+fn compare(endpoint: &str, schema_path: &Path, update: bool) -> Result<i32, String> {
+    let current: String = fs::read_to_string(schema_path)
+        .map_err(|err| format!("{}: {err}", schema_path.display()))?;
+    let current: SchemaDocument = graphql_parser::parse_schema::<String>(&current)
+        .map_err(|err| format!("{}: {err}", schema_path.display()))?
+        .into_static();
+
+    println!("Introspecting {endpoint}");
+    let latest: SchemaDocument = introspection::fetch(endpoint)?;
+
+    let changes: Vec<SchemaChange> =
+        diff::diff(&SchemaIndex::new(&current), &SchemaIndex::new(&latest));
+    let breaking: usize = changes.iter().filter(|change| change.breaking).count();
+    for change in &changes {
+        println!("{change}");
+    }
+    println!("\n{} change(s), {breaking} breaking", changes.len());
+
+    let operations_dir: &Path = schema_path.parent().unwrap_or(Path::new("."));
+    let latest_index: SchemaIndex = SchemaIndex::new(&latest);
+    let mut invalid: usize = 0;
+    for path in operations(operations_dir, schema_path)? {
+        let source: String =
+            fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+        let errors: Vec<String> = validate::validate(&latest_index, &source);
+        if !errors.is_empty() {
+            invalid += 1;
+            println!("\nFAILED   {}", path.display());
+            for error in errors {
+                println!("         {error}");
+            }
+        }
+    }
+
+    if invalid > 0 {
+        eprintln!("\n{invalid} operation(s) are invalid against the endpoint's schema");
+        return Ok(1);
+    }
+    println!("\nEvery operation is valid against the endpoint's schema");
+
+    if update {
+        fs::write(schema_path, print::print(&latest))
+            .map_err(|err| format!("{}: {err}", schema_path.display()))?;
+        println!("Updated {}", schema_path.display());
+        Ok(0)
+    } else {
+        Ok(i32::from(breaking > 0))
+    }
+}
+
+/// Finds the operations in a directory, and its subdirectories.
+///
+/// **Arguments**
+///
+/// * `dir` - The directory.
+/// * `schema_path` - The schema, which is skipped.
+///
+/// **Returns**
+///
+/// * `Result<Vec<PathBuf>, String>` - The paths of the `.graphql` operations.
+fn operations(dir: &Path, schema_path: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut paths: Vec<PathBuf> = Vec::new();
+    let entries = fs::read_dir(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+    for entry in entries {
+        let path: PathBuf = entry.map_err(|err| err.to_string())?.path();
+        if path.is_dir() {
+            paths.extend(operations(&path, schema_path)?);
+        } else if path.extension().is_some_and(|ext| ext == "graphql") && path != schema_path {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphql::transport::stub::{StubReply, StubServer};
+    use serde_json::{json, Value};
+
+    /// The checked-in schema
+    const SCHEMA: &str = "type Query {\n  product(id: ID!): Product\n}\n\ntype Product {\n  id: ID!\n  name: String\n}\n";
+
+    /// An operation against the schema
+    const OPERATION: &str = "query ProductQuery($id: ID!) { product(id: $id) { id name } }";
+
+    /// A reference to a named type
+    fn named(kind: &str, name: &str) -> Value {
+        json!({ "kind": kind, "name": name, "ofType": null })
+    }
+
+    /// A non-null reference to a type
+    fn non_null(of_type: Value) -> Value {
+        json!({ "kind": "NON_NULL", "name": null, "ofType": of_type })
+    }
+
+    /// An introspected field
+    fn field(name: &str, field_type: Value, args: Value) -> Value {
+        json!({
+            "name": name,
+            "description": null,
+            "args": args,
+            "type": field_type,
+            "isDeprecated": false,
+            "deprecationReason": null,
+        })
+    }
+
+    /// An introspection response for the schema, with or without `Product.name`
+    fn introspection(with_name: bool) -> StubReply {
+        let id = json!([{
+            "name": "id",
+            "description": null,
+            "type": non_null(named("SCALAR", "ID")),
+            "defaultValue": null,
+        }]);
+        let mut product_fields = vec![field("id", non_null(named("SCALAR", "ID")), json!([]))];
+        if with_name {
+            product_fields.push(field("name", named("SCALAR", "String"), json!([])));
+        }
+        let object = |name: &str, fields: Vec<Value>| {
+            json!({
+                "kind": "OBJECT",
+                "name": name,
+                "description": null,
+                "fields": fields,
+                "inputFields": null,
+                "interfaces": [],
+                "enumValues": null,
+                "possibleTypes": null,
+            })
+        };
+
+        StubReply::json(
+            200,
+            json!({ "data": { "__schema": {
+                "queryType": { "name": "Query" },
+                "mutationType": null,
+                "types": [
+                    object("Query", vec![field("product", named("OBJECT", "Product"), id)]),
+                    object("Product", product_fields),
+                    { "kind": "SCALAR", "name": "ID" },
+                    { "kind": "SCALAR", "name": "String" },
+                ],
+            } } }),
+        )
+    }
+
+    /// A directory with the checked-in schema and an operation
+    fn schema_dir(name: &str) -> PathBuf {
+        let dir: PathBuf =
+            std::env::temp_dir().join(format!("wept-schema-{}-{name}", std::process::id()));
+        fs::create_dir_all(dir.join("product")).unwrap();
+        fs::write(dir.join("schema.graphql"), SCHEMA).unwrap();
+        fs::write(dir.join("product/product_query.graphql"), OPERATION).unwrap();
+        dir
+    }
+
+    #[test]
+    fn an_unchanged_schema_passes_the_check() {
+        let server = StubServer::start(vec![introspection(true)]);
+        let dir: PathBuf = schema_dir("unchanged");
+
+        let code = compare(&server.endpoint(), &dir.join("schema.graphql"), false);
+
+        assert_eq!(code, Ok(0));
+        assert!(server.requests()[0].body.contains("IntrospectionQuery"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn a_schema_that_breaks_an_operation_is_not_written() {
+        let server = StubServer::start(vec![introspection(false)]);
+        let dir: PathBuf = schema_dir("breaking");
+
+        let code = compare(&server.endpoint(), &dir.join("schema.graphql"), true);
+
+        assert_eq!(code, Ok(1));
+        assert_eq!(
+            fs::read_to_string(dir.join("schema.graphql")).unwrap(),
+            SCHEMA
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn an_updated_schema_is_written() {
+        let server = StubServer::start(vec![introspection(true)]);
+        let dir: PathBuf = schema_dir("update");
+        fs::write(
+            dir.join("schema.graphql"),
+            "type Query {\n  product(id: ID!): Product\n}\n\ntype Product {\n  id: ID!\n}\n",
+        )
+        .unwrap();
+
+        let code = compare(&server.endpoint(), &dir.join("schema.graphql"), true);
+
+        assert_eq!(code, Ok(0));
+        let written: String = fs::read_to_string(dir.join("schema.graphql")).unwrap();
+        assert!(written.contains("name: String"), "{written}");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn an_unreachable_endpoint_is_an_error() {
+        let dir: PathBuf = schema_dir("unreachable");
+
+        let result = compare(
+            "http://127.0.0.1:9/graphql",
+            &dir.join("schema.graphql"),
+            false,
+        );
+
+        assert!(result.is_err_and(|err| err.starts_with("Failed to introspect")));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,321 @@
+use graphql_parser::schema::{
+    Definition, Directive, EnumValue, Field, InputValue, TypeDefinition, Value as GraphQLValue,
+};
+
+// Modules
+use crate::cli::schema::{index::type_name, SchemaDocument};
+
+/// Width lines are wrapped at, as Prettier does
+const PRINT_WIDTH: usize = 80;
+
+/// The reason printed as a bare `@deprecated`
+const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
+
+/// Prints a schema as SDL.
+///
+/// Matches the layout of `schema.graphql`, which was printed by `graphql-js` and
+/// formatted by Prettier, so updates only show what changed. Types are sorted by
+/// name, and descriptions are printed as block strings.
+///
+/// **Arguments**
+///
+/// * `document` - The schema.
+///
+/// **Returns**
+///
+/// * `String` - The SDL.
+pub fn print(document: &SchemaDocument) -> String {
+    let mut blocks: Vec<String> = Vec::new();
+    let mut types: Vec<&TypeDefinition<'static, String>> = Vec::new();
+    for definition in &document.definitions {
+        match definition {
+            Definition::SchemaDefinition(schema) => {
+                let mut block: String = "schema {\n".to_string();
+                for (operation, root) in [
+                    ("query", &schema.query),
+                    ("mutation", &schema.mutation),
+                    ("subscription", &schema.subscription),
+                ] {
+                    if let Some(root) = root {
+                        block.push_str(&format!("  {operation}: {root}\n"));
+                    }
+                }
+                block.push('}');
+                blocks.push(block);
+            }
+            Definition::TypeDefinition(type_definition) => types.push(type_definition),
+            _ => {}
+        }
+    }
+
+    types.sort_by_cached_key(|type_definition| {
+        let name: &str = type_name(type_definition);
+        (name.to_lowercase(), name.to_string())
+    });
+    blocks.extend(types.into_iter().map(print_type));
+
+    blocks.join("\n\n") + "\n"
+}
+
+/// Prints a type definition.
+fn print_type(type_definition: &TypeDefinition<'static, String>) -> String {
+    match type_definition {
+        TypeDefinition::Scalar(scalar) => {
+            description(&scalar.description, "", true) + &format!("scalar {}", scalar.name)
+        }
+        TypeDefinition::Object(object) => {
+            description(&object.description, "", true)
+                + &format!(
+                    "type {}{} {}",
+                    object.name,
+                    implements(&object.implements_interfaces),
+                    fields(&object.fields)
+                )
+        }
+        TypeDefinition::Interface(interface) => {
+            description(&interface.description, "", true)
+                + &format!(
+                    "interface {}{} {}",
+                    interface.name,
+                    implements(&interface.implements_interfaces),
+                    fields(&interface.fields)
+                )
+        }
+        TypeDefinition::Union(union) => {
+            let line: String = format!("union {} = {}", union.name, union.types.join(" | "));
+            // Prettier breaks described unions, even when they fit
+            let union_type: String = if union.description.is_none() && line.len() <= PRINT_WIDTH {
+                line
+            } else {
+                format!("union {} =\n    {}", union.name, union.types.join("\n  | "))
+            };
+            description(&union.description, "", true) + &union_type
+        }
+        TypeDefinition::Enum(enumeration) => {
+            let values: Vec<String> = enumeration
+                .values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| enum_value(value, i == 0))
+                .collect();
+            description(&enumeration.description, "", true)
+                + &format!("enum {} {{\n{}\n}}", enumeration.name, values.join("\n"))
+        }
+        TypeDefinition::InputObject(input) => {
+            let fields: Vec<String> = input
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    description(&field.description, "  ", i == 0)
+                        + &with_directives("  ", &input_value(field), &field.directives)
+                })
+                .collect();
+            description(&input.description, "", true)
+                + &format!("input {} {{\n{}\n}}", input.name, fields.join("\n"))
+        }
+    }
+}
+
+/// Prints the interfaces a type implements.
+fn implements(interfaces: &[String]) -> String {
+    if interfaces.is_empty() {
+        String::new()
+    } else {
+        format!(" implements {}", interfaces.join(" & "))
+    }
+}
+
+/// Prints the fields of an object or interface.
+fn fields(fields: &[Field<'static, String>]) -> String {
+    let fields: Vec<String> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| description(&field.description, "  ", i == 0) + &print_field(field))
+        .collect();
+
+    format!("{{\n{}\n}}", fields.join("\n"))
+}
+
+/// Prints a field, breaking its arguments over several lines if they are
+/// described or don't fit.
+fn print_field(field: &Field<'static, String>) -> String {
+    let inline: String = if field.arguments.is_empty() {
+        format!("{}: {}", field.name, field.field_type)
+    } else {
+        let arguments: Vec<String> = field.arguments.iter().map(input_value).collect();
+        format!(
+            "{}({}): {}",
+            field.name,
+            arguments.join(", "),
+            field.field_type
+        )
+    };
+    let is_described: bool = field
+        .arguments
+        .iter()
+        .any(|argument| argument.description.is_some());
+    if !is_described && 2 + inline.len() <= PRINT_WIDTH {
+        return with_directives("  ", &inline, &field.directives);
+    }
+
+    let arguments: Vec<String> = field
+        .arguments
+        .iter()
+        .enumerate()
+        .map(|(i, argument)| {
+            description(&argument.description, "    ", i == 0)
+                + &with_directives("    ", &input_value(argument), &argument.directives)
+        })
+        .collect();
+    let broken: String = format!(
+        "{}(\n{}\n  ): {}",
+        field.name,
+        arguments.join("\n"),
+        field.field_type
+    );
+
+    with_directives("  ", &broken, &field.directives)
+}
+
+/// Prints an argument or input field, without its description or directives.
+fn input_value(input: &InputValue<'static, String>) -> String {
+    match &input.default_value {
+        Some(default) => format!("{}: {} = {}", input.name, input.value_type, default),
+        None => format!("{}: {}", input.name, input.value_type),
+    }
+}
+
+/// Prints an enum value.
+fn enum_value(value: &EnumValue<'static, String>, first: bool) -> String {
+    description(&value.description, "  ", first)
+        + &with_directives("  ", &value.name, &value.directives)
+}
+
+/// Indents a definition, and appends its directives.
+///
+/// Directives that don't fit move to the next line, and their arguments are
+/// broken over several lines if they still don't fit.
+fn with_directives(
+    indentation: &str,
+    definition: &str,
+    directives: &[Directive<'static, String>],
+) -> String {
+    let mut printed: String = format!("{indentation}{definition}");
+    for directive in directives {
+        let arguments: Vec<String> = directive
+            .arguments
+            .iter()
+            .filter(|(name, value)| {
+                directive.name != "deprecated"
+                    || name != "reason"
+                    || *value != GraphQLValue::String(DEFAULT_DEPRECATION_REASON.to_string())
+            })
+            .map(|(name, value)| format!("{name}: {value}"))
+            .collect();
+        let inline: String = if arguments.is_empty() {
+            format!("@{}", directive.name)
+        } else {
+            format!("@{}({})", directive.name, arguments.join(", "))
+        };
+
+        let last_line: usize = printed.lines().last().unwrap_or_default().len();
+        if last_line + 1 + inline.len() <= PRINT_WIDTH {
+            printed.push_str(&format!(" {inline}"));
+        } else if indentation.len() + 2 + inline.len() <= PRINT_WIDTH || arguments.is_empty() {
+            printed.push_str(&format!("\n{indentation}  {inline}"));
+        } else {
+            printed.push_str(&format!(
+                "\n{indentation}  @{}(\n{indentation}    {}\n{indentation}  )",
+                directive.name,
+                arguments.join(&format!("\n{indentation}    "))
+            ));
+        }
+    }
+
+    printed
+}
+
+/// Prints a description as a block string, preceded by a blank line unless it
+/// is the first in its block.
+///
+/// Long lines are wrapped at word boundaries, as `graphql-js` does.
+fn description(description: &Option<String>, indentation: &str, first: bool) -> String {
+    let Some(description) = description else {
+        return String::new();
+    };
+
+    let max_len: usize = 120 - indentation.len();
+    let mut printed: String = if first {
+        String::new()
+    } else {
+        "\n".to_string()
+    };
+    printed.push_str(&format!("{indentation}\"\"\"\n"));
+    for line in description.split('\n') {
+        let lines: Vec<String> = if line.chars().count() < max_len + 5 {
+            vec![line.to_string()]
+        } else {
+            break_line(line, max_len)
+        };
+        for line in lines {
+            let line: String = line.replace("\"\"\"", "\\\"\"\"");
+            if line.is_empty() {
+                printed.push('\n');
+            } else {
+                printed.push_str(&format!("{indentation}{line}\n"));
+            }
+        }
+    }
+    printed.push_str(&format!("{indentation}\"\"\"\n"));
+
+    printed
+}
+
+/// Breaks a long line into lines of about 80 characters, at spaces.
+///
+/// Each line takes as many words as fit in `max_len - 40` characters, and at
+/// least 15 characters. Lines without such a break are left whole.
+fn break_line(line: &str, max_len: usize) -> Vec<String> {
+    let chars: Vec<char> = line.chars().collect();
+    let max_chunk: usize = max_len - 40;
+
+    // Each chunk starts at the start of the line or at a space, and ends before a
+    // space or at the end of the line
+    let mut chunks: Vec<(usize, usize)> = Vec::new();
+    let mut start: usize = 0;
+    while start < chars.len() {
+        let text_start: usize = match (start, chars[start]) {
+            (_, ' ') => start + 1,
+            (0, _) => start,
+            _ => {
+                start += 1;
+                continue;
+            }
+        };
+        let longest: usize = (text_start + max_chunk).min(chars.len());
+        let end = (text_start + 15..=longest)
+            .rev()
+            .find(|end| *end == chars.len() || chars[*end] == ' ');
+        match end {
+            Some(end) => {
+                chunks.push((start, end));
+                start = end;
+            }
+            None => start += 1,
+        }
+    }
+    if chunks.len() < 2 {
+        return vec![line.to_string()];
+    }
+
+    let text = |from: usize, to: usize| chars[from..to].iter().collect::<String>();
+    let mut lines: Vec<String> = Vec::new();
+    for (i, (start, end)) in chunks.iter().enumerate() {
+        let gap_end: usize = chunks.get(i + 1).map_or(chars.len(), |next| next.0);
+        let from: usize = if i == 0 { 0 } else { start + 1 };
+        lines.push(text(from, *end) + &text(*end, gap_end));
+    }
+
+    lines
+}
@@ -0,0 +1,309 @@
+use graphql_parser::query::{
+    Definition, Document, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
+    TypeCondition, VariableDefinition,
+};
+use graphql_parser::schema::TypeDefinition;
+use std::collections::HashMap;
+
+// Modules
+use crate::cli::schema::index::{base_type, is_required, SchemaIndex};
+
+/// A parsed GraphQL operation document
+type OperationDocument = Document<'static, String>;
+
+/// Validates the operations and fragments in a document against a schema.
+///
+/// Covers what the generated types rely on: every selected field, argument, type
+/// condition and variable type must exist, required arguments must be provided, and
+/// only objects, interfaces and unions can have a selection.
+///
+/// **Arguments**
+///
+/// * `schema` - The schema.
+/// * `source` - The document's source.
+///
+/// **Returns**
+///
+/// * `Vec<String>` - Why the document is invalid, if it is.
+pub fn validate(schema: &SchemaIndex, source: &str) -> Vec<String> {
+    let document: OperationDocument = match graphql_parser::parse_query::<String>(source) {
+        Ok(document) => document.into_static(),
+        Err(err) => return vec![err.to_string()],
+    };
+
+    let mut validator = Validator {
+        schema,
+        fragments: document
+            .definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                Definition::Fragment(fragment) => Some((fragment.name.as_str(), fragment)),
+                Definition::Operation(_) => None,
+            })
+            .collect(),
+        errors: Vec::new(),
+    };
+    for definition in &document.definitions {
+        match definition {
+            Definition::Operation(operation) => validator.operation(operation),
+            Definition::Fragment(fragment) => validator.fragment(fragment),
+        }
+    }
+
+    validator.errors
+}
+
+/// # Validator
+///
+/// Walks a document's selections, collecting errors.
+struct Validator<'a> {
+    /// The schema
+    schema: &'a SchemaIndex<'a>,
+    /// The document's fragments, by name
+    fragments: HashMap<&'a str, &'a FragmentDefinition<'static, String>>,
+    /// The errors found so far
+    errors: Vec<String>,
+}
+
+impl Validator<'_> {
+    /// Validates an operation from its root type.
+    fn operation(&mut self, operation: &OperationDefinition<'static, String>) {
+        let (root, variables, selection_set) = match operation {
+            OperationDefinition::SelectionSet(selection_set) => {
+                (Some(self.schema.query), &[][..], selection_set)
+            }
+            OperationDefinition::Query(query) => (
+                Some(self.schema.query),
+                &query.variable_definitions[..],
+                &query.selection_set,
+            ),
+            OperationDefinition::Mutation(mutation) => (
+                self.schema.mutation,
+                &mutation.variable_definitions[..],
+                &mutation.selection_set,
+            ),
+            OperationDefinition::Subscription(subscription) => (
+                None,
+                &subscription.variable_definitions[..],
+                &subscription.selection_set,
+            ),
+        };
+
+        self.variables(variables);
+        match root {
+            Some(root) => self.selection_set(root, selection_set),
+            None => self
+                .errors
+                .push("The schema does not support this operation type".to_string()),
+        }
+    }
+
+    /// Validates a fragment from its type condition.
+    fn fragment(&mut self, fragment: &FragmentDefinition<'static, String>) {
+        let TypeCondition::On(type_name) = &fragment.type_condition;
+        if self.schema.types.contains_key(type_name.as_str()) {
+            self.selection_set(type_name, &fragment.selection_set);
+        } else {
+            self.errors.push(format!(
+                "Fragment {} is on unknown type {type_name}",
+                fragment.name
+            ));
+        }
+    }
+
+    /// Checks that every variable has an input type.
+    fn variables(&mut self, variables: &[VariableDefinition<'static, String>]) {
+        for variable in variables {
+            let type_name: &str = base_type(&variable.var_type);
+            let is_input: bool = self.schema.is_leaf(type_name)
+                || matches!(
+                    self.schema.types.get(type_name),
+                    Some(TypeDefinition::InputObject(_))
+                );
+            if !is_input {
+                self.errors.push(format!(
+                    "Variable ${} has unknown input type {type_name}",
+                    variable.name
+                ));
+            }
+        }
+    }
+
+    /// Validates the selections on a type.
+    fn selection_set(&mut self, parent: &str, selection_set: &SelectionSet<'static, String>) {
+        for selection in &selection_set.items {
+            match selection {
+                Selection::Field(field) if field.name == "__typename" => {}
+                Selection::Field(field) => {
+                    let Some(definition) = self
+                        .schema
+                        .fields(parent)
+                        .and_then(|fields| fields.iter().find(|def| def.name == field.name))
+                    else {
+                        self.errors
+                            .push(format!("Field {parent}.{} does not exist", field.name));
+                        continue;
+                    };
+
+                    for (argument, _) in &field.arguments {
+                        if !definition.arguments.iter().any(|def| def.name == *argument) {
+                            self.errors.push(format!(
+                                "Argument {parent}.{}({argument}) does not exist",
+                                field.name
+                            ));
+                        }
+                    }
+                    for argument in definition.arguments.iter().filter(|def| is_required(def)) {
+                        if !field
+                            .arguments
+                            .iter()
+                            .any(|(name, _)| *name == argument.name)
+                        {
+                            self.errors.push(format!(
+                                "Argument {parent}.{}({}) is required",
+                                field.name, argument.name
+                            ));
+                        }
+                    }
+
+                    let field_type: &str = base_type(&definition.field_type);
+                    let is_leaf: bool = self.schema.is_leaf(field_type);
+                    let has_selection: bool = !field.selection_set.items.is_empty();
+                    if is_leaf && has_selection {
+                        self.errors.push(format!(
+                            "Field {parent}.{} is a {field_type}, which has no fields",
+                            field.name
+                        ));
+                    } else if !is_leaf && !has_selection {
+                        self.errors.push(format!(
+                            "Field {parent}.{} is a {field_type}, which needs a selection",
+                            field.name
+                        ));
+                    } else if has_selection {
+                        self.selection_set(field_type, &field.selection_set);
+                    }
+                }
+                Selection::InlineFragment(fragment) => match &fragment.type_condition {
+                    Some(TypeCondition::On(type_name))
+                        if !self.schema.types.contains_key(type_name.as_str()) =>
+                    {
+                        self.errors
+                            .push(format!("Fragment on unknown type {type_name}"));
+                    }
+                    Some(TypeCondition::On(type_name)) => {
+                        self.selection_set(type_name, &fragment.selection_set)
+                    }
+                    None => self.selection_set(parent, &fragment.selection_set),
+                },
+                Selection::FragmentSpread(spread) => {
+                    if !self.fragments.contains_key(spread.fragment_name.as_str()) {
+                        self.errors
+                            .push(format!("Fragment {} does not exist", spread.fragment_name));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::schema::SchemaDocument;
+
+    /// A small store schema
+    const SCHEMA: &str = "
+        type Query {
+            product(id: ID!): Product
+            products(first: Int, where: ProductFilter): [Product]
+        }
+        type Mutation { addToCart(input: AddToCartInput!): Product }
+        type Product { id: ID! name: String price: Price }
+        type Price { amount: Float currency: String }
+        input ProductFilter { search: String }
+        input AddToCartInput { productId: Int! quantity: Int }
+    ";
+
+    /// Validates an operation against the schema
+    fn errors(source: &str) -> Vec<String> {
+        let schema: SchemaDocument = graphql_parser::parse_schema::<String>(SCHEMA)
+            .unwrap()
+            .into_static();
+
+        validate(&SchemaIndex::new(&schema), source)
+    }
+
+    #[test]
+    fn valid_operations_have_no_errors() {
+        assert_eq!(
+            errors(
+                "query Products($first: Int, $where: ProductFilter) {
+                    products(first: $first, where: $where) { ...Details }
+                }
+                fragment Details on Product { __typename id name price { amount } }"
+            ),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            errors(
+                "mutation AddToCart($input: AddToCartInput!) {
+                    addToCart(input: $input) { ... on Product { id } }
+                }"
+            ),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn unknown_fields_and_arguments_are_errors() {
+        assert_eq!(
+            errors("{ product(id: 1, slug: \"a\") { id sku } }"),
+            [
+                "Argument Query.product(slug) does not exist",
+                "Field Product.sku does not exist",
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_required_arguments_are_errors() {
+        assert_eq!(
+            errors("{ product { id } }"),
+            ["Argument Query.product(id) is required"]
+        );
+    }
+
+    #[test]
+    fn selections_must_match_the_field_type() {
+        assert_eq!(
+            errors("{ product(id: 1) { name { length } price } }"),
+            [
+                "Field Product.name is a String, which has no fields",
+                "Field Product.price is a Price, which needs a selection",
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_types_and_fragments_are_errors() {
+        assert_eq!(
+            errors(
+                "query Search($where: Filter) {
+                    products(where: $where) { ...Missing ... on Variation { id } }
+                }
+                fragment Orphan on Variation { id }"
+            ),
+            [
+                "Variable $where has unknown input type Filter",
+                "Fragment Missing does not exist",
+                "Fragment on unknown type Variation",
+                "Fragment Orphan is on unknown type Variation",
+            ]
+        );
+    }
+
+    #[test]
+    fn unparseable_documents_are_errors() {
+        assert_eq!(errors("query {").len(), 1);
+    }
+}
@@ -0,0 +1,307 @@
+// Modules
+use crate::{
+    app::{
+        cart::CartService,
+        error::{AppError, AppErrorKind},
+        state::{RestoreStatus, State, CUSTOMER, CUSTOMER_RESTORE},
+    },
+    graphql::{
+        cache::QueryCache,
+        client::GraphQLClient,
+        models::{
+            cart::cart_query,
+            customer::{
+                customer_query, forget_session, login, register_customer, reset_user_password,
+                send_password_reset_email, CustomerQuery, ForgetSession, Login, RegisterCustomer,
+                ResetUserPassword, SendPasswordResetEmail,
+            },
+        },
+    },
+    models::{
+        address::non_empty,
+        customer::{Customer, Registration},
+    },
+};
+
+/// Auth controller
+///
+/// Signs customers in and out, keeping the stored auth tokens, the WooCommerce
+/// session and the `CUSTOMER` signal in sync.
+#[derive(Clone)]
+pub struct AuthController {
+    client: GraphQLClient,
+}
+
+impl AuthController {
+    /// Creates a new auth controller.
+    ///
+    /// **Returns**
+    ///
+    /// * `AuthController` - The new auth controller.
+    pub fn new() -> Self {
+        Self {
+            client: GraphQLClient::new().with_invalidations(&[cart_query::OPERATION_NAME]),
+        }
+    }
+
+    /// Signs a customer in.
+    ///
+    /// The auth and refresh tokens are stored, and the customer's WooCommerce
+    /// session replaces the guest session, so their saved cart is used.
+    ///
+    /// **Arguments**
+    ///
+    /// * `username` - The username or email address.
+    /// * `password` - The password.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Customer, AppError>` - The signed-in customer.
+    pub async fn login(&self, username: String, password: String) -> Result<Customer, AppError> {
+        let variables = login::Variables { username, password };
+        let response = self
+            .client
+            .execute_mutation::<_, Login>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        let Some(login::LoginLogin {
+            auth_token: Some(auth_token),
+            refresh_token,
+            session_token,
+            customer: Some(customer),
+        }) = response.login
+        else {
+            return Err(AppError::new(
+                AppErrorKind::Unauthorized,
+                "The username or password is incorrect.",
+                Some("The login mutation returned no auth token or customer"),
+                None,
+            ));
+        };
+
+        Ok(Self::sign_in(
+            &auth_token,
+            refresh_token.as_deref(),
+            session_token.as_deref(),
+            customer.into(),
+        ))
+    }
+
+    /// Registers a new customer, and signs them in.
+    ///
+    /// Registration is sent with the guest's WooCommerce session, which WooGraphQL
+    /// hands over to the new customer, so the guest cart is kept.
+    ///
+    /// **Arguments**
+    ///
+    /// * `registration` - The details to register with.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Customer, AppError>` - The new, signed-in customer.
+    pub async fn register(&self, registration: &Registration) -> Result<Customer, AppError> {
+        let variables = register_customer::Variables {
+            email: registration.email.trim().to_string(),
+            password: registration.password.clone(),
+            first_name: non_empty(&registration.first_name),
+            last_name: non_empty(&registration.last_name),
+            billing: registration.billing.as_ref().map(Into::into),
+        };
+        let response = self
+            .client
+            .execute_mutation::<_, RegisterCustomer>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        let Some(register_customer::RegisterCustomerRegisterCustomer {
+            auth_token: Some(auth_token),
+            refresh_token,
+            customer: Some(mut customer),
+        }) = response.register_customer
+        else {
+            return Err(AppError::new(
+                AppErrorKind::GraphQL,
+                "Your account could not be created.",
+                Some("The registerCustomer mutation returned no auth token or customer"),
+                None,
+            ));
+        };
+
+        let session_token: Option<String> = customer.session_token.take();
+        Ok(Self::sign_in(
+            &auth_token,
+            refresh_token.as_deref(),
+            session_token.as_deref(),
+            customer.into(),
+        ))
+    }
+
+    /// Stores a signed-in customer's tokens, and publishes them to `CUSTOMER`.
+    ///
+    /// **Arguments**
+    ///
+    /// * `auth_token` - The JWT auth token.
+    /// * `refresh_token` - The JWT refresh token.
+    /// * `session_token` - The customer's WooCommerce session token.
+    /// * `customer` - The customer.
+    ///
+    /// **Returns**
+    ///
+    /// * `Customer` - The customer.
+    fn sign_in(
+        auth_token: &str,
+        refresh_token: Option<&str>,
+        session_token: Option<&str>,
+        customer: Customer,
+    ) -> Customer {
+        State::set_auth_token(auth_token);
+        if let Some(refresh_token) = refresh_token {
+            State::set_refresh_token(refresh_token);
+        }
+        if let Some(session_token) = session_token {
+            State::set_session_token(session_token);
+        }
+        *CUSTOMER.write() = Some(customer.clone());
+
+        customer
+    }
+
+    /// Signs the customer out.
+    ///
+    /// Asks WooCommerce to forget the session, then removes the tokens and the
+    /// cart, so the next request starts a new guest session. The customer is signed
+    /// out locally even if the backend can't be reached.
+    pub async fn logout(&self) {
+        if let Err(err) = self
+            .client
+            .execute_mutation::<_, ForgetSession>(forget_session::Variables {})
+            .await
+        {
+            tracing::warn!("Failed to forget the WooCommerce session: {err}");
+        }
+
+        State::clear_auth_tokens();
+        State::clear_session_token();
+        QueryCache::invalidate(cart_query::OPERATION_NAME);
+        *CUSTOMER.write() = None;
+
+        CartService::reset();
+    }
+
+    /// Emails a password reset link.
+    ///
+    /// **Arguments**
+    ///
+    /// * `username` - The username or email address of the account.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the email was sent.
+    pub async fn send_password_reset_email(&self, username: String) -> Result<(), AppError> {
+        let variables = send_password_reset_email::Variables { username };
+        let response = self
+            .client
+            .execute_mutation::<_, SendPasswordResetEmail>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        match response.send_password_reset_email {
+            Some(payload) if payload.success == Some(true) => Ok(()),
+            _ => Err(AppError::new(
+                AppErrorKind::GraphQL,
+                "The password reset email could not be sent.",
+                Some("The sendPasswordResetEmail mutation did not succeed"),
+                None,
+            )),
+        }
+    }
+
+    /// Resets a password with the key from a password reset link.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The reset key.
+    /// * `login` - The username of the account.
+    /// * `password` - The new password.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the password was reset.
+    pub async fn reset_password(
+        &self,
+        key: String,
+        login: String,
+        password: String,
+    ) -> Result<(), AppError> {
+        let variables = reset_user_password::Variables {
+            key,
+            login,
+            password,
+        };
+        let response = self
+            .client
+            .execute_mutation::<_, ResetUserPassword>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        match response
+            .reset_user_password
+            .and_then(|payload| payload.user)
+        {
+            Some(_) => Ok(()),
+            None => Err(AppError::new(
+                AppErrorKind::GraphQL,
+                "Your password could not be reset.",
+                Some("The resetUserPassword mutation returned no user"),
+                None,
+            )),
+        }
+    }
+
+    /// Gets the signed-in customer from the API.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Option<Customer>, AppError>` - The customer, or `None` for guests.
+    pub async fn get_customer(&self) -> Result<Option<Customer>, AppError> {
+        if State::get_auth_token().is_none() {
+            return Ok(None);
+        }
+
+        let variables = customer_query::Variables {};
+        let response = self
+            .client
+            .execute_query::<_, CustomerQuery, customer_query::ResponseData>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(response.customer.map(Customer::from))
+    }
+
+    /// Restores the signed-in customer when the app starts.
+    ///
+    /// Stored tokens that the backend no longer accepts are removed. Other
+    /// failures keep the tokens, and are recorded in `CUSTOMER_RESTORE` so the
+    /// restore can be retried.
+    pub async fn restore(&self) {
+        *CUSTOMER_RESTORE.write() = RestoreStatus::Pending;
+
+        let status: RestoreStatus = match self.get_customer().await {
+            Ok(customer) => {
+                *CUSTOMER.write() = customer;
+                RestoreStatus::Done
+            }
+            Err(err) if err.kind == AppErrorKind::Unauthorized => {
+                State::clear_auth_tokens();
+                *CUSTOMER.write() = None;
+                RestoreStatus::Done
+            }
+            Err(err) => {
+                tracing::warn!("Failed to restore the signed-in customer: {err}");
+                RestoreStatus::Failed(err.public_message)
+            }
+        };
+        *CUSTOMER_RESTORE.write() = status;
+    }
+}
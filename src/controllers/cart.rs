@@ -1,32 +1,31 @@
-use wasm_bindgen::prelude::*;
+use std::time::Duration;
 
 // Modules
 use crate::{
-    app::{
-        error::{AppError, GraphQLErrorWrapper},
-        state::SESSION_TOKEN_KEY,
-    },
+    app::error::{AppError, AppErrorKind},
     graphql::{
-        client::{GraphQLClient, Response},
+        cache::CachePolicy,
+        client::GraphQLClient,
         models::cart::{
-            add_to_cart, cart_query, remove_items_from_cart, update_item_quantities, AddToCart,
-            CartQuery, RemoveItemsFromCart, UpdateItemQuantities,
+            add_to_cart, apply_coupon, cart_query, empty_cart, remove_coupons,
+            remove_items_from_cart, restore_cart_items, update_item_quantities, AddToCart,
+            ApplyCoupon, CartQuery, EmptyCart, RemoveCoupons, RemoveItemsFromCart,
+            RestoreCartItems, UpdateItemQuantities,
         },
     },
+    models::cart::Cart,
 };
 
-#[wasm_bindgen]
-extern "C" {
-    // LocalStorage setItem
-    #[wasm_bindgen(js_namespace = localStorage)]
-    fn setItem(key: &str, value: &str);
-
-    // LocalStorage getItem
-    #[wasm_bindgen(js_namespace = localStorage)]
-    fn getItem(key: &str) -> Option<String>;
-}
+/// Cache policy for the cart query
+///
+/// Kept short as the cart can also change in other tabs. Cart mutations
+/// invalidate it, so it is always refetched after a change made here.
+const CACHE_POLICY: CachePolicy = CachePolicy::new(Duration::from_secs(30), Duration::ZERO);
 
 /// Cart controller
+///
+/// Every cart mutation returns the whole cart, so it doesn't have to be refetched.
+/// The cart state is written by `CartService`, not here.
 #[derive(Clone)]
 pub struct CartController {
     client: GraphQLClient,
@@ -39,42 +38,28 @@ impl CartController {
     ///
     /// * `CartController` - The new cart controller.
     pub fn new() -> Self {
-        let mut client = GraphQLClient::new();
-        if let Some(token) = Self::get_session_token() {
-            client.set_session_token(token);
+        Self {
+            client: GraphQLClient::new().with_invalidations(&[cart_query::OPERATION_NAME]),
         }
-        Self { client }
-    }
-
-    /// Gets the session token from local storage.
-    ///
-    /// **Returns**
-    ///
-    /// * `Option<String>` - The session token.
-    pub fn get_session_token() -> Option<String> {
-        getItem(SESSION_TOKEN_KEY)
-    }
-
-    /// Sets the session token in local storage.
-    ///
-    /// **Arguments**
-    ///
-    /// * `token` - The session token to set.
-    pub fn set_session_token(token: &str) {
-        setItem(SESSION_TOKEN_KEY, token);
     }
 
     /// Gets the cart from the API.
     ///
     /// **Returns**
     ///
-    /// * `Result<Option<cart_query::ResponseData>, AppError>` - The cart data.
-    pub async fn get_cart(&self) -> Result<Option<cart_query::ResponseData>, AppError> {
+    /// * `Result<Cart, AppError>` - The cart.
+    pub async fn get_cart(&self) -> Result<Cart, AppError> {
         let variables = cart_query::Variables {};
-        self.client
-            .execute_query::<_, CartQuery, _>(variables)
+        let response: Option<cart_query::ResponseData> = self
+            .client
+            .execute_cached_query::<_, CartQuery, _>(variables, CACHE_POLICY)
             .await
-            .map_err(|err| AppError::from(GraphQLErrorWrapper(err)))
+            .map_err(AppError::from)?;
+
+        Ok(response
+            .and_then(|response| response.cart)
+            .map(Cart::from)
+            .unwrap_or_default())
     }
 
     /// Adds an item to the cart.
@@ -86,12 +71,8 @@ impl CartController {
     ///
     /// **Returns**
     ///
-    /// * `Result<Option<add_to_cart::ResponseData>, AppError>` - The cart data.
-    pub async fn add_to_cart(
-        &mut self,
-        product_id: i64,
-        quantity: i64,
-    ) -> Result<Option<add_to_cart::ResponseData>, AppError> {
+    /// * `Result<Cart, AppError>` - The updated cart.
+    pub async fn add_to_cart(&self, product_id: i64, quantity: i64) -> Result<Cart, AppError> {
         // Build the variables for the mutation.
         let variables = add_to_cart::Variables {
             product_id,
@@ -103,28 +84,15 @@ impl CartController {
             .client
             .execute_mutation::<_, AddToCart>(variables)
             .await
-            .map_err(GraphQLErrorWrapper)?;
-
-        // Set the session token in local storage.
-        if let Some(token) = response.headers().get(SESSION_TOKEN_KEY) {
-            if let Ok(token_str) = token.to_str() {
-                Self::set_session_token(token_str);
-                self.client.set_session_token(token_str.to_string());
-            }
-        }
+            .map_err(AppError::from)?;
 
-        // Parse the response.
-        let response_body: Response<add_to_cart::ResponseData> = response.json().await?;
-
-        // Check for errors.
-        if let Some(errors) = response_body.errors {
-            if !errors.is_empty() {
-                Err(errors)?
-            }
-        }
-
-        // Return the cart data.
-        Ok(response_body.data)
+        payload_cart(
+            response
+                .add_to_cart
+                .and_then(|payload| payload.cart)
+                .map(Cart::from),
+            add_to_cart::OPERATION_NAME,
+        )
     }
 
     /// Updates the quantity of an item in the cart.
@@ -132,20 +100,16 @@ impl CartController {
     /// **Arguments**
     ///
     /// * `key` - The key of the item to update.
-    /// * `quantity` - The quantity of the item to update.
+    /// * `quantity` - The quantity of the item, which removes it when zero.
     ///
     /// **Returns**
     ///
-    /// * `Result<Option<update_item_quantities::ResponseData>, AppError>` - The cart data.
-    pub async fn update_item_quantity(
-        &mut self,
-        key: String,
-        quantity: i64,
-    ) -> Result<Option<update_item_quantities::ResponseData>, AppError> {
+    /// * `Result<Cart, AppError>` - The updated cart.
+    pub async fn update_item_quantity(&self, key: String, quantity: i64) -> Result<Cart, AppError> {
         // Build the variables for the mutation.
         let variables = update_item_quantities::Variables {
             items: vec![Some(update_item_quantities::CartItemQuantityInput {
-                key: key.clone(),
+                key,
                 quantity,
             })],
         };
@@ -155,38 +119,32 @@ impl CartController {
             .client
             .execute_mutation::<_, UpdateItemQuantities>(variables)
             .await
-            .map_err(GraphQLErrorWrapper)?;
-
-        // Parse the response.
-        let response_body: Response<update_item_quantities::ResponseData> = response.json().await?;
-
-        // Check for errors.
-        if let Some(errors) = response_body.errors {
-            if !errors.is_empty() {
-                Err(errors)?
-            }
-        }
+            .map_err(AppError::from)?;
 
-        // Return the cart data.
-        Ok(response_body.data)
+        payload_cart(
+            response
+                .update_item_quantities
+                .and_then(|payload| payload.cart)
+                .map(Cart::from),
+            update_item_quantities::OPERATION_NAME,
+        )
     }
 
-    /// Removes an item from the cart.
+    /// Removes items from the cart.
+    ///
+    /// WooCommerce keeps the removed items in the session, so they can be restored.
     ///
     /// **Arguments**
     ///
-    /// * `key` - The key of the item to remove.
+    /// * `keys` - The keys of the items to remove.
     ///
     /// **Returns**
     ///
-    /// * `Result<Option<remove_items_from_cart::ResponseData>, AppError>` - The cart data.
-    pub async fn remove_item_from_cart(
-        &mut self,
-        key: String,
-    ) -> Result<Option<remove_items_from_cart::ResponseData>, AppError> {
+    /// * `Result<Cart, AppError>` - The updated cart.
+    pub async fn remove_items(&self, keys: Vec<String>) -> Result<Cart, AppError> {
         // Build the variables for the mutation.
         let variables = remove_items_from_cart::Variables {
-            keys: vec![Some(key.clone())],
+            keys: keys.into_iter().map(Some).collect(),
         };
 
         // Execute the mutation.
@@ -194,19 +152,205 @@ impl CartController {
             .client
             .execute_mutation::<_, RemoveItemsFromCart>(variables)
             .await
-            .map_err(GraphQLErrorWrapper)?;
+            .map_err(AppError::from)?;
 
-        // Parse the response.
-        let response_body: Response<remove_items_from_cart::ResponseData> = response.json().await?;
+        payload_cart(
+            response
+                .remove_items_from_cart
+                .and_then(|payload| payload.cart)
+                .map(Cart::from),
+            remove_items_from_cart::OPERATION_NAME,
+        )
+    }
 
-        // Check for errors.
-        if let Some(errors) = response_body.errors {
-            if !errors.is_empty() {
-                Err(errors)?
-            }
+    /// Restores items removed from the cart.
+    ///
+    /// **Arguments**
+    ///
+    /// * `keys` - The keys of the removed items.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Cart, AppError>` - The updated cart, or an error if an item
+    ///   could not be restored.
+    pub async fn restore_items(&self, keys: Vec<String>) -> Result<Cart, AppError> {
+        let variables = restore_cart_items::Variables {
+            keys: Some(keys.iter().cloned().map(Some).collect()),
+        };
+        let response = self
+            .client
+            .execute_mutation::<_, RestoreCartItems>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        let cart: Cart = payload_cart(
+            response
+                .restore_cart_items
+                .and_then(|payload| payload.cart)
+                .map(Cart::from),
+            restore_cart_items::OPERATION_NAME,
+        )?;
+
+        // WooCommerce skips the items it no longer has, such as after the cart was emptied
+        if let Some(key) = keys
+            .iter()
+            .find(|key| !cart.lines.iter().any(|line| line.key == **key))
+        {
+            return Err(AppError::new(
+                AppErrorKind::NotFound,
+                "The item can no longer be restored.".to_string(),
+                Some(format!(
+                    "The {} mutation did not restore {key}",
+                    restore_cart_items::OPERATION_NAME
+                )),
+                None,
+            ));
         }
 
-        // Return the cart data.
-        Ok(response_body.data)
+        Ok(cart)
+    }
+
+    /// Empties the cart, removing its items and coupons.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Cart, AppError>` - The emptied cart.
+    pub async fn empty_cart(&self) -> Result<Cart, AppError> {
+        let variables = empty_cart::Variables {};
+        let response = self
+            .client
+            .execute_mutation::<_, EmptyCart>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        payload_cart(
+            response
+                .empty_cart
+                .and_then(|payload| payload.cart)
+                .map(Cart::from),
+            empty_cart::OPERATION_NAME,
+        )
+    }
+
+    /// Applies a coupon to the cart.
+    ///
+    /// WooCommerce rejects unknown, expired and already applied coupons with a
+    /// message naming the coupon.
+    ///
+    /// **Arguments**
+    ///
+    /// * `code` - The coupon code.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Cart, AppError>` - The cart, with the coupon applied.
+    pub async fn apply_coupon(&self, code: String) -> Result<Cart, AppError> {
+        let variables = apply_coupon::Variables { code };
+        let response = self
+            .client
+            .execute_mutation::<_, ApplyCoupon>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        payload_cart(
+            response
+                .apply_coupon
+                .and_then(|payload| payload.cart)
+                .map(Cart::from),
+            apply_coupon::OPERATION_NAME,
+        )
+    }
+
+    /// Removes a coupon from the cart.
+    ///
+    /// **Arguments**
+    ///
+    /// * `code` - The coupon code.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Cart, AppError>` - The cart, without the coupon.
+    pub async fn remove_coupon(&self, code: String) -> Result<Cart, AppError> {
+        let variables = remove_coupons::Variables {
+            codes: Some(vec![Some(code)]),
+        };
+        let response = self
+            .client
+            .execute_mutation::<_, RemoveCoupons>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        payload_cart(
+            response
+                .remove_coupons
+                .and_then(|payload| payload.cart)
+                .map(Cart::from),
+            remove_coupons::OPERATION_NAME,
+        )
+    }
+}
+
+/// Gets the cart returned by a cart mutation.
+///
+/// **Arguments**
+///
+/// * `cart` - The cart in the mutation's payload.
+/// * `operation_name` - The mutation's operation name.
+///
+/// **Returns**
+///
+/// * `Result<Cart, AppError>` - The cart, or an error if the payload had none.
+pub fn payload_cart(cart: Option<Cart>, operation_name: &str) -> Result<Cart, AppError> {
+    cart.ok_or_else(|| {
+        AppError::new(
+            AppErrorKind::GraphQL,
+            "Your cart could not be updated.".to_string(),
+            Some(format!("The {operation_name} mutation returned no cart")),
+            None,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn controllers_share_the_session_cart() {
+        let first = CartController::new();
+        let second = CartController::new();
+
+        block_on(first.add_to_cart(11, 1)).unwrap();
+        let cart: Cart = block_on(second.add_to_cart(12, 2)).unwrap();
+
+        let mut quantities: Vec<(i64, i64)> = cart
+            .lines
+            .iter()
+            .map(|line| (line.product_id, line.quantity))
+            .collect();
+        quantities.sort();
+        assert_eq!(quantities, [(11, 1), (12, 2)]);
+        assert_eq!(block_on(first.get_cart()).unwrap(), cart);
+    }
+
+    #[test]
+    fn coupon_mutations_return_the_cart() {
+        let controller = CartController::new();
+        block_on(controller.add_to_cart(11, 1)).unwrap();
+
+        let cart: Cart = block_on(controller.apply_coupon("WELCOME10".to_string())).unwrap();
+        let codes: Vec<&str> = cart
+            .applied_coupons
+            .iter()
+            .map(|coupon| coupon.code.as_str())
+            .collect();
+        assert_eq!(codes, ["welcome10"]);
+        assert!(cart.totals.has_discount());
+        assert_eq!(block_on(controller.get_cart()).unwrap(), cart);
+
+        let cart: Cart = block_on(controller.remove_coupon("welcome10".to_string())).unwrap();
+        assert!(cart.applied_coupons.is_empty());
+        assert!(!cart.totals.has_discount());
     }
 }
@@ -1,11 +1,14 @@
 use async_trait::async_trait;
+use std::time::Duration;
 
 // Modules
 use crate::{
-    app::error::{AppError, AppErrorKind, GraphQLErrorWrapper},
+    app::error::{AppError, AppErrorKind},
     controllers::entity::EntityController,
     graphql::{
+        cache::CachePolicy,
         client::GraphQLClient,
+        policy::RequestPolicy,
         models::category::{
             product_category, product_categories,
             ProductCategory as ProductCategoryGraphQLQuery, 
@@ -17,6 +20,12 @@ use crate::{
     },
 };
 
+/// Cache policy for category queries
+///
+/// The category tree is rarely edited.
+const CACHE_POLICY: CachePolicy =
+    CachePolicy::new(Duration::from_secs(300), Duration::from_secs(1800));
+
 /// Category controller
 #[derive(Debug, Clone)]
 pub struct CategoryController {
@@ -26,9 +35,17 @@ pub struct CategoryController {
 
 impl CategoryController {
     /// Creates a new CategoryController
+    ///
+    /// Category queries resolve their product lists, so they are not batched with
+    /// the lighter queries that render alongside them, and their large documents
+    /// are sent as persisted queries.
     pub fn new() -> Self {
         Self {
-            client: GraphQLClient::new(),
+            client: GraphQLClient::new().with_policy(
+                RequestPolicy::default()
+                    .with_batching(false)
+                    .with_persisted_queries(true),
+            ),
         }
     }
 
@@ -61,21 +78,21 @@ impl CategoryController {
         // Execute the request
         let request = self
             .client
-            .execute_query::<
+            .execute_cached_query::<
                 product_category::Variables,
                 ProductCategoryGraphQLQuery,
                 product_category::ResponseData,
-            >(request)
+            >(request, CACHE_POLICY)
             .await
-            .map_err(|e_string| {
+            .map_err(|err| {
                 AppError::new_with_source(
-                    AppErrorKind::Api, 
+                    AppErrorKind::from(&err),
                     "A network or GraphQL error occurred while fetching the category.".to_string(),
                     Some(format!(
                         "Failed to fetch category data for slug '{}'.",
                         slug
                     )),
-                    GraphQLErrorWrapper(e_string),
+                    err,
                 )
             })?;
 
@@ -143,17 +160,17 @@ impl EntityController for CategoryController {
         // Execute the request
         let request = self
             .client
-            .execute_query::<_, ProductCategoriesGraphQLQuery, product_categories::ResponseData>(request)
+            .execute_cached_query::<_, ProductCategoriesGraphQLQuery, product_categories::ResponseData>(request, CACHE_POLICY)
             .await
-            .map_err(|e_string| {
+            .map_err(|err| {
                 AppError::new_with_source(
-                    AppErrorKind::Api, 
+                    AppErrorKind::from(&err),
                     "A network or GraphQL error occurred while fetching categories.".to_string(),
                     Some(format!(
                         "Failed to fetch product categories list. Page size: {:?}, After: {:?}.",
                         page_size, after
                     )),
-                    GraphQLErrorWrapper(e_string),
+                    err,
                 )
             })?;
 
@@ -0,0 +1,165 @@
+// Modules
+use crate::{
+    app::{
+        cart::CartService,
+        error::{AppError, AppErrorKind},
+        state::PLACED_ORDER,
+    },
+    controllers::cart::payload_cart,
+    graphql::{
+        client::GraphQLClient,
+        models::{
+            cart::cart_query,
+            checkout::{
+                checkout, checkout_query, update_shipping_method, Checkout, CheckoutQuery,
+                UpdateShippingMethod,
+            },
+        },
+    },
+    models::{
+        address::non_empty,
+        cart::Cart,
+        checkout::{CheckoutDetails, CheckoutOptions},
+        order::Order,
+    },
+};
+
+/// Checkout controller
+///
+/// Turns the cart into an order. The checkout options are never cached, as the
+/// shipping rates and totals change with the cart and the customer's address.
+#[derive(Clone)]
+pub struct CheckoutController {
+    client: GraphQLClient,
+}
+
+impl CheckoutController {
+    /// Creates a new checkout controller.
+    ///
+    /// **Returns**
+    ///
+    /// * `CheckoutController` - The new checkout controller.
+    pub fn new() -> Self {
+        Self {
+            client: GraphQLClient::new().with_invalidations(&[cart_query::OPERATION_NAME]),
+        }
+    }
+
+    /// Gets the shipping rates, payment gateways and totals of the cart.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<CheckoutOptions, AppError>` - The checkout options.
+    pub async fn get_options(&self) -> Result<CheckoutOptions, AppError> {
+        let variables = checkout_query::Variables {};
+        self.client
+            .execute_query::<_, CheckoutQuery, checkout_query::ResponseData>(variables)
+            .await
+            .map(CheckoutOptions::from)
+            .map_err(AppError::from)
+    }
+
+    /// Chooses the shipping rate of each shipping package.
+    ///
+    /// **Arguments**
+    ///
+    /// * `shipping_methods` - The chosen rate ID of each shipping package.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Cart, AppError>` - The cart, with the rates chosen.
+    pub async fn update_shipping_method(
+        &self,
+        shipping_methods: Vec<String>,
+    ) -> Result<Cart, AppError> {
+        let variables = update_shipping_method::Variables {
+            shipping_methods: Some(shipping_methods.into_iter().map(Some).collect()),
+        };
+        let response = self
+            .client
+            .execute_mutation::<_, UpdateShippingMethod>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        payload_cart(
+            response
+                .update_shipping_method
+                .and_then(|payload| payload.cart)
+                .map(Cart::from),
+            update_shipping_method::OPERATION_NAME,
+        )
+    }
+
+    /// Places an order for the cart.
+    ///
+    /// WooCommerce empties the cart once the order is placed, so the stored cart
+    /// is emptied too. The order is published to `PLACED_ORDER`.
+    ///
+    /// **Arguments**
+    ///
+    /// * `details` - The details to check out with.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Order, AppError>` - The placed order.
+    pub async fn checkout(&self, details: &CheckoutDetails) -> Result<Order, AppError> {
+        let variables = checkout::Variables {
+            billing: Some((&details.billing).into()),
+            shipping: details.shipping.as_ref().map(Into::into),
+            ship_to_different_address: Some(details.shipping.is_some()),
+            shipping_method: Some(details.shipping_methods.iter().cloned().map(Some).collect()),
+            payment_method: non_empty(&details.payment.payment_method),
+            customer_note: non_empty(&details.customer_note),
+            meta_data: Some(
+                details
+                    .payment
+                    .meta_data
+                    .iter()
+                    .map(|(key, value)| {
+                        Some(checkout::MetaDataInput {
+                            id: None,
+                            key: key.clone(),
+                            value: value.clone(),
+                        })
+                    })
+                    .collect(),
+            ),
+        };
+        let response = self
+            .client
+            .execute_mutation::<_, Checkout>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        let Some(checkout::CheckoutCheckout {
+            result,
+            order: Some(order),
+            ..
+        }) = response.checkout
+        else {
+            return Err(AppError::new(
+                AppErrorKind::GraphQL,
+                "Your order could not be placed.",
+                Some("The checkout mutation returned no order"),
+                None,
+            ));
+        };
+        if result.as_deref().is_some_and(|result| result != "success") {
+            return Err(AppError::new(
+                AppErrorKind::GraphQL,
+                "Your order could not be placed.".to_string(),
+                Some(format!(
+                    "The checkout mutation returned result '{result:?}'"
+                )),
+                None,
+            ));
+        }
+
+        let order: Order = order.into();
+        *PLACED_ORDER.write() = Some(order.clone());
+
+        CartService::reset();
+
+        Ok(order)
+    }
+}
@@ -0,0 +1,120 @@
+// Modules
+use crate::{
+    app::{
+        error::{AppError, AppErrorKind},
+        state::CUSTOMER,
+    },
+    graphql::{
+        client::GraphQLClient,
+        models::customer::{update_customer, UpdateCustomer},
+    },
+    models::{
+        address::{non_empty, Address},
+        customer::{AccountDetails, Customer},
+    },
+};
+
+/// Customer controller
+///
+/// Updates the signed-in customer's account, keeping the `CUSTOMER` signal in
+/// sync with the backend.
+#[derive(Clone)]
+pub struct CustomerController {
+    client: GraphQLClient,
+}
+
+impl CustomerController {
+    /// Creates a new customer controller.
+    ///
+    /// **Returns**
+    ///
+    /// * `CustomerController` - The new customer controller.
+    pub fn new() -> Self {
+        Self {
+            client: GraphQLClient::new(),
+        }
+    }
+
+    /// Updates the customer's name, email address and password.
+    ///
+    /// **Arguments**
+    ///
+    /// * `details` - The new account details. A blank password keeps the current one.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Customer, AppError>` - The updated customer.
+    pub async fn update_details(&self, details: &AccountDetails) -> Result<Customer, AppError> {
+        self.update(update_customer::Variables {
+            first_name: Some(details.first_name.trim().to_string()),
+            last_name: Some(details.last_name.trim().to_string()),
+            display_name: non_empty(&details.display_name),
+            email: non_empty(&details.email),
+            password: Some(details.password.clone()).filter(|password| !password.is_empty()),
+            billing: None,
+            shipping: None,
+        })
+        .await
+    }
+
+    /// Updates the customer's billing and shipping addresses.
+    ///
+    /// **Arguments**
+    ///
+    /// * `billing` - The new billing address.
+    /// * `shipping` - The new shipping address.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Customer, AppError>` - The updated customer.
+    pub async fn update_addresses(
+        &self,
+        billing: &Address,
+        shipping: &Address,
+    ) -> Result<Customer, AppError> {
+        self.update(update_customer::Variables {
+            first_name: None,
+            last_name: None,
+            display_name: None,
+            email: None,
+            password: None,
+            billing: Some(billing.into()),
+            shipping: Some(shipping.into()),
+        })
+        .await
+    }
+
+    /// Sends an `updateCustomer` mutation, and publishes the result to `CUSTOMER`.
+    ///
+    /// **Arguments**
+    ///
+    /// * `variables` - The fields to update.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Customer, AppError>` - The updated customer.
+    async fn update(&self, variables: update_customer::Variables) -> Result<Customer, AppError> {
+        let response = self
+            .client
+            .execute_mutation::<_, UpdateCustomer>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        let Some(customer) = response
+            .update_customer
+            .and_then(|payload| payload.customer)
+        else {
+            return Err(AppError::new(
+                AppErrorKind::GraphQL,
+                "Your account could not be updated.",
+                Some("The updateCustomer mutation returned no customer"),
+                None,
+            ));
+        };
+
+        let customer: Customer = customer.into();
+        *CUSTOMER.write() = Some(customer.clone());
+
+        Ok(customer)
+    }
+}
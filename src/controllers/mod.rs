@@ -1,8 +1,13 @@
+pub mod auth;
 pub mod cart;
 pub mod category;
+pub mod checkout;
+pub mod customer;
 pub mod entity;
 pub mod navigation;
+pub mod order;
 pub mod page;
 pub mod post;
 pub mod product;
 pub mod settings;
+pub mod shipping;
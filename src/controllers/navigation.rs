@@ -1,8 +1,22 @@
-use crate::graphql::{
-    client::GraphQLClient,
-    models::navigation::{navigation_query, NavigationQuery},
+use std::time::Duration;
+
+// Modules
+use crate::{
+    app::error::{AppError, AppErrorKind},
+    graphql::{
+        cache::CachePolicy,
+        client::GraphQLClient,
+        models::navigation::{navigation_query, NavigationQuery},
+        policy::RequestPolicy,
+    },
 };
 
+/// Cache policy for menu queries
+///
+/// Menus are part of every page and only change when the site is restructured.
+const CACHE_POLICY: CachePolicy =
+    CachePolicy::new(Duration::from_secs(600), Duration::from_secs(3600));
+
 #[derive(Clone)]
 pub struct NavigationController {
     client: GraphQLClient,
@@ -10,23 +24,33 @@ pub struct NavigationController {
 
 impl NavigationController {
     pub fn new() -> Self {
+        // The menu is the only way around the site, so try harder to load it
         Self {
-            client: GraphQLClient::new(),
+            client: GraphQLClient::new().with_policy(RequestPolicy::default().with_max_retries(4)),
         }
     }
 
     pub async fn get_menu(
         &self,
         menu_name: &str,
-    ) -> anyhow::Result<Option<navigation_query::ResponseData>> {
+    ) -> Result<Option<navigation_query::ResponseData>, AppError> {
         let variables = navigation_query::Variables {
             id: menu_name.to_string(),
         };
         let response_body = self
             .client
-            .execute_query::<_, NavigationQuery, _>(variables)
+            .execute_cached_query::<_, NavigationQuery, _>(variables, CACHE_POLICY)
             .await
-            .map_err(|e| anyhow::anyhow!(e))?;
+            .map_err(|err| {
+                AppError::new_with_source(
+                    AppErrorKind::from(&err),
+                    "An error occurred while fetching the menu.".to_string(),
+                    Some(format!(
+                        "Failed to execute navigation query for menu '{menu_name}'"
+                    )),
+                    err,
+                )
+            })?;
 
         Ok(response_body)
     }
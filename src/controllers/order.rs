@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+
+// Modules
+use crate::{
+    app::error::{AppError, AppErrorKind},
+    controllers::entity::EntityController,
+    graphql::{
+        client::GraphQLClient,
+        models::order::{customer_orders_query, order_query, CustomerOrdersQuery, OrderQuery},
+    },
+    models::order::{Order, Orders},
+};
+
+/// Order controller
+///
+/// Fetches the signed-in customer's orders. Orders are private to the customer,
+/// so they are never cached.
+#[derive(Debug)]
+pub struct OrderController {
+    /// The GraphQL client used for API communication
+    client: GraphQLClient,
+}
+
+impl OrderController {
+    /// Creates a new order controller
+    pub fn new() -> Self {
+        Self {
+            client: GraphQLClient::new(),
+        }
+    }
+}
+
+/// Order controller implementation
+#[async_trait(?Send)]
+impl EntityController for OrderController {
+    /// A single order entity
+    type Entity = Order;
+    /// A collection of orders
+    type EntityCollection = Orders;
+
+    /// Get an order by its database ID
+    ///
+    /// Orders have no slug, so the database ID from the order's URL is used.
+    ///
+    /// **Arguments**
+    ///
+    /// * `slug` - The database ID of the order to get
+    ///
+    /// **Returns**
+    ///
+    /// * `Self::Entity` - The order entity
+    async fn get_by_slug(&self, slug: &str) -> Result<Self::Entity, AppError> {
+        // Build the request
+        let request: order_query::Variables = order_query::Variables {
+            id: slug.to_string(),
+        };
+        let request = self
+            .client
+            .execute_query::<_, OrderQuery, order_query::ResponseData>(request);
+
+        // Execute the request
+        let request: order_query::ResponseData = request.await.map_err(|err| {
+            AppError::new_with_source(
+                AppErrorKind::from(&err),
+                "An error occurred while fetching the order.".to_string(),
+                Some(format!("Failed to execute get_order query for ID '{slug}'")),
+                err,
+            )
+        })?;
+
+        // Cast the order
+        let request: Option<Order> = request.order.map(Order::from);
+
+        // Return the order
+        request.ok_or_else(|| {
+            AppError::new(
+                AppErrorKind::NotFound,
+                "The requested order could not be found.".to_string(),
+                Some(format!(
+                    "Order with ID '{slug}' not found in GraphQL response."
+                )),
+                None,
+            )
+        })
+    }
+
+    /// Get the signed-in customer's orders
+    ///
+    /// **Arguments**
+    ///
+    /// * `page_size` - The number of orders to get
+    /// * `after` - The cursor to get the next page of orders
+    ///
+    /// **Returns**
+    ///
+    /// * `Self::EntityCollection` - The collection of orders
+    async fn get_list(
+        &self,
+        page_size: Option<usize>,
+        after: Option<String>,
+    ) -> Result<Self::EntityCollection, AppError> {
+        // Build the request
+        let request = customer_orders_query::Variables {
+            first: Some(page_size.unwrap_or(10) as i64),
+            after: after.clone(),
+        };
+        let request = self
+            .client
+            .execute_query::<_, CustomerOrdersQuery, customer_orders_query::ResponseData>(request);
+
+        // Execute the request
+        let request: customer_orders_query::ResponseData = request.await.map_err(|err| {
+            AppError::new_with_source(
+                AppErrorKind::from(&err),
+                "An error occurred while fetching your orders.".to_string(),
+                Some(format!(
+                    "Failed to execute get_orders query. Page size: '{page_size:?}', After: '{after:?}'"
+                )),
+                err,
+            )
+        })?;
+
+        // Cast the orders
+        let request: Option<Orders> = request
+            .customer
+            .and_then(|customer| customer.orders)
+            .map(Orders::from);
+
+        // Return the orders
+        request.ok_or_else(|| {
+            AppError::new(
+                AppErrorKind::NotFound,
+                "Your orders could not be found.".to_string(),
+                Some("Customer orders not found in GraphQL response.".to_string()),
+                None,
+            )
+        })
+    }
+}
@@ -1,16 +1,25 @@
 use async_trait::async_trait;
+use std::time::Duration;
 
 // Modules
 use crate::{
-    app::error::{AppError, AppErrorKind, GraphQLErrorWrapper},
+    app::error::{AppError, AppErrorKind},
     controllers::entity::EntityController,
     graphql::{
+        cache::CachePolicy,
         client::GraphQLClient,
         models::page::{page_query, pages_query, PageQuery, PagesQuery},
+        policy::RequestPolicy,
     },
     models::page::{Page, Pages},
 };
 
+/// Cache policy for page queries
+///
+/// Pages are near-static content.
+const CACHE_POLICY: CachePolicy =
+    CachePolicy::new(Duration::from_secs(300), Duration::from_secs(3600));
+
 /// Page controller
 #[derive(Debug)]
 pub struct PageController {
@@ -20,9 +29,13 @@ pub struct PageController {
 
 impl PageController {
     /// Creates a new page controller
+    ///
+    /// Page content is public and rarely changes, so page queries are sent as
+    /// persisted queries, which a CDN can cache.
     pub fn new() -> Self {
         Self {
-            client: GraphQLClient::new(),
+            client: GraphQLClient::new()
+                .with_policy(RequestPolicy::default().with_persisted_queries(true)),
         }
     }
 }
@@ -51,17 +64,17 @@ impl EntityController for PageController {
         };
         let request = self
             .client
-            .execute_query::<_, PageQuery, page_query::ResponseData>(request);
+            .execute_cached_query::<_, PageQuery, page_query::ResponseData>(request, CACHE_POLICY);
 
         // Execute the request
         let request: page_query::ResponseData = request.await.map_err(|err| {
             AppError::new_with_source(
-                AppErrorKind::GraphQL,
+                AppErrorKind::from(&err),
                 "An error occurred while fetching the page.".to_string(),
                 Some(format!(
                     "Failed to execute get_page query for slug '{slug}'"
                 )),
-                GraphQLErrorWrapper(err),
+                err,
             )
         })?;
 
@@ -103,17 +116,20 @@ impl EntityController for PageController {
         };
         let request = self
             .client
-            .execute_query::<_, PagesQuery, pages_query::ResponseData>(request);
+            .execute_cached_query::<_, PagesQuery, pages_query::ResponseData>(
+                request,
+                CACHE_POLICY,
+            );
 
         // Execute the request
         let request: pages_query::ResponseData = request.await.map_err(|err| {
             AppError::new_with_source(
-                AppErrorKind::GraphQL,
+                AppErrorKind::from(&err),
                 "An error occurred while fetching the list of pages.".to_string(),
                 Some(format!(
                     "Failed to execute get_pages query. Page size: '{page_size:?}', After: '{after:?}'"
                 )),
-                GraphQLErrorWrapper(err),
+                err,
             )
         })?;
 
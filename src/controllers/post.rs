@@ -1,16 +1,23 @@
 use async_trait::async_trait;
+use std::time::Duration;
 
 // Modules
 use crate::{
-    app::error::{AppError, AppErrorKind, GraphQLErrorWrapper},
+    app::error::{AppError, AppErrorKind},
     controllers::entity::EntityController,
     graphql::{
+        cache::CachePolicy,
         client::GraphQLClient,
         models::post::{post_query, posts_query, PostQuery, PostsQuery},
+        policy::RequestPolicy,
     },
     models::post::{Post, Posts},
 };
 
+/// Cache policy for post queries
+const CACHE_POLICY: CachePolicy =
+    CachePolicy::new(Duration::from_secs(120), Duration::from_secs(600));
+
 /// Post controller
 #[derive(Debug)]
 pub struct PostController {
@@ -20,9 +27,13 @@ pub struct PostController {
 
 impl PostController {
     /// Creates a new post controller
+    ///
+    /// Post content is public and rarely changes, so post queries are sent as
+    /// persisted queries, which a CDN can cache.
     pub fn new() -> Self {
         Self {
-            client: GraphQLClient::new(),
+            client: GraphQLClient::new()
+                .with_policy(RequestPolicy::default().with_persisted_queries(true)),
         }
     }
 }
@@ -51,17 +62,17 @@ impl EntityController for PostController {
         };
         let request = self
             .client
-            .execute_query::<_, PostQuery, post_query::ResponseData>(request);
+            .execute_cached_query::<_, PostQuery, post_query::ResponseData>(request, CACHE_POLICY);
 
         // Execute the request
         let request: post_query::ResponseData = request.await.map_err(|err| {
             AppError::new_with_source(
-                AppErrorKind::GraphQL,
+                AppErrorKind::from(&err),
                 "An error occurred while fetching the post.".to_string(),
                 Some(format!(
                     "Failed to execute get_post query for slug '{slug}'"
                 )),
-                GraphQLErrorWrapper(err),
+                err,
             )
         })?;
 
@@ -103,17 +114,20 @@ impl EntityController for PostController {
         };
         let request = self
             .client
-            .execute_query::<_, PostsQuery, posts_query::ResponseData>(request);
+            .execute_cached_query::<_, PostsQuery, posts_query::ResponseData>(
+                request,
+                CACHE_POLICY,
+            );
 
         // Execute the request
         let request: posts_query::ResponseData = request.await.map_err(|err| {
             AppError::new_with_source(
-                AppErrorKind::GraphQL,
+                AppErrorKind::from(&err),
                 "An error occurred while fetching the list of posts.".to_string(),
                 Some(format!(
                     "Failed to execute get_posts query. Page size: '{page_size:?}', After: '{after:?}'"
                 )),
-                GraphQLErrorWrapper(err),
+                err,
             )
         })?;
 
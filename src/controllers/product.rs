@@ -1,19 +1,29 @@
 use async_trait::async_trait;
+use std::time::Duration;
 
 // Modules
 use crate::{
-    app::error::{AppError, AppErrorKind, GraphQLErrorWrapper},
+    app::error::{AppError, AppErrorKind},
     controllers::entity::EntityController,
     graphql::{
+        cache::CachePolicy,
         client::GraphQLClient,
         models::product::{
             product_query, products_query, search_products_query, ProductQuery, ProductsQuery,
             SearchProductsQuery,
         },
+        policy::RequestPolicy,
     },
     models::product::{Product, Products},
 };
 
+/// Cache policy for product queries
+///
+/// Prices and stock change, so keep products fresh for a minute, but keep
+/// serving them for five while they refresh when browsing back and forth.
+const CACHE_POLICY: CachePolicy =
+    CachePolicy::new(Duration::from_secs(60), Duration::from_secs(300));
+
 /// Product controller
 #[derive(Debug, Clone)]
 pub struct ProductController {
@@ -23,9 +33,13 @@ pub struct ProductController {
 
 impl ProductController {
     /// Creates a new product controller
+    ///
+    /// Product queries carry large documents, so they are sent as persisted
+    /// queries.
     pub fn new() -> Self {
         Self {
-            client: GraphQLClient::new(),
+            client: GraphQLClient::new()
+                .with_policy(RequestPolicy::default().with_persisted_queries(true)),
         }
     }
 
@@ -41,12 +55,12 @@ impl ProductController {
             .await
             .map_err(|err| {
                 AppError::new_with_source(
-                    AppErrorKind::GraphQL,
+                    AppErrorKind::from(&err),
                     "An error occurred while searching for products.".to_string(),
                     Some(format!(
                         "Failed to execute search_products query for term '{search_term}'"
                     )),
-                    GraphQLErrorWrapper(err),
+                    err,
                 )
             })?;
 
@@ -87,17 +101,20 @@ impl EntityController for ProductController {
         };
         let request = self
             .client
-            .execute_query::<_, ProductQuery, product_query::ResponseData>(request);
+            .execute_cached_query::<_, ProductQuery, product_query::ResponseData>(
+                request,
+                CACHE_POLICY,
+            );
 
         // Execute the request
         let request: product_query::ResponseData = request.await.map_err(|err| {
             AppError::new_with_source(
-                AppErrorKind::GraphQL,
+                AppErrorKind::from(&err),
                 "An error occurred while fetching the product.".to_string(),
                 Some(format!(
                     "Failed to execute get_product query for slug '{slug}'"
                 )),
-                GraphQLErrorWrapper(err),
+                err,
             )
         })?;
 
@@ -139,15 +156,18 @@ impl EntityController for ProductController {
         };
         let request = self
             .client
-            .execute_query::<_, ProductsQuery, products_query::ResponseData>(request);
+            .execute_cached_query::<_, ProductsQuery, products_query::ResponseData>(
+                request,
+                CACHE_POLICY,
+            );
 
         // Execute the request
         let request: products_query::ResponseData = request.await.map_err(|err| {
             AppError::new_with_source(
-                AppErrorKind::GraphQL,
+                AppErrorKind::from(&err),
                 "An error occurred while fetching the list of products.".to_string(),
                 Some(format!("Failed to execute get_products query. Page size: '{page_size:?}', After: '{after:?}'")),
-                GraphQLErrorWrapper(err),
+                err,
             )
         })?;
 
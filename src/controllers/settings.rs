@@ -1,8 +1,23 @@
-use crate::graphql::{
-    client::GraphQLClient,
-    models::settings::{self, WeptSettingsQuery},
+use std::time::Duration;
+
+// Modules
+use crate::{
+    app::error::AppError,
+    graphql::{
+        cache::CachePolicy,
+        client::GraphQLClient,
+        models::settings::{self, WeptSettingsQuery},
+        policy::RequestPolicy,
+    },
 };
 
+/// Cache policy for the settings query
+///
+/// The header and footer both render the settings on every page, so one
+/// response is shared between them for the whole visit.
+const CACHE_POLICY: CachePolicy =
+    CachePolicy::new(Duration::from_secs(600), Duration::from_secs(3600));
+
 /// Settings controller
 #[derive(Clone, Debug)]
 pub struct SettingsController;
@@ -18,14 +33,18 @@ impl SettingsController {
     /// # Returns
     ///
     /// A `Result` containing the `wept_settings_query::ResponseData` if the query is
-    /// successful, or an `anyhow::Error` if it fails.
-    pub async fn get(&self) -> anyhow::Result<Option<settings::wept_settings_query::ResponseData>> {
-        let client = GraphQLClient::new();
+    /// successful, or an `AppError` if it fails.
+    pub async fn get(
+        &self,
+    ) -> Result<Option<settings::wept_settings_query::ResponseData>, AppError> {
+        // Settings only decorate the header and footer, so fail fast rather than hold them up
+        let client = GraphQLClient::new()
+            .with_policy(RequestPolicy::default().with_timeout(Duration::from_secs(5)));
         let variables = settings::wept_settings_query::Variables {};
         let response_body = client
-            .execute_query::<_, WeptSettingsQuery, _>(variables)
+            .execute_cached_query::<_, WeptSettingsQuery, _>(variables, CACHE_POLICY)
             .await
-            .map_err(|e: String| anyhow::anyhow!(e))?;
+            .map_err(AppError::from)?;
 
         let response_data = response_body;
         Ok(response_data)
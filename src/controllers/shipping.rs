@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+// Modules
+use crate::{
+    app::{error::AppError, state::CUSTOMER},
+    graphql::{
+        cache::CachePolicy,
+        client::GraphQLClient,
+        models::{
+            cart::cart_query,
+            enum_value,
+            shipping::{
+                countries_query, country_states_query, update_shipping_address, CountriesQuery,
+                CountryStatesQuery, UpdateShippingAddress,
+            },
+        },
+    },
+    models::{address::Address, shipping::CountryState},
+};
+
+/// Cache policy for the countries and their states
+///
+/// They only change when the store's settings do.
+const CACHE_POLICY: CachePolicy =
+    CachePolicy::new(Duration::from_secs(3600), Duration::from_secs(86400));
+
+/// Shipping controller
+///
+/// Gets the countries shipped to, and sets the destination the cart's shipping
+/// rates are calculated for.
+#[derive(Clone)]
+pub struct ShippingController {
+    client: GraphQLClient,
+}
+
+impl ShippingController {
+    /// Creates a new shipping controller.
+    ///
+    /// **Returns**
+    ///
+    /// * `ShippingController` - The new shipping controller.
+    pub fn new() -> Self {
+        Self {
+            client: GraphQLClient::new().with_invalidations(&[cart_query::OPERATION_NAME]),
+        }
+    }
+
+    /// Gets the countries.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Vec<String>, AppError>` - The country codes, e.g. `AU`.
+    pub async fn get_countries(&self) -> Result<Vec<String>, AppError> {
+        let variables = countries_query::Variables {};
+        self.client
+            .execute_cached_query::<_, CountriesQuery, countries_query::ResponseData>(
+                variables,
+                CACHE_POLICY,
+            )
+            .await
+            .map(|response| {
+                response
+                    .countries
+                    .unwrap_or_default()
+                    .into_iter()
+                    .flatten()
+                    .map(|country| enum_value(&country))
+                    .collect()
+            })
+            .map_err(AppError::from)
+    }
+
+    /// Gets the states of a country.
+    ///
+    /// **Arguments**
+    ///
+    /// * `country` - The country code.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Vec<CountryState>, AppError>` - The states, none if the country has none.
+    pub async fn get_country_states(&self, country: &str) -> Result<Vec<CountryState>, AppError> {
+        let variables = country_states_query::Variables {
+            country: country_states_query::CountriesEnum::Other(country.to_uppercase()),
+        };
+        self.client
+            .execute_cached_query::<_, CountryStatesQuery, country_states_query::ResponseData>(
+                variables,
+                CACHE_POLICY,
+            )
+            .await
+            .map(|response| {
+                response
+                    .country_states
+                    .unwrap_or_default()
+                    .into_iter()
+                    .flatten()
+                    .map(CountryState::from)
+                    .collect()
+            })
+            .map_err(AppError::from)
+    }
+
+    /// Sets the shipping destination of the session's customer.
+    ///
+    /// The cart's shipping rates are recalculated for the new destination. Fields
+    /// left blank are kept. The signed-in customer's shipping address is updated too.
+    ///
+    /// **Arguments**
+    ///
+    /// * `destination` - The destination, usually only a country, state and postcode.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<(), AppError>` - Whether the destination was set.
+    pub async fn update_shipping_address(&self, destination: &Address) -> Result<(), AppError> {
+        let variables = update_shipping_address::Variables {
+            shipping: Some(destination.into()),
+        };
+        self.client
+            .execute_mutation::<_, UpdateShippingAddress>(variables)
+            .await
+            .map_err(AppError::from)?;
+
+        if let Some(customer) = CUSTOMER.write().as_mut() {
+            let shipping: &mut Address = customer.shipping.get_or_insert_with(Address::default);
+            shipping.country = destination.country.clone();
+            shipping.state = destination.state.clone();
+            shipping.postcode = destination.postcode.clone();
+        }
+
+        Ok(())
+    }
+}
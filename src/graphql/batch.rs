@@ -0,0 +1,306 @@
+use dioxus::prelude::spawn_forever;
+use futures::{channel::oneshot, future::join_all};
+use graphql_client::QueryBody;
+use serde_json::Value;
+use std::{cell::RefCell, mem, rc::Rc, time::Duration};
+
+// Modules
+use crate::graphql::{client::GraphQLClient, error::GraphQLClientError, policy};
+
+/// How long to wait for further queries before a batch is sent
+const BATCH_WINDOW: Duration = Duration::from_millis(10);
+
+/// Maximum number of queries in one batch
+///
+/// Matches the default `batch_limit` of WPGraphQL.
+const MAX_BATCH_SIZE: usize = 10;
+
+thread_local! {
+    /// Queries waiting for the current batch to be sent.
+    static PENDING: RefCell<Vec<BatchEntry>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A query waiting to be sent in a batch.
+struct BatchEntry {
+    /// The client that issued the query
+    client: GraphQLClient,
+    /// The query
+    payload: Rc<QueryBody<Value>>,
+    /// Delivers the query's result back to its caller
+    sender: oneshot::Sender<Result<Value, GraphQLClientError>>,
+}
+
+/// # Query Batcher
+///
+/// Combines queries issued within a few milliseconds of each other into a single
+/// HTTP request, using WPGraphQL's array batching format.
+///
+/// Each query still receives its own result, or its own GraphQL errors.
+pub struct QueryBatcher;
+
+impl QueryBatcher {
+    /// Adds a query to the next batch and waits for its result.
+    ///
+    /// **Arguments**
+    ///
+    /// * `client` - The client issuing the query.
+    /// * `payload` - The query.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Value, GraphQLClientError>` - The query's data.
+    pub async fn enqueue(
+        client: &GraphQLClient,
+        payload: Rc<QueryBody<Value>>,
+    ) -> Result<Value, GraphQLClientError> {
+        let (sender, receiver) = oneshot::channel();
+        let opens_batch: bool = PENDING.with_borrow_mut(|pending| {
+            pending.push(BatchEntry {
+                client: client.clone(),
+                payload,
+                sender,
+            });
+            pending.len() == 1
+        });
+
+        // Without a runtime to schedule the batch on, send the query straight away
+        if opens_batch && spawn_forever(Self::flush_after_window()).is_none() {
+            Self::flush().await;
+        }
+
+        // The sender is only dropped unanswered if the batch task is cancelled
+        receiver.await.unwrap_or(Err(GraphQLClientError::NoData))
+    }
+
+    /// Waits for the batch window to close, then sends the pending queries.
+    async fn flush_after_window() {
+        policy::sleep(BATCH_WINDOW).await;
+        Self::flush().await;
+    }
+
+    /// Sends every pending query, split into batches of at most `MAX_BATCH_SIZE`.
+    async fn flush() {
+        let mut entries: Vec<BatchEntry> = PENDING.with_borrow_mut(mem::take);
+
+        let mut batches: Vec<Vec<BatchEntry>> = Vec::new();
+        while !entries.is_empty() {
+            let rest: Vec<BatchEntry> = entries.split_off(entries.len().min(MAX_BATCH_SIZE));
+            batches.push(mem::replace(&mut entries, rest));
+        }
+
+        join_all(batches.into_iter().map(Self::send)).await;
+    }
+
+    /// Sends a batch and delivers each result to its caller.
+    ///
+    /// A batch of one is sent as a plain request. Otherwise, the batch is sent with
+    /// the longest timeout of its queries, so that none of them times out early.
+    ///
+    /// **Arguments**
+    ///
+    /// * `entries` - The queries in the batch.
+    async fn send(mut entries: Vec<BatchEntry>) {
+        if entries.len() == 1 {
+            if let Some(entry) = entries.pop() {
+                let result = entry
+                    .client
+                    .execute_request::<Value>(entry.payload.as_ref())
+                    .await;
+                let _ = entry.sender.send(result);
+            }
+            return;
+        }
+
+        let timeout: Option<Duration> = entries
+            .iter()
+            .map(|entry| entry.client.timeout())
+            .reduce(|longest, timeout| longest.zip(timeout).map(|(a, b)| a.max(b)))
+            .flatten();
+        let payloads: Vec<&QueryBody<Value>> =
+            entries.iter().map(|entry| entry.payload.as_ref()).collect();
+
+        match entries[0]
+            .client
+            .execute_batch_request(&payloads, timeout)
+            .await
+        {
+            Ok(results) => {
+                let mut results = results.into_iter();
+                for entry in entries {
+                    let result = results.next().unwrap_or(Err(GraphQLClientError::NoData));
+                    let _ = entry.sender.send(result);
+                }
+            }
+            Err(err) => {
+                for entry in entries {
+                    let _ = entry.sender.send(Err(err.clone()));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+    use crate::graphql::transport::{
+        http::HttpTransport,
+        stub::{StubReply, StubServer},
+    };
+    use serde_json::json;
+
+    /// A client for the stub server
+    fn client(server: &StubServer) -> GraphQLClient {
+        GraphQLClient::with_transport(Rc::new(HttpTransport::with_endpoint(server.endpoint())))
+    }
+
+    /// Queues a query in the pending batch, as `enqueue` does before the batch
+    /// window closes, without flushing it.
+    fn queue(
+        client: &GraphQLClient,
+        operation_name: &'static str,
+    ) -> oneshot::Receiver<Result<Value, GraphQLClientError>> {
+        let (sender, receiver) = oneshot::channel();
+        PENDING.with_borrow_mut(|pending| {
+            pending.push(BatchEntry {
+                client: client.clone(),
+                payload: Rc::new(QueryBody {
+                    variables: json!({}),
+                    query: "query { __typename }",
+                    operation_name,
+                }),
+                sender,
+            })
+        });
+        receiver
+    }
+
+    /// Flushes the pending batch, and collects the result of each query.
+    fn flush(
+        receivers: Vec<oneshot::Receiver<Result<Value, GraphQLClientError>>>,
+    ) -> Vec<Result<Value, GraphQLClientError>> {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("runtime")
+            .block_on(async {
+                QueryBatcher::flush().await;
+                join_all(receivers)
+                    .await
+                    .into_iter()
+                    .map(|result| result.expect("a result for every query"))
+                    .collect()
+            })
+    }
+
+    /// The results of a batch, each with its index in the batch
+    fn indexed_results(count: usize) -> StubReply {
+        let results: Vec<Value> = (0..count)
+            .map(|index| json!({ "data": { "index": index } }))
+            .collect();
+        StubReply::json(200, Value::Array(results))
+    }
+
+    #[test]
+    fn queries_are_fanned_out_in_one_request() {
+        let server = StubServer::start(vec![indexed_results(3)]);
+        let client = client(&server);
+        let receivers = vec![
+            queue(&client, "First"),
+            queue(&client, "Second"),
+            queue(&client, "Third"),
+        ];
+
+        let results = flush(receivers);
+
+        let data: Vec<Value> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(
+            data,
+            [
+                json!({ "index": 0 }),
+                json!({ "index": 1 }),
+                json!({ "index": 2 })
+            ]
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        let body: Value = serde_json::from_str(&requests[0].body).unwrap();
+        let operation_names: Vec<&str> = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|payload| payload["operationName"].as_str())
+            .collect();
+        assert_eq!(operation_names, ["First", "Second", "Third"]);
+    }
+
+    #[test]
+    fn a_query_with_graphql_errors_fails_alone() {
+        let server = StubServer::start(vec![StubReply::json(
+            200,
+            json!([
+                { "data": { "index": 0 } },
+                { "data": null, "errors": [{ "message": "Cannot query field" }] },
+                { "data": { "index": 2 } },
+            ]),
+        )]);
+        let client = client(&server);
+        let receivers = vec![
+            queue(&client, "First"),
+            queue(&client, "Second"),
+            queue(&client, "Third"),
+        ];
+
+        let results = flush(receivers);
+
+        assert_eq!(results[0].as_ref().unwrap(), &json!({ "index": 0 }));
+        assert!(
+            matches!(&results[1], Err(GraphQLClientError::GraphQL { errors, .. })
+                if errors[0].message == "Cannot query field"),
+            "{:?}",
+            results[1]
+        );
+        assert_eq!(results[2].as_ref().unwrap(), &json!({ "index": 2 }));
+    }
+
+    #[test]
+    fn a_failed_request_fails_every_query() {
+        let server = StubServer::start(vec![StubReply::json(500, json!("Server error"))]);
+        let client = client(&server);
+        let receivers = vec![queue(&client, "First"), queue(&client, "Second")];
+
+        let results = flush(receivers);
+
+        assert_eq!(results.len(), 2);
+        for result in results {
+            assert!(
+                matches!(&result, Err(GraphQLClientError::Http { status, .. })
+                    if status.as_u16() == 500),
+                "{result:?}"
+            );
+        }
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn large_batches_are_split() {
+        let server = StubServer::start(vec![indexed_results(MAX_BATCH_SIZE)]);
+        let client = client(&server);
+        let receivers = (0..MAX_BATCH_SIZE * 2)
+            .map(|_| queue(&client, "Query"))
+            .collect();
+
+        let results = flush(receivers);
+
+        for (index, result) in results.into_iter().enumerate() {
+            assert_eq!(result.unwrap(), json!({ "index": index % MAX_BATCH_SIZE }));
+        }
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        for request in requests {
+            let body: Value = serde_json::from_str(&request.body).unwrap();
+            assert_eq!(body.as_array().map(Vec::len), Some(MAX_BATCH_SIZE));
+        }
+    }
+}
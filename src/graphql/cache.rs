@@ -0,0 +1,332 @@
+use chrono::{DateTime, Utc};
+use graphql_client::QueryBody;
+use serde::Serialize;
+use serde_json::Value;
+use std::{cell::RefCell, collections::HashMap, time::Duration};
+
+// Modules
+use crate::{app::state::State, graphql::jwt};
+
+thread_local! {
+    /// Cached responses, shared by every `GraphQLClient` in the application.
+    static CACHE: RefCell<HashMap<CacheKey, CacheEntry>> = RefCell::new(HashMap::new());
+}
+
+/// # Cache Policy
+///
+/// Controls how long a cached query response is served.
+///
+/// A response is served as-is until `ttl` expires. For a further
+/// `stale_while_revalidate` it is still served, but refreshed in the background.
+/// After that it is refetched before anything is returned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CachePolicy {
+    /// How long a response is fresh
+    pub ttl: Duration,
+    /// How long a stale response may be served while it is revalidated
+    pub stale_while_revalidate: Duration,
+}
+
+impl CachePolicy {
+    /// Creates a new cache policy.
+    ///
+    /// **Arguments**
+    ///
+    /// * `ttl` - How long a response is fresh.
+    /// * `stale_while_revalidate` - How long a stale response may be served while it is revalidated.
+    ///
+    /// **Returns**
+    ///
+    /// * `CachePolicy` - The new cache policy.
+    pub const fn new(ttl: Duration, stale_while_revalidate: Duration) -> Self {
+        Self {
+            ttl,
+            stale_while_revalidate,
+        }
+    }
+}
+
+/// # Cache Key
+///
+/// Identifies a query response by its operation name and variables, and by the
+/// WooCommerce session and signed-in customer it was fetched for.
+///
+/// Responses such as the cart and the customer's details depend on who asks, so
+/// a response is only served to the session and customer it was fetched for.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    /// The GraphQL operation name
+    operation_name: &'static str,
+    /// The variables, serialized as JSON
+    variables: String,
+    /// The WooCommerce session the response was fetched for
+    session: Option<String>,
+    /// The signed-in customer the response was fetched for
+    customer: Option<String>,
+}
+
+impl CacheKey {
+    /// Creates the cache key for a query, sent with the current session and auth tokens.
+    ///
+    /// The tokens are identified by their subject, so renewing a token keeps its
+    /// cached responses.
+    ///
+    /// **Arguments**
+    ///
+    /// * `payload` - The query to create the key for.
+    ///
+    /// **Returns**
+    ///
+    /// * `CacheKey` - The cache key.
+    pub fn new<V: Serialize>(payload: &QueryBody<V>) -> Self {
+        let identity = |token: String| jwt::subject(&token).unwrap_or(token);
+
+        Self {
+            operation_name: payload.operation_name,
+            variables: serde_json::to_string(&payload.variables).unwrap_or_default(),
+            session: State::get_session_token().map(identity),
+            customer: State::get_auth_token().map(identity),
+        }
+    }
+}
+
+/// A cached query response.
+struct CacheEntry {
+    /// The response data
+    data: Value,
+    /// When the response was received
+    fetched_at: DateTime<Utc>,
+    /// Whether a background revalidation is in progress
+    revalidating: bool,
+}
+
+/// # Cache Lookup
+///
+/// The result of looking up a query in the cache.
+pub enum CacheLookup {
+    /// The response is fresh and can be served as-is.
+    Fresh(Value),
+    /// The response is stale and should be revalidated in the background.
+    Stale(Value),
+    /// The response is stale, but a background revalidation is already running.
+    Revalidating(Value),
+    /// There is no usable response.
+    Miss,
+}
+
+/// # Query Cache
+///
+/// A client-side cache of query responses.
+///
+/// Operations opt in through `GraphQLClient::execute_cached_query`, and mutations
+/// clear the operations they affect through `QueryCache::invalidate`.
+pub struct QueryCache;
+
+impl QueryCache {
+    /// Looks up a query response.
+    ///
+    /// A stale response is marked as revalidating, so only the first caller to see
+    /// it receives `CacheLookup::Stale`.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The cache key of the query.
+    /// * `policy` - The cache policy of the query.
+    ///
+    /// **Returns**
+    ///
+    /// * `CacheLookup` - The cached response and its freshness.
+    pub fn lookup(key: &CacheKey, policy: &CachePolicy) -> CacheLookup {
+        CACHE.with_borrow_mut(|cache| {
+            let Some(entry) = cache.get_mut(key) else {
+                return CacheLookup::Miss;
+            };
+
+            let age: Duration = (Utc::now() - entry.fetched_at).to_std().unwrap_or_default();
+            if age <= policy.ttl {
+                CacheLookup::Fresh(entry.data.clone())
+            } else if age <= policy.ttl + policy.stale_while_revalidate {
+                if entry.revalidating {
+                    CacheLookup::Revalidating(entry.data.clone())
+                } else {
+                    entry.revalidating = true;
+                    CacheLookup::Stale(entry.data.clone())
+                }
+            } else {
+                CacheLookup::Miss
+            }
+        })
+    }
+
+    /// Stores a query response.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The cache key of the query.
+    /// * `data` - The response data.
+    pub fn store(key: CacheKey, data: Value) {
+        CACHE.with_borrow_mut(|cache| {
+            cache.insert(
+                key,
+                CacheEntry {
+                    data,
+                    fetched_at: Utc::now(),
+                    revalidating: false,
+                },
+            );
+        });
+    }
+
+    /// Clears the revalidating flag after a failed background revalidation.
+    ///
+    /// The stale response is kept, and the next lookup will try again.
+    ///
+    /// **Arguments**
+    ///
+    /// * `key` - The cache key of the query.
+    pub fn revalidation_failed(key: &CacheKey) {
+        CACHE.with_borrow_mut(|cache| {
+            if let Some(entry) = cache.get_mut(key) {
+                entry.revalidating = false;
+            }
+        });
+    }
+
+    /// Removes every cached response of an operation.
+    ///
+    /// **Arguments**
+    ///
+    /// * `operation_name` - The GraphQL operation name, e.g. `cart_query::OPERATION_NAME`.
+    pub fn invalidate(operation_name: &str) {
+        CACHE.with_borrow_mut(|cache| {
+            cache.retain(|key, _| key.operation_name != operation_name);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const POLICY: CachePolicy = CachePolicy::new(Duration::from_secs(60), Duration::from_secs(300));
+
+    /// A query for the cart
+    fn payload() -> QueryBody<Value> {
+        QueryBody {
+            variables: json!({}),
+            query: "query CartQuery { cart { isEmpty } }",
+            operation_name: "CartQuery",
+        }
+    }
+
+    /// A token issued to a subject, as the backend's tokens are
+    fn token(data: Value) -> String {
+        jwt::encode(&json!({ "exp": 0, "data": data }))
+    }
+
+    /// Stores a response that was fetched some time ago
+    fn store_aged(key: &CacheKey, age: Duration) {
+        QueryCache::store(key.clone(), json!({ "cart": { "isEmpty": true } }));
+        CACHE.with_borrow_mut(|cache| {
+            let entry: &mut CacheEntry = cache.get_mut(key).unwrap();
+            entry.fetched_at -= chrono::Duration::from_std(age).unwrap();
+        });
+    }
+
+    #[test]
+    fn a_response_within_its_ttl_is_fresh() {
+        let key: CacheKey = CacheKey::new(&payload());
+        store_aged(&key, Duration::from_secs(30));
+
+        assert!(matches!(
+            QueryCache::lookup(&key, &POLICY),
+            CacheLookup::Fresh(_)
+        ));
+    }
+
+    #[test]
+    fn a_stale_response_is_revalidated_once() {
+        let key: CacheKey = CacheKey::new(&payload());
+        store_aged(&key, Duration::from_secs(120));
+
+        assert!(matches!(
+            QueryCache::lookup(&key, &POLICY),
+            CacheLookup::Stale(_)
+        ));
+        assert!(matches!(
+            QueryCache::lookup(&key, &POLICY),
+            CacheLookup::Revalidating(_)
+        ));
+
+        QueryCache::revalidation_failed(&key);
+        assert!(matches!(
+            QueryCache::lookup(&key, &POLICY),
+            CacheLookup::Stale(_)
+        ));
+    }
+
+    #[test]
+    fn an_expired_response_is_a_miss() {
+        let key: CacheKey = CacheKey::new(&payload());
+        store_aged(&key, Duration::from_secs(600));
+
+        assert!(matches!(
+            QueryCache::lookup(&key, &POLICY),
+            CacheLookup::Miss
+        ));
+    }
+
+    #[test]
+    fn an_invalidated_operation_is_a_miss() {
+        let key: CacheKey = CacheKey::new(&payload());
+        store_aged(&key, Duration::ZERO);
+
+        QueryCache::invalidate("CartQuery");
+
+        assert!(matches!(
+            QueryCache::lookup(&key, &POLICY),
+            CacheLookup::Miss
+        ));
+    }
+
+    #[test]
+    fn a_response_is_not_served_to_another_session_or_customer() {
+        State::set_session_token(&token(json!({ "customer_id": "t_guest" })));
+        let guest: CacheKey = CacheKey::new(&payload());
+        store_aged(&guest, Duration::ZERO);
+
+        State::set_session_token(&token(json!({ "customer_id": "t_other" })));
+        let other: CacheKey = CacheKey::new(&payload());
+        assert!(matches!(
+            QueryCache::lookup(&other, &POLICY),
+            CacheLookup::Miss
+        ));
+
+        State::set_auth_token(&token(json!({ "user": { "id": "1" } })));
+        let customer: CacheKey = CacheKey::new(&payload());
+        assert_ne!(customer, other);
+        assert!(matches!(
+            QueryCache::lookup(&customer, &POLICY),
+            CacheLookup::Miss
+        ));
+
+        State::clear_auth_tokens();
+        State::clear_session_token();
+    }
+
+    #[test]
+    fn a_renewed_token_keeps_its_responses() {
+        State::set_session_token(&format!("{}1", token(json!({ "customer_id": "t_guest" }))));
+        let key: CacheKey = CacheKey::new(&payload());
+        store_aged(&key, Duration::ZERO);
+
+        State::set_session_token(&format!("{}2", token(json!({ "customer_id": "t_guest" }))));
+        assert!(matches!(
+            QueryCache::lookup(&CacheKey::new(&payload()), &POLICY),
+            CacheLookup::Fresh(_)
+        ));
+
+        State::clear_session_token();
+    }
+}
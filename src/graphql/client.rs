@@ -1,111 +1,889 @@
+use dioxus::prelude::spawn_forever;
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
 pub use graphql_client::Response;
 use graphql_client::{GraphQLQuery, QueryBody};
-use reqwest::Client;
+use reqwest::StatusCode;
 use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+    time::Duration,
+};
 
 // Modules
-use crate::app::state::{State, SESSION_TOKEN_KEY};
+use crate::{
+    app::state::{State, CUSTOMER},
+    graphql::{
+        batch::QueryBatcher,
+        cache::{CacheKey, CacheLookup, CachePolicy, QueryCache},
+        error::GraphQLClientError,
+        jwt,
+        models::customer::{refresh_jwt_auth_token, RefreshJwtAuthToken},
+        persisted::PersistedQueries,
+        policy::{self, RequestPolicy},
+        transport::{
+            fixture::FixtureTransport, http::HttpTransport, Transport, TransportRequest,
+            TransportResponse,
+        },
+    },
+};
+
+/// A query request that can be awaited by several callers
+type SharedRequest = Shared<LocalBoxFuture<'static, Result<Value, GraphQLClientError>>>;
+
+/// An auth token refresh that can be awaited by several callers
+type SharedRefresh = Shared<LocalBoxFuture<'static, Option<String>>>;
+
+/// How long before it expires the auth token is refreshed
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+thread_local! {
+    /// The transport of every client that doesn't bring its own, so that they all
+    /// share one HTTP connection pool, or one set of fixture carts.
+    static DEFAULT_TRANSPORT: Rc<dyn Transport> = if cfg!(any(test, feature = "fixtures")) {
+        Rc::new(FixtureTransport::embedded())
+    } else {
+        Rc::new(HttpTransport::new())
+    };
+
+    /// Queries that have been sent but not yet answered, keyed like the cache, so
+    /// only queries sent for the same session and customer share a response.
+    static IN_FLIGHT: RefCell<HashMap<CacheKey, SharedRequest>> = RefCell::new(HashMap::new());
+
+    /// The auth token refresh in progress, if any.
+    static REFRESHING: RefCell<Option<SharedRefresh>> = const { RefCell::new(None) };
+
+    /// Whether the backend failed to serve or register a persisted query.
+    static PERSISTED_QUERIES_UNSUPPORTED: Cell<bool> = const { Cell::new(false) };
+}
 
 /// GraphQL client for making requests to the WordPress API
-#[derive(Default, Clone, Debug)]
+///
+/// Every response is checked for a refreshed WooCommerce session token, which is
+/// persisted so that all later requests share the same session. Requests made
+/// while a customer is signed in carry their JWT auth token, which is refreshed
+/// shortly before it expires.
+#[derive(Clone, Debug)]
 pub struct GraphQLClient {
-    /// HTTP client for making network requests
-    pub client: Client,
-    /// WooCommerce session token
-    session_token: Option<String>,
+    /// Transport that delivers requests to the backend
+    transport: Rc<dyn Transport>,
+    /// Timeout and retry policy for requests
+    policy: RequestPolicy,
+    /// Cached operations to invalidate after each mutation
+    invalidates: &'static [&'static str],
 }
 
 impl GraphQLClient {
     /// Create a new GraphQL client
+    ///
+    /// Requests go to the configured backend, or to the recorded fixtures when built
+    /// with the `fixtures` feature, or for tests. Every client shares the same
+    /// transport.
     pub fn new() -> Self {
+        Self::with_transport(DEFAULT_TRANSPORT.with(Rc::clone))
+    }
+
+    /// Create a new GraphQL client that sends its requests through a transport
+    pub fn with_transport(transport: Rc<dyn Transport>) -> Self {
         Self {
-            client: Client::new(),
-            session_token: None,
+            transport,
+            policy: RequestPolicy::default(),
+            invalidates: &[],
         }
     }
 
-    /// Set the session token
-    pub fn set_session_token(&mut self, token: String) {
-        self.session_token = Some(token);
+    /// Override the timeout and retry policy
+    ///
+    /// Lets each controller tune the policy to how critical and how slow its
+    /// operations are.
+    pub fn with_policy(mut self, policy: RequestPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Set the cached operations that this client's mutations affect
+    ///
+    /// The operations are invalidated after every mutation, so the next query
+    /// refetches them instead of serving outdated data.
+    pub fn with_invalidations(mut self, operation_names: &'static [&'static str]) -> Self {
+        self.invalidates = operation_names;
+        self
     }
 
     /// Execute a GraphQL query
-    pub async fn execute_query<V, Q, R>(&self, variables: V) -> Result<R, String>
+    ///
+    /// Queries are idempotent, so transient failures are retried with backoff
+    /// according to the client's `RequestPolicy`.
+    pub async fn execute_query<V, Q, R>(&self, variables: V) -> Result<R, GraphQLClientError>
     where
         V: Serialize,
         Q: GraphQLQuery<Variables = V>,
         R: DeserializeOwned,
     {
-        let payload: QueryBody<V> = Q::build_query(variables);
-        let response = self.execute_request::<R>(payload).await?;
+        let payload: QueryBody<Value> = Self::to_json_payload(Q::build_query(variables))?;
+        let data: Value = self.execute_query_request(payload).await?;
+
+        Ok(serde_json::from_value(data)?)
+    }
 
-        if let Some(errors) = response.errors {
-            if !errors.is_empty() {
-                return Err(format!("GraphQL error: {errors:?}"));
+    /// Execute a GraphQL query through the query cache
+    ///
+    /// Fresh responses are served from the cache. Stale responses are served too,
+    /// while a background request revalidates them, as per the `CachePolicy`.
+    pub async fn execute_cached_query<V, Q, R>(
+        &self,
+        variables: V,
+        cache_policy: CachePolicy,
+    ) -> Result<R, GraphQLClientError>
+    where
+        V: Serialize,
+        Q: GraphQLQuery<Variables = V>,
+        R: DeserializeOwned,
+    {
+        let payload: QueryBody<Value> = Self::to_json_payload(Q::build_query(variables))?;
+        let key: CacheKey = CacheKey::new(&payload);
+
+        let data: Value = match QueryCache::lookup(&key, &cache_policy) {
+            CacheLookup::Fresh(data) | CacheLookup::Revalidating(data) => data,
+            CacheLookup::Stale(data) => {
+                let client: GraphQLClient = self.clone();
+                let revalidation_key: CacheKey = key.clone();
+                let revalidation = async move {
+                    let operation_name: &str = payload.operation_name;
+                    match client.execute_query_request(payload).await {
+                        Ok(data) => QueryCache::store(revalidation_key, data),
+                        Err(err) => {
+                            tracing::warn!("Failed to revalidate {operation_name}: {err}");
+                            QueryCache::revalidation_failed(&revalidation_key);
+                        }
+                    }
+                };
+                if spawn_forever(revalidation).is_none() {
+                    QueryCache::revalidation_failed(&key);
+                }
+                data
             }
-        }
+            CacheLookup::Miss => {
+                let data: Value = self.execute_query_request(payload).await?;
+                QueryCache::store(key, data.clone());
+                data
+            }
+        };
 
-        response
-            .data
-            .ok_or_else(|| "No data in response".to_string())
+        Ok(serde_json::from_value(data)?)
+    }
+
+    /// Execute a GraphQL query request, sharing it with identical in-flight queries
+    ///
+    /// Components that mount together often ask for the same data. Only the first
+    /// caller sends the query; later callers wait for the same response.
+    async fn execute_query_request(
+        &self,
+        payload: QueryBody<Value>,
+    ) -> Result<Value, GraphQLClientError> {
+        let key: CacheKey = CacheKey::new(&payload);
+        let request: SharedRequest = IN_FLIGHT.with_borrow_mut(|in_flight| {
+            in_flight
+                .entry(key.clone())
+                .or_insert_with(|| {
+                    let client: GraphQLClient = self.clone();
+                    async move {
+                        let result = client.execute_with_retries(Rc::new(payload)).await;
+                        IN_FLIGHT.with_borrow_mut(|in_flight| in_flight.remove(&key));
+                        result
+                    }
+                    .boxed_local()
+                    .shared()
+                })
+                .clone()
+        });
+
+        request.await
+    }
+
+    /// Execute a GraphQL query request, retrying transient failures
+    async fn execute_with_retries(
+        &self,
+        payload: Rc<QueryBody<Value>>,
+    ) -> Result<Value, GraphQLClientError> {
+        let mut retry: u32 = 0;
+        loop {
+            let result = if self.policy.persisted {
+                self.execute_persisted_request(&payload).await
+            } else if self.policy.batch {
+                QueryBatcher::enqueue(self, payload.clone()).await
+            } else {
+                self.execute_request::<Value>(payload.as_ref()).await
+            };
+
+            match result {
+                Err(err) if err.is_transient() && retry < self.policy.max_retries => {
+                    let delay = self.policy.backoff(retry, policy::jitter());
+                    tracing::warn!(
+                        "Retrying {} in {delay:?} after error: {err}",
+                        payload.operation_name
+                    );
+                    policy::sleep(delay).await;
+                    retry += 1;
+                }
+                Err(err) if err.is_transient() && retry > 0 => {
+                    return Err(GraphQLClientError::RetriesExhausted {
+                        attempts: retry + 1,
+                        last: Box::new(err),
+                    })
+                }
+                result => return result,
+            }
+        }
     }
 
     /// Execute a GraphQL mutation
-    pub async fn execute_mutation<V, Q>(&self, variables: V) -> Result<reqwest::Response, String>
+    ///
+    /// Mutations are never retried, as repeating a cart or checkout action could
+    /// apply it twice. For the same reason they are never shared or batched.
+    pub async fn execute_mutation<V, Q>(
+        &self,
+        variables: V,
+    ) -> Result<Q::ResponseData, GraphQLClientError>
     where
         V: Serialize,
         Q: GraphQLQuery<Variables = V>,
     {
         let payload: QueryBody<V> = Q::build_query(variables);
-        self.execute_mutation_request(payload).await
+        let response = self.execute_request::<Q::ResponseData>(&payload).await;
+
+        // A failed mutation may still have been applied, so invalidate regardless
+        for operation_name in self.invalidates {
+            QueryCache::invalidate(operation_name);
+        }
+
+        response
     }
 
     /// Execute a GraphQL request
-    async fn execute_request<R>(
-        &self,
-        payload: QueryBody<impl Serialize>,
-    ) -> Result<Response<R>, String>
+    ///
+    /// GraphQL `errors[]` are returned as `GraphQLClientError::GraphQL`, even when the
+    /// backend pairs them with a non-success HTTP status.
+    pub async fn execute_request<R>(&self, body: &impl Serialize) -> Result<R, GraphQLClientError>
     where
         R: DeserializeOwned,
     {
-        let mut builder = self.client.post(Self::get_endpoint());
-        if let Some(token) = &self.session_token {
-            builder = builder.header(SESSION_TOKEN_KEY, format!("Session {}", token));
+        let request = TransportRequest::post(serde_json::to_value(body)?, self.policy.timeout);
+        let (status, body) = self.send(request).await?;
+
+        parse_response(status, body)
+    }
+
+    /// Execute a GraphQL query as an Automatic Persisted Query
+    ///
+    /// Sends only the query's hash as a GET. If the backend hasn't seen the hash yet,
+    /// the query is sent again as a POST with its full document, which registers it.
+    /// Queries missing from the persisted query manifest are always sent in full.
+    ///
+    /// If the backend turns out not to support persisted queries, or won't register
+    /// the hash, the query is sent in full, and so are all later queries.
+    async fn execute_persisted_request(
+        &self,
+        payload: &QueryBody<Value>,
+    ) -> Result<Value, GraphQLClientError> {
+        let hash: Option<String> = PersistedQueries::hash(payload.query);
+        let Some(hash) = hash.filter(|_| !PERSISTED_QUERIES_UNSUPPORTED.get()) else {
+            return self.execute_request::<Value>(payload).await;
+        };
+        let extensions: Value = json!({
+            "persistedQuery": { "version": 1, "sha256Hash": hash },
+        });
+
+        let params = vec![
+            ("operationName", payload.operation_name.to_string()),
+            ("variables", payload.variables.to_string()),
+            ("extensions", extensions.to_string()),
+        ];
+        let (status, body) = self
+            .send(TransportRequest::get(params, self.policy.timeout))
+            .await?;
+
+        let result = match parse_response::<Value>(status, body) {
+            Err(err) if err.is_persisted_query_not_found() => {
+                let mut body: Value = serde_json::to_value(payload)?;
+                body["extensions"] = extensions;
+                self.execute_request::<Value>(&body).await
+            }
+            result => result,
+        };
+
+        match result {
+            Err(err)
+                if err.is_persisted_query_not_found() || err.is_persisted_query_not_supported() =>
+            {
+                tracing::warn!("Sending queries in full, as persisted queries failed: {err}");
+                PERSISTED_QUERIES_UNSUPPORTED.set(true);
+                self.execute_request::<Value>(payload).await
+            }
+            result => result,
         }
+    }
 
-        builder
-            .json(&payload)
-            .send()
-            .await
-            .map_err(|err| format!("Request failed: {err}"))?
-            .json::<Response<R>>()
-            .await
-            .map_err(|err| format!("Failed to parse response: {err}"))
+    /// Execute several GraphQL queries in a single request
+    ///
+    /// Uses WPGraphQL's array batching, where the response holds one result per
+    /// query, in the same order. Each query's GraphQL errors are kept separate.
+    pub async fn execute_batch_request(
+        &self,
+        payloads: &[&QueryBody<Value>],
+        timeout: Option<Duration>,
+    ) -> Result<Vec<Result<Value, GraphQLClientError>>, GraphQLClientError> {
+        let request = TransportRequest::post(serde_json::to_value(payloads)?, timeout);
+        let (status, body) = self.send(request).await?;
+
+        match serde_json::from_str::<Vec<Response<Value>>>(&body) {
+            Ok(responses) if status.is_success() || responses.iter().any(has_errors) => {
+                Ok(responses
+                    .into_iter()
+                    .map(|response| response_data(status, response))
+                    .collect())
+            }
+            Err(err) if status.is_success() => Err(err.into()),
+            _ => Err(GraphQLClientError::Http { status, body }),
+        }
+    }
+
+    /// Send a request through the transport
+    ///
+    /// Attaches the WooCommerce session and the customer's auth token, and returns
+    /// the status and body text.
+    async fn send(
+        &self,
+        mut request: TransportRequest,
+    ) -> Result<(StatusCode, String), GraphQLClientError> {
+        request.auth_token = self.auth_token().await;
+        request.session_token = State::get_session_token();
+
+        let response: TransportResponse = self.transport.send(request).await?;
+        Self::capture_session_token(&response);
+
+        Ok((response.status, response.body))
+    }
+
+    /// Get the signed-in customer's auth token, refreshing it if it is about to expire
+    ///
+    /// Requests sent while a refresh is in progress wait for it, so the refresh
+    /// token is only exchanged once.
+    async fn auth_token(&self) -> Option<String> {
+        let token: String = State::get_auth_token()?;
+        if !jwt::expires_within(&token, REFRESH_MARGIN) {
+            return Some(token);
+        }
+
+        let refresh: SharedRefresh = REFRESHING.with_borrow_mut(|refreshing| {
+            refreshing
+                .get_or_insert_with(|| {
+                    let client: GraphQLClient = self.clone();
+                    async move {
+                        let token: Option<String> = client.refresh_auth_token().await;
+                        REFRESHING.with_borrow_mut(|refreshing| *refreshing = None);
+                        token
+                    }
+                    .boxed_local()
+                    .shared()
+                })
+                .clone()
+        });
+
+        refresh.await
     }
 
-    /// Execute a GraphQL mutation request
-    async fn execute_mutation_request(
+    /// Exchange the refresh token for a new auth token
+    ///
+    /// Transient failures keep the current token, so the next request tries again,
+    /// but a missing or rejected refresh token signs the customer out.
+    async fn refresh_auth_token(&self) -> Option<String> {
+        let result = match State::get_refresh_token() {
+            Some(refresh_token) => self.request_auth_token(refresh_token).await,
+            None => Ok(None),
+        };
+
+        match result {
+            Ok(Some(token)) => {
+                State::set_auth_token(&token);
+                Some(token)
+            }
+            Err(err) if err.is_transient() => {
+                tracing::warn!("Failed to refresh the auth token: {err}");
+                State::get_auth_token()
+            }
+            Ok(None) | Err(_) => {
+                tracing::warn!("Signing out, as the auth token could not be refreshed");
+                State::clear_auth_tokens();
+                *CUSTOMER.write() = None;
+                None
+            }
+        }
+    }
+
+    /// Request a new auth token with a refresh token
+    ///
+    /// Sent straight through the transport, as the refresh token is the only
+    /// credential it needs.
+    ///
+    /// **Arguments**
+    ///
+    /// * `jwt_refresh_token` - The refresh token.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<Option<String>, GraphQLClientError>` - The new auth token, if the refresh token was accepted.
+    async fn request_auth_token(
         &self,
-        payload: QueryBody<impl Serialize>,
-    ) -> Result<reqwest::Response, String> {
-        let mut builder = self.client.post(Self::get_endpoint());
-        if let Some(token) = &self.session_token {
-            builder = builder.header(SESSION_TOKEN_KEY, format!("Session {}", token));
+        jwt_refresh_token: String,
+    ) -> Result<Option<String>, GraphQLClientError> {
+        let variables = refresh_jwt_auth_token::Variables { jwt_refresh_token };
+        let payload = RefreshJwtAuthToken::build_query(variables);
+        let mut request =
+            TransportRequest::post(serde_json::to_value(payload)?, self.policy.timeout);
+        request.session_token = State::get_session_token();
+
+        let response: TransportResponse = self.transport.send(request).await?;
+        Self::capture_session_token(&response);
+        let data: refresh_jwt_auth_token::ResponseData =
+            parse_response(response.status, response.body)?;
+
+        Ok(data
+            .refresh_jwt_auth_token
+            .and_then(|refresh| refresh.auth_token))
+    }
+
+    /// Get the timeout for a single attempt
+    pub fn timeout(&self) -> Option<Duration> {
+        self.policy.timeout
+    }
+
+    /// Convert a query's variables to JSON
+    ///
+    /// Lets queries with different variable types share the in-flight and batch
+    /// queues.
+    fn to_json_payload<V: Serialize>(
+        payload: QueryBody<V>,
+    ) -> Result<QueryBody<Value>, GraphQLClientError> {
+        Ok(QueryBody {
+            variables: serde_json::to_value(payload.variables)?,
+            query: payload.query,
+            operation_name: payload.operation_name,
+        })
+    }
+
+    /// Persist a refreshed WooCommerce session token from a response
+    ///
+    /// WooGraphQL may issue or renew the session on any operation, so the header is
+    /// checked on every response rather than only after specific cart actions.
+    fn capture_session_token(response: &TransportResponse) {
+        if let Some(token) = response.session_token.as_deref() {
+            if State::get_session_token().as_deref() != Some(token) {
+                State::set_session_token(token);
+            }
+        }
+    }
+}
+
+impl Default for GraphQLClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Check whether a response contains GraphQL errors
+fn has_errors<R>(response: &Response<R>) -> bool {
+    response
+        .errors
+        .as_ref()
+        .is_some_and(|errors| !errors.is_empty())
+}
+
+/// Parse the body of a GraphQL response
+///
+/// **Arguments**
+///
+/// * `status` - The HTTP status of the response.
+/// * `body` - The response body.
+///
+/// **Returns**
+///
+/// * `Result<R, GraphQLClientError>` - The data, or the error the response describes.
+fn parse_response<R>(status: StatusCode, body: String) -> Result<R, GraphQLClientError>
+where
+    R: DeserializeOwned,
+{
+    match serde_json::from_str::<Response<R>>(&body) {
+        Ok(response) if status.is_success() || has_errors(&response) => {
+            response_data(status, response)
         }
+        Err(err) if status.is_success() => Err(err.into()),
+        _ => Err(GraphQLClientError::Http { status, body }),
+    }
+}
+
+/// Get the data of a GraphQL response
+///
+/// **Arguments**
+///
+/// * `status` - The HTTP status of the response.
+/// * `response` - The GraphQL response.
+///
+/// **Returns**
+///
+/// * `Result<R, GraphQLClientError>` - The data, or the GraphQL errors.
+fn response_data<R>(status: StatusCode, response: Response<R>) -> Result<R, GraphQLClientError> {
+    match response {
+        Response {
+            errors: Some(errors),
+            ..
+        } if !errors.is_empty() => Err(GraphQLClientError::GraphQL { status, errors }),
+        Response { data, .. } => data.ok_or(GraphQLClientError::NoData),
+    }
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+    use crate::{
+        app::error::AppErrorKind,
+        graphql::{
+            models::{
+                cart::{empty_cart, EmptyCart},
+                settings::{wept_settings_query, WeptSettingsQuery},
+            },
+            transport::stub::{StubReply, StubServer},
+        },
+    };
+    use std::future::Future;
+
+    /// A client for the stub server, with short timeouts and delays
+    fn client(server: &StubServer) -> GraphQLClient {
+        let policy = RequestPolicy {
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            ..RequestPolicy::default()
+        };
+
+        GraphQLClient::with_transport(Rc::new(HttpTransport::with_endpoint(server.endpoint())))
+            .with_policy(
+                policy
+                    .with_timeout(Duration::from_millis(100))
+                    .with_batching(false),
+            )
+    }
 
-        builder
-            .json(&payload)
-            .send()
+    /// Runs a future on the runtime reqwest needs natively
+    fn run<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("runtime")
+            .block_on(future)
+    }
+
+    /// Sends the settings query
+    async fn query(client: &GraphQLClient) -> Result<Value, GraphQLClientError> {
+        client
+            .execute_query::<_, WeptSettingsQuery, Value>(wept_settings_query::Variables {})
             .await
-            .map_err(|err| format!("Request failed: {err}"))
     }
 
-    /// Get the GraphQL endpoint URL
-    fn get_endpoint() -> String {
-        format!(
-            "{}/{}",
-            State::get_backend_host(),
-            State::get_backend_path()
+    fn data() -> StubReply {
+        StubReply::json(200, json!({ "data": { "page": null } }))
+    }
+
+    #[test]
+    fn a_slow_response_times_out() {
+        let server = StubServer::start(vec![data().after(Duration::from_millis(500))]);
+        let client = client(&server).with_policy(
+            RequestPolicy::default()
+                .with_timeout(Duration::from_millis(50))
+                .with_max_retries(0)
+                .with_batching(false),
+        );
+
+        let error = run(query(&client)).unwrap_err();
+
+        assert!(matches!(error, GraphQLClientError::Timeout(_)), "{error}");
+        assert_eq!(AppErrorKind::from(&error), AppErrorKind::Timeout);
+    }
+
+    #[test]
+    fn a_query_is_retried_after_a_server_error() {
+        let server = StubServer::start(vec![StubReply::json(503, json!({})), data()]);
+
+        let data = run(query(&client(&server))).unwrap();
+
+        assert_eq!(data, json!({ "page": null }));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(|request| request.method == "POST"
+            && request.target == "/graphql"
+            && request.body.contains("WeptSettingsQuery")));
+    }
+
+    #[test]
+    fn a_query_is_retried_after_a_network_error() {
+        let server = StubServer::start(vec![StubReply::HangUp, data()]);
+
+        let data = run(query(&client(&server))).unwrap();
+
+        assert_eq!(data, json!({ "page": null }));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn retries_run_out_after_the_policy_allows() {
+        let server = StubServer::start(vec![StubReply::json(502, json!({}))]);
+
+        let error = run(query(&client(&server))).unwrap_err();
+
+        assert!(
+            matches!(
+                error,
+                GraphQLClientError::RetriesExhausted { attempts: 3, .. }
+            ),
+            "{error}"
+        );
+        assert_eq!(AppErrorKind::from(&error), AppErrorKind::RetriesExhausted);
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn graphql_errors_are_not_retried() {
+        let server = StubServer::start(vec![StubReply::json(
+            200,
+            json!({ "errors": [{ "message": "Cannot query field" }] }),
+        )]);
+
+        let error = run(query(&client(&server))).unwrap_err();
+
+        assert!(
+            matches!(error, GraphQLClientError::GraphQL { .. }),
+            "{error}"
+        );
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn a_mutation_is_never_retried() {
+        let server = StubServer::start(vec![
+            StubReply::json(503, json!({})),
+            StubReply::json(200, json!({ "data": { "emptyCart": null } })),
+        ]);
+
+        let result =
+            run(client(&server).execute_mutation::<_, EmptyCart>(empty_cart::Variables {}));
+
+        assert!(matches!(
+            result,
+            Err(GraphQLClientError::Http { status, .. }) if status == StatusCode::SERVICE_UNAVAILABLE
+        ));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn a_rate_limited_query_is_retried() {
+        let server = StubServer::start(vec![StubReply::json(429, json!({})), data()]);
+
+        run(query(&client(&server))).unwrap();
+
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn a_query_still_rate_limited_after_its_retries_is_reported_as_rate_limited() {
+        let server = StubServer::start(vec![StubReply::json(429, json!({}))]);
+
+        let error = run(query(&client(&server))).unwrap_err();
+
+        assert!(
+            matches!(error, GraphQLClientError::RetriesExhausted { .. }),
+            "{error}"
+        );
+        assert_eq!(AppErrorKind::from(&error), AppErrorKind::RateLimited);
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn identical_queries_in_flight_share_a_request() {
+        let server = StubServer::start(vec![data().after(Duration::from_millis(50))]);
+        let client = client(&server);
+
+        let (first, second) = run(async { futures::join!(query(&client), query(&client)) });
+
+        assert_eq!(first.unwrap(), second.unwrap());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn queries_in_flight_for_another_session_are_not_shared() {
+        let server = StubServer::start(vec![data().after(Duration::from_millis(20))]);
+        let client = client(&server);
+
+        State::set_session_token("first-session");
+        let (first, second) = run(async {
+            let mut first = Box::pin(query(&client));
+            assert!(futures::poll!(&mut first).is_pending());
+
+            State::set_session_token("second-session");
+            futures::join!(first, query(&client))
+        });
+        State::clear_session_token();
+
+        assert_eq!(first.unwrap(), second.unwrap());
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    /// A client that sends persisted queries to the stub server
+    fn persisted_client(server: &StubServer) -> GraphQLClient {
+        client(server).with_policy(
+            RequestPolicy::default()
+                .with_timeout(Duration::from_millis(100))
+                .with_persisted_queries(true),
         )
     }
+
+    fn persisted_query_error(message: &str) -> StubReply {
+        StubReply::json(200, json!({ "errors": [{ "message": message }] }))
+    }
+
+    #[test]
+    fn a_persisted_query_is_sent_as_its_hash() {
+        let server = StubServer::start(vec![data()]);
+
+        run(query(&persisted_client(&server))).unwrap();
+
+        let hash: String = PersistedQueries::hash(wept_settings_query::QUERY).unwrap();
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert!(requests[0].target.contains(&hash));
+        assert!(!requests[0].target.contains("query="));
+    }
+
+    #[test]
+    fn an_unknown_persisted_query_is_registered_with_its_document() {
+        let server = StubServer::start(vec![
+            persisted_query_error("PersistedQueryNotFound"),
+            data(),
+        ]);
+
+        let data = run(query(&persisted_client(&server))).unwrap();
+
+        assert_eq!(data, json!({ "page": null }));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "POST");
+        let body: Value = serde_json::from_str(&requests[1].body).unwrap();
+        assert_eq!(body["query"], wept_settings_query::QUERY);
+        assert_eq!(
+            body["extensions"]["persistedQuery"]["sha256Hash"],
+            PersistedQueries::hash(wept_settings_query::QUERY).unwrap()
+        );
+    }
+
+    #[test]
+    fn a_refused_registration_sends_queries_in_full() {
+        let server = StubServer::start(vec![
+            persisted_query_error("PersistedQueryNotFound"),
+            persisted_query_error("PersistedQueryNotFound"),
+            data(),
+        ]);
+        let client = persisted_client(&server);
+
+        run(query(&client)).unwrap();
+        run(query(&client)).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 4);
+        let body: Value = serde_json::from_str(&requests[2].body).unwrap();
+        assert_eq!(body["query"], wept_settings_query::QUERY);
+        assert!(body.get("extensions").is_none());
+        assert_eq!(requests[3].method, "POST");
+    }
+
+    #[test]
+    fn an_unsupported_persisted_query_is_sent_in_full() {
+        let server = StubServer::start(vec![
+            persisted_query_error("PersistedQueryNotSupported"),
+            data(),
+        ]);
+
+        run(query(&persisted_client(&server))).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        let body: Value = serde_json::from_str(&requests[1].body).unwrap();
+        assert!(body.get("extensions").is_none());
+    }
+
+    /// An auth token that expires some time from now
+    fn auth_token(expires_in: i64, user: &str) -> String {
+        jwt::encode(&json!({
+            "exp": chrono::Utc::now().timestamp() + expires_in,
+            "data": { "user": { "id": user } },
+        }))
+    }
+
+    #[test]
+    fn an_expiring_auth_token_is_refreshed_before_the_request() {
+        let renewed: String = auth_token(900, "renewed");
+        let server = StubServer::start(vec![
+            StubReply::json(
+                200,
+                json!({ "data": { "refreshJwtAuthToken": { "authToken": renewed } } }),
+            ),
+            data(),
+        ]);
+        State::set_auth_token(&auth_token(30, "expiring"));
+        State::set_refresh_token("refresh-token");
+
+        run(query(&client(&server))).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].body.contains("refresh-token"));
+        assert_eq!(requests[0].authorization, None);
+        assert_eq!(requests[1].authorization, Some(format!("Bearer {renewed}")));
+        assert_eq!(State::get_auth_token(), Some(renewed));
+        State::clear_auth_tokens();
+    }
+
+    #[test]
+    fn a_valid_auth_token_is_sent_as_is() {
+        let token: String = auth_token(900, "valid");
+        let server = StubServer::start(vec![data()]);
+        State::set_auth_token(&token);
+        State::set_refresh_token("refresh-token");
+
+        run(query(&client(&server))).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].authorization, Some(format!("Bearer {token}")));
+        State::clear_auth_tokens();
+    }
+
+    #[test]
+    fn a_failed_refresh_keeps_the_token_for_the_next_request() {
+        let expiring: String = auth_token(30, "expiring");
+        let server = StubServer::start(vec![StubReply::json(503, json!({})), data()]);
+        State::set_auth_token(&expiring);
+        State::set_refresh_token("refresh-token");
+
+        run(query(&client(&server))).unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[1].authorization,
+            Some(format!("Bearer {expiring}"))
+        );
+        assert_eq!(State::get_auth_token(), Some(expiring));
+        State::clear_auth_tokens();
+    }
 }
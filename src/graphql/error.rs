@@ -0,0 +1,221 @@
+use graphql_client::Error as GraphQLError;
+use reqwest::{Error as ReqwestError, StatusCode};
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    sync::Arc,
+};
+
+/// # GraphQL Client Error
+///
+/// A structured error raised by the `GraphQLClient`.
+///
+/// Keeps enough detail about the failure (transport, HTTP status, decoding or
+/// GraphQL `errors[]`) for `AppErrorKind` to decide how the UI should react.
+///
+/// Cloneable, so a single failed request can be reported to every caller that
+/// shared it.
+#[derive(Clone, Debug)]
+pub enum GraphQLClientError {
+    /// The request could not be sent, or the connection failed.
+    Network(Arc<ReqwestError>),
+    /// The request did not complete in time.
+    Timeout(Arc<ReqwestError>),
+    /// The backend responded with a non-success HTTP status and no GraphQL errors.
+    Http { status: StatusCode, body: String },
+    /// The response body could not be decoded.
+    Decode(Arc<serde_json::Error>),
+    /// The backend returned GraphQL `errors[]`.
+    GraphQL {
+        status: StatusCode,
+        errors: Vec<GraphQLError>,
+    },
+    /// The response contained neither data nor errors.
+    NoData,
+    /// Every attempt allowed by the `RequestPolicy` failed.
+    RetriesExhausted {
+        attempts: u32,
+        last: Box<GraphQLClientError>,
+    },
+}
+
+impl GraphQLClientError {
+    /// Gets the HTTP status of the response, if one was received.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<StatusCode>` - The HTTP status.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::Network(err) | Self::Timeout(err) => err.status(),
+            Self::Http { status, .. } | Self::GraphQL { status, .. } => Some(*status),
+            Self::Decode(_) | Self::NoData => None,
+            Self::RetriesExhausted { last, .. } => last.status(),
+        }
+    }
+
+    /// Checks whether the failure is likely to succeed if the request is retried.
+    ///
+    /// **Returns**
+    ///
+    /// * `bool` - Whether the request may be retried.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Network(_) | Self::Timeout(_) => true,
+            Self::Http { status, .. } => {
+                status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks whether the backend did not recognise a persisted query's hash.
+    ///
+    /// The query then has to be sent again with its full document.
+    ///
+    /// **Returns**
+    ///
+    /// * `bool` - Whether the persisted query was not found.
+    pub fn is_persisted_query_not_found(&self) -> bool {
+        self.has_graphql_error("PersistedQueryNotFound", "PERSISTED_QUERY_NOT_FOUND")
+    }
+
+    /// Checks whether the backend does not support persisted queries.
+    ///
+    /// **Returns**
+    ///
+    /// * `bool` - Whether persisted queries are not supported.
+    pub fn is_persisted_query_not_supported(&self) -> bool {
+        self.has_graphql_error(
+            "PersistedQueryNotSupported",
+            "PERSISTED_QUERY_NOT_SUPPORTED",
+        )
+    }
+
+    /// Checks whether a GraphQL error has a message or an `extensions.code`.
+    ///
+    /// **Arguments**
+    ///
+    /// * `message` - The error message.
+    /// * `code` - The error code.
+    ///
+    /// **Returns**
+    ///
+    /// * `bool` - Whether one of the GraphQL errors matches.
+    fn has_graphql_error(&self, message: &str, code: &str) -> bool {
+        let Self::GraphQL { errors, .. } = self else {
+            return false;
+        };
+
+        errors
+            .iter()
+            .any(|error| error.message == message || error_code(error) == Some(code))
+    }
+}
+
+/// Reads the WPGraphQL `extensions.category` of a GraphQL error.
+///
+/// **Arguments**
+///
+/// * `error` - The GraphQL error.
+///
+/// **Returns**
+///
+/// * `Option<&str>` - The category, such as `user`, `graphql` or `internal`.
+pub fn error_category(error: &GraphQLError) -> Option<&str> {
+    error.extensions.as_ref()?.get("category")?.as_str()
+}
+
+/// Reads the `extensions.code` of a GraphQL error.
+///
+/// **Arguments**
+///
+/// * `error` - The GraphQL error.
+///
+/// **Returns**
+///
+/// * `Option<&str>` - The code, such as `PERSISTED_QUERY_NOT_FOUND` or a WordPress
+///   error code such as `existing_user_email`.
+pub fn error_code(error: &GraphQLError) -> Option<&str> {
+    error.extensions.as_ref()?.get("code")?.as_str()
+}
+
+/// Reads the WPGraphQL `extensions.debugMessage` of a GraphQL error.
+///
+/// Only present when GraphQL debug mode is enabled on the WordPress backend.
+///
+/// **Arguments**
+///
+/// * `error` - The GraphQL error.
+///
+/// **Returns**
+///
+/// * `Option<&str>` - The debug message.
+pub fn error_debug_message(error: &GraphQLError) -> Option<&str> {
+    error.extensions.as_ref()?.get("debugMessage")?.as_str()
+}
+
+impl Display for GraphQLClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Network(err) => write!(f, "Request failed: {err}"),
+            Self::Timeout(err) => write!(f, "Request timed out: {err}"),
+            Self::Http { status, body } => write!(f, "HTTP {status}: {body}"),
+            Self::Decode(err) => write!(f, "Failed to parse response: {err}"),
+            Self::GraphQL { status, errors } => {
+                write!(f, "GraphQL error (HTTP {status}):")?;
+                for error in errors {
+                    write!(f, " [{error}")?;
+                    if let Some(category) = error_category(error) {
+                        write!(f, " (category: {category})")?;
+                    }
+                    if let Some(debug_message) = error_debug_message(error) {
+                        write!(f, " (debug: {debug_message})")?;
+                    }
+                    write!(f, "]")?;
+                }
+                Ok(())
+            }
+            Self::NoData => write!(f, "No data in response"),
+            Self::RetriesExhausted { attempts, last } => {
+                write!(f, "Request failed after {attempts} attempts: {last}")
+            }
+        }
+    }
+}
+
+impl Error for GraphQLClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Network(err) | Self::Timeout(err) => Some(err.as_ref()),
+            Self::Decode(err) => Some(err.as_ref()),
+            Self::RetriesExhausted { last, .. } => Some(last.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<ReqwestError> for GraphQLClientError {
+    /// Converts a reqwest error, separating timeouts from other network failures.
+    ///
+    /// **Arguments**
+    ///
+    /// * `error` - The reqwest error to convert.
+    ///
+    /// **Returns**
+    ///
+    /// * `GraphQLClientError` - The converted error.
+    fn from(error: ReqwestError) -> Self {
+        if error.is_timeout() {
+            Self::Timeout(Arc::new(error))
+        } else {
+            Self::Network(Arc::new(error))
+        }
+    }
+}
+
+impl From<serde_json::Error> for GraphQLClientError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Decode(Arc::new(error))
+    }
+}
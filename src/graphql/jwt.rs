@@ -0,0 +1,170 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::time::Duration;
+
+/// The claims of a WPGraphQL JWT Authentication token that the client relies on
+#[derive(Debug, Deserialize)]
+struct Claims {
+    /// When the token expires, in seconds since the Unix epoch
+    exp: i64,
+}
+
+/// The `data` claim of a WooCommerce session token or a JWT auth token
+#[derive(Debug, Deserialize)]
+struct Subject {
+    /// The `data` claim
+    data: SubjectData,
+}
+
+/// Who a token was issued to
+#[derive(Debug, Deserialize)]
+struct SubjectData {
+    /// The session's customer, for a WooCommerce session token
+    customer_id: Option<String>,
+    /// The signed-in user, for a JWT auth token
+    user: Option<SubjectUser>,
+}
+
+/// The user a JWT auth token was issued to
+#[derive(Debug, Deserialize)]
+struct SubjectUser {
+    /// The user's ID
+    id: String,
+}
+
+/// Decodes the payload of a JWT.
+fn payload(token: &str) -> Option<Vec<u8>> {
+    let payload: &str = token.split('.').nth(1)?;
+    URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()
+}
+
+/// Gets when a JWT expires, from its `exp` claim.
+///
+/// The signature is not verified, as only the backend can do that. The expiry
+/// only decides when to refresh the token.
+///
+/// **Arguments**
+///
+/// * `token` - The JWT.
+///
+/// **Returns**
+///
+/// * `Option<DateTime<Utc>>` - When the token expires, or `None` if it can't be decoded.
+pub fn expiry(token: &str) -> Option<DateTime<Utc>> {
+    let claims: Claims = serde_json::from_slice(&payload(token)?).ok()?;
+
+    DateTime::from_timestamp(claims.exp, 0)
+}
+
+/// Gets who a JWT was issued to.
+///
+/// A WooCommerce session token names the session's customer, and a JWT auth
+/// token the signed-in user. Renewing a token keeps its subject, so the subject
+/// identifies the session or customer across renewals.
+///
+/// **Arguments**
+///
+/// * `token` - The JWT.
+///
+/// **Returns**
+///
+/// * `Option<String>` - Who the token was issued to, or `None` if it can't be decoded.
+pub fn subject(token: &str) -> Option<String> {
+    let subject: Subject = serde_json::from_slice(&payload(token)?).ok()?;
+
+    subject
+        .data
+        .customer_id
+        .or_else(|| subject.data.user.map(|user| user.id))
+}
+
+/// Checks whether a JWT expires within a margin.
+///
+/// Tokens without a readable expiry are left to the backend to reject.
+///
+/// **Arguments**
+///
+/// * `token` - The JWT.
+/// * `margin` - How long before the expiry the token counts as expiring.
+///
+/// **Returns**
+///
+/// * `bool` - Whether the token expires within the margin.
+pub fn expires_within(token: &str, margin: Duration) -> bool {
+    let margin = chrono::Duration::from_std(margin).unwrap_or_default();
+    expiry(token).is_some_and(|expiry| expiry - margin <= Utc::now())
+}
+
+/// Encodes claims as an unsigned JWT, for tests of code that reads tokens.
+///
+/// **Arguments**
+///
+/// * `claims` - The claims of the token.
+///
+/// **Returns**
+///
+/// * `String` - The token.
+#[cfg(test)]
+pub fn encode(claims: &serde_json::Value) -> String {
+    let payload: String = URL_SAFE_NO_PAD.encode(claims.to_string());
+
+    format!("eyJhbGciOiJIUzI1NiJ9.{payload}.signature")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn encoded_claims_are_decoded() {
+        for claims in [
+            json!({}),
+            json!({ "a": 1 }),
+            json!({ "exp": 1_700_000_000 }),
+        ] {
+            let token: String = encode(&claims);
+            let decoded: serde_json::Value =
+                serde_json::from_slice(&payload(&token).unwrap()).unwrap();
+
+            assert_eq!(decoded, claims);
+        }
+    }
+
+    #[test]
+    fn the_expiry_is_read_from_the_exp_claim() {
+        let token: String = encode(&json!({ "exp": 1_700_000_000, "data": {} }));
+
+        assert_eq!(expiry(&token), DateTime::from_timestamp(1_700_000_000, 0));
+    }
+
+    #[test]
+    fn unreadable_tokens_have_no_expiry() {
+        assert_eq!(expiry("not a token"), None);
+        assert_eq!(expiry("header.!!!.signature"), None);
+        assert_eq!(expiry(&encode(&json!({ "data": {} }))), None);
+
+        assert!(!expires_within("not a token", Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn a_token_expires_within_a_margin_of_its_expiry() {
+        let token = |seconds: i64| encode(&json!({ "exp": Utc::now().timestamp() + seconds }));
+        let margin = Duration::from_secs(60);
+
+        assert!(expires_within(&token(-10), margin));
+        assert!(expires_within(&token(30), margin));
+        assert!(!expires_within(&token(600), margin));
+    }
+
+    #[test]
+    fn the_subject_is_the_session_customer_or_the_user() {
+        let session: String = encode(&json!({ "data": { "customer_id": "t_guest" } }));
+        let auth: String = encode(&json!({ "data": { "user": { "id": "7" } } }));
+
+        assert_eq!(subject(&session).as_deref(), Some("t_guest"));
+        assert_eq!(subject(&auth).as_deref(), Some("7"));
+        assert_eq!(subject(&encode(&json!({ "exp": 0 }))), None);
+    }
+}
@@ -2,5 +2,12 @@
 ///
 /// This module is responsible for all GraphQL-related operations, including
 /// client setup, query definitions, and execution.
+pub mod batch;
+pub mod cache;
 pub mod client;
+pub mod error;
+pub mod jwt;
 pub mod models;
+pub mod persisted;
+pub mod policy;
+pub mod transport;
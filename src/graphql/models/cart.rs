@@ -33,3 +33,35 @@ pub struct UpdateItemQuantities;
     response_derives = "Debug, Clone, PartialEq, serde::Serialize"
 )]
 pub struct RemoveItemsFromCart;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/apply_coupon_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct ApplyCoupon;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/remove_coupons_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct RemoveCoupons;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/empty_cart_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct EmptyCart;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/restore_cart_items_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct RestoreCartItems;
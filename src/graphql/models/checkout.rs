@@ -0,0 +1,28 @@
+#![allow(clippy::all)]
+
+use graphql_client::GraphQLQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/checkout_query.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct CheckoutQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/update_shipping_method_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct UpdateShippingMethod;
+
+// Generated enums already implement `Serialize`
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/checkout_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq"
+)]
+pub struct Checkout;
@@ -0,0 +1,70 @@
+#![allow(clippy::all)]
+
+use graphql_client::GraphQLQuery;
+
+// Generated enums already implement `Serialize`
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/customer_query.graphql",
+    response_derives = "Debug, Clone, PartialEq"
+)]
+pub struct CustomerQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/login_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct Login;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/refresh_jwt_auth_token_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct RefreshJwtAuthToken;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/forget_session_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct ForgetSession;
+
+// Generated enums already implement `Serialize`
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/register_customer_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq"
+)]
+pub struct RegisterCustomer;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/send_password_reset_email_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct SendPasswordResetEmail;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/reset_user_password_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq, serde::Serialize"
+)]
+pub struct ResetUserPassword;
+
+// Generated enums already implement `Serialize`
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/update_customer_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq"
+)]
+pub struct UpdateCustomer;
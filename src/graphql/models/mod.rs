@@ -1,7 +1,33 @@
+use serde::Serialize;
+
 pub mod cart;
 pub mod category;
+pub mod checkout;
+pub mod customer;
 pub mod navigation;
+pub mod order;
 pub mod page;
 pub mod post;
 pub mod product;
 pub mod settings;
+pub mod shipping;
+
+/// Gets the GraphQL name of a generated enum value, such as a country code or an
+/// order status.
+///
+/// Each operation module generates its own copy of an enum, so values are read
+/// through their serialized form.
+///
+/// **Arguments**
+///
+/// * `value` - The enum value.
+///
+/// **Returns**
+///
+/// * `String` - The value's name, e.g. `AU` or `PROCESSING`.
+pub fn enum_value<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
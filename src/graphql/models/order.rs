@@ -0,0 +1,21 @@
+#![allow(clippy::all)]
+
+use graphql_client::GraphQLQuery;
+
+// Generated enums already implement `Serialize`
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/customer_orders_query.graphql",
+    response_derives = "Debug, Clone, PartialEq"
+)]
+pub struct CustomerOrdersQuery;
+
+// Generated enums already implement `Serialize`
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/order_query.graphql",
+    response_derives = "Debug, Clone, PartialEq"
+)]
+pub struct OrderQuery;
@@ -0,0 +1,30 @@
+#![allow(clippy::all)]
+
+use graphql_client::GraphQLQuery;
+
+// Generated enums already implement `Serialize`
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/countries_query.graphql",
+    response_derives = "Debug, Clone, PartialEq"
+)]
+pub struct CountriesQuery;
+
+// Generated enums already implement `Serialize`
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/country_states_query.graphql",
+    response_derives = "Debug, Clone, PartialEq"
+)]
+pub struct CountryStatesQuery;
+
+// Generated enums already implement `Serialize`
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/graphql/schema/schema.graphql",
+    query_path = "src/graphql/schema/update_shipping_address_mutation.graphql",
+    response_derives = "Debug, Clone, PartialEq"
+)]
+pub struct UpdateShippingAddress;
@@ -0,0 +1,125 @@
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, fmt::Write, sync::LazyLock};
+
+// Generated by `build.rs` from the operations in `src/graphql/schema/`
+include!(concat!(env!("OUT_DIR"), "/persisted_queries.rs"));
+
+/// The sha256 hash of each operation in the manifest, by document
+///
+/// Hashed once, the first time a query is sent.
+static HASHES: LazyLock<HashMap<&'static str, String>> = LazyLock::new(|| {
+    PERSISTED_QUERIES
+        .iter()
+        .map(|query| (*query, sha256_hex(query)))
+        .collect()
+});
+
+/// # Persisted Queries
+///
+/// Hashes operations for Automatic Persisted Queries, and lists them in the
+/// persisted query manifest.
+///
+/// Only operations in the manifest are sent as Automatic Persisted Queries, so a
+/// backend restricted to the manifest accepts every request the client makes.
+pub struct PersistedQueries;
+
+impl PersistedQueries {
+    /// Gets the sha256 hash of a query document.
+    ///
+    /// The hash is taken over the exact document that is sent, so it matches the
+    /// hash the backend computes when the document is registered.
+    ///
+    /// **Arguments**
+    ///
+    /// * `query` - The query document, as built by `GraphQLQuery::build_query`.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<String>` - The hex-encoded hash, if the query is in the manifest.
+    pub fn hash(query: &str) -> Option<String> {
+        HASHES.get(query).cloned()
+    }
+
+    /// Gets the persisted query manifest.
+    ///
+    /// **Returns**
+    ///
+    /// * `BTreeMap<String, &str>` - The document of every operation in `src/graphql/schema/`, by sha256 hash.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn manifest() -> std::collections::BTreeMap<String, &'static str> {
+        HASHES
+            .iter()
+            .map(|(query, hash)| (hash.clone(), *query))
+            .collect()
+    }
+}
+
+/// Gets the hex-encoded SHA-256 hash of a string.
+///
+/// **Arguments**
+///
+/// * `input` - The string to hash.
+///
+/// **Returns**
+///
+/// * `String` - The hash, as 64 lowercase hex digits.
+fn sha256_hex(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+    use crate::graphql::models::product::products_query;
+
+    #[test]
+    fn sha256_matches_the_reference_vectors() {
+        assert_eq!(
+            sha256_hex(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha256_hex("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            sha256_hex("abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+        assert_eq!(
+            sha256_hex(&"a".repeat(1_000)),
+            "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3"
+        );
+    }
+
+    #[test]
+    fn the_hash_is_taken_over_the_document_that_is_sent() {
+        let hash: String = PersistedQueries::hash(products_query::QUERY).unwrap();
+
+        assert_eq!(hash, sha256_hex(products_query::QUERY));
+        assert_eq!(
+            PersistedQueries::manifest().get(&hash),
+            Some(&products_query::QUERY)
+        );
+    }
+
+    #[test]
+    fn only_operations_in_the_manifest_are_hashed() {
+        assert_eq!(PersistedQueries::hash("query Unknown { __typename }"), None);
+    }
+
+    #[test]
+    fn the_manifest_lists_every_operation() {
+        let manifest = PersistedQueries::manifest();
+
+        assert_eq!(manifest.len(), PERSISTED_QUERIES.len());
+        assert!(manifest
+            .iter()
+            .all(|(hash, query)| hash.len() == 64 && *hash == sha256_hex(query)));
+    }
+}
@@ -0,0 +1,211 @@
+use std::time::Duration;
+#[cfg(target_family = "wasm")]
+use wasm_bindgen::prelude::*;
+
+#[cfg(target_family = "wasm")]
+#[wasm_bindgen]
+extern "C" {
+    // Window setTimeout
+    #[wasm_bindgen(js_name = setTimeout)]
+    fn set_timeout(handler: &JsValue, timeout: i32) -> i32;
+}
+
+/// # Request Policy
+///
+/// Controls how long a request may take and how failed queries are retried.
+///
+/// Retries only ever apply to queries, as they are idempotent. Mutations such as
+/// cart and checkout actions are sent once, however the policy is configured.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RequestPolicy {
+    /// Maximum time to wait for a single attempt
+    pub timeout: Option<Duration>,
+    /// Number of retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each further retry
+    pub base_delay: Duration,
+    /// Upper bound for the delay between retries
+    pub max_delay: Duration,
+    /// Whether queries may be batched with other concurrent queries
+    pub batch: bool,
+    /// Whether queries are sent as Automatic Persisted Queries
+    pub persisted: bool,
+}
+
+impl Default for RequestPolicy {
+    /// # Default Request Policy
+    ///
+    /// A 10 second timeout with up to 2 retries, starting at 250ms apart. Queries
+    /// are batched, and sent in full rather than as persisted queries.
+    fn default() -> Self {
+        Self {
+            timeout: Some(Duration::from_secs(10)),
+            max_retries: 2,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(4),
+            batch: true,
+            persisted: false,
+        }
+    }
+}
+
+impl RequestPolicy {
+    /// Sets the timeout for a single attempt.
+    ///
+    /// **Arguments**
+    ///
+    /// * `timeout` - The maximum time to wait for a response.
+    ///
+    /// **Returns**
+    ///
+    /// * `RequestPolicy` - The updated policy.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the number of retries after the first attempt.
+    ///
+    /// **Arguments**
+    ///
+    /// * `max_retries` - The number of retries.
+    ///
+    /// **Returns**
+    ///
+    /// * `RequestPolicy` - The updated policy.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets whether queries may be batched with other concurrent queries.
+    ///
+    /// A batch is only answered once its slowest query completes, so slow queries
+    /// should opt out rather than hold up the rest.
+    ///
+    /// **Arguments**
+    ///
+    /// * `batch` - Whether to batch queries.
+    ///
+    /// **Returns**
+    ///
+    /// * `RequestPolicy` - The updated policy.
+    pub fn with_batching(mut self, batch: bool) -> Self {
+        self.batch = batch;
+        self
+    }
+
+    /// Sets whether queries are sent as Automatic Persisted Queries.
+    ///
+    /// Persisted queries are sent as a GET with only the query's sha256 hash, which
+    /// keeps large documents off the wire and lets a CDN cache the response. They
+    /// are never batched, as a batch has to be a POST.
+    ///
+    /// **Arguments**
+    ///
+    /// * `persisted` - Whether to send persisted queries.
+    ///
+    /// **Returns**
+    ///
+    /// * `RequestPolicy` - The updated policy.
+    pub fn with_persisted_queries(mut self, persisted: bool) -> Self {
+        self.persisted = persisted;
+        self
+    }
+
+    /// Gets the delay before a retry.
+    ///
+    /// Uses exponential backoff with "equal jitter": half of the delay is fixed and
+    /// half is random, so clients that failed together don't retry in lockstep.
+    ///
+    /// **Arguments**
+    ///
+    /// * `retry` - The retry number, starting at 0.
+    /// * `jitter` - The random part, from 0 to 1, usually from `policy::jitter`.
+    ///
+    /// **Returns**
+    ///
+    /// * `Duration` - The delay before the retry.
+    pub fn backoff(&self, retry: u32, jitter: f64) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay);
+        let half = delay / 2;
+
+        half + half.mul_f64(jitter.clamp(0.0, 1.0))
+    }
+}
+
+/// Gets a random jitter for a backoff.
+///
+/// **Returns**
+///
+/// * `f64` - A random number from 0 (inclusive) to 1 (exclusive).
+pub fn jitter() -> f64 {
+    #[cfg(target_family = "wasm")]
+    return js_sys::Math::random();
+
+    // Every `RandomState` is seeded from the operating system's random source
+    #[cfg(not(target_family = "wasm"))]
+    {
+        use std::hash::{BuildHasher, Hasher};
+
+        let random: u64 = std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish();
+        (random >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Waits for the given duration without blocking the event loop.
+///
+/// **Arguments**
+///
+/// * `duration` - The duration to wait.
+pub async fn sleep(duration: Duration) {
+    #[cfg(target_family = "wasm")]
+    {
+        let (sender, receiver) = futures::channel::oneshot::channel::<()>();
+        let handler: JsValue = Closure::once_into_js(move || {
+            let _ = sender.send(());
+        });
+        set_timeout(&handler, duration.as_millis().min(i32::MAX as u128) as i32);
+        let _ = receiver.await;
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    tokio::time::sleep(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_each_retry_up_to_the_max_delay() {
+        let policy = RequestPolicy::default();
+
+        assert_eq!(policy.backoff(0, 1.0), Duration::from_millis(250));
+        assert_eq!(policy.backoff(1, 1.0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(2, 1.0), Duration::from_secs(1));
+        assert_eq!(policy.backoff(10, 1.0), Duration::from_secs(4));
+    }
+
+    #[test]
+    fn backoff_keeps_half_of_the_delay_fixed() {
+        let policy = RequestPolicy::default();
+
+        assert_eq!(policy.backoff(1, 0.0), Duration::from_millis(250));
+        assert_eq!(policy.backoff(1, 0.5), Duration::from_millis(375));
+        assert_eq!(policy.backoff(1, 7.0), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn jitter_is_a_fraction_that_varies() {
+        let jitters: Vec<f64> = (0..32).map(|_| jitter()).collect();
+
+        assert!(jitters.iter().all(|jitter| (0.0..1.0).contains(jitter)));
+        assert!(jitters.iter().any(|jitter| *jitter != jitters[0]));
+    }
+}
@@ -0,0 +1,532 @@
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde_json::{json, Value};
+use std::{cell::RefCell, collections::HashMap};
+use uuid::Uuid;
+
+// Modules
+use crate::graphql::{
+    error::GraphQLClientError,
+    models::{
+        cart::{
+            add_to_cart, apply_coupon, cart_query, empty_cart, remove_coupons,
+            remove_items_from_cart, restore_cart_items, update_item_quantities,
+        },
+        checkout::{checkout, update_shipping_method},
+        product::products_query,
+        shipping::{country_states_query, update_shipping_address},
+    },
+    transport::{Transport, TransportRequest, TransportResponse},
+};
+
+// Generated by `build.rs` from the recorded responses in `fixtures/`
+include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+
+/// The coupons the fixture carts accept, each with its description and percentage off
+const FIXTURE_COUPONS: &[(&str, &str, f64)] = &[("welcome10", "10% off your order", 10.0)];
+
+/// The coupon the fixture carts reject as expired
+const EXPIRED_COUPON: &str = "expired";
+
+/// The shipping rates of the fixture carts, each with its label and cost
+const FIXTURE_SHIPPING_RATES: &[(&str, &str, f64)] = &[
+    ("flat_rate:1", "Flat rate", 5.0),
+    ("local_pickup:2", "Local pickup", 0.0),
+];
+
+/// The country whose states are recorded in the `CountryStatesQuery` fixture
+const FIXTURE_STATES_COUNTRY: &str = "AU";
+
+/// # Fixture Transport
+///
+/// Answers requests with recorded WordPress responses, chosen by operation name,
+/// so the app and its controllers can run without a backend.
+///
+/// Each fixture is a full GraphQL response, stored as `fixtures/<OperationName>.json`.
+/// Variables are ignored, except by the cart operations: every WooCommerce session
+/// gets its own cart, built from the products in the `ProductsQuery` fixture, and
+/// emptied by checking out. Carts accept the coupons in `FIXTURE_COUPONS`, and are
+/// shipped at `FIXTURE_SHIPPING_RATES` once a shipping country is set.
+#[derive(Clone, Debug, Default)]
+pub struct FixtureTransport {
+    /// The recorded responses, by operation name
+    fixtures: HashMap<String, Value>,
+    /// The cart of each session, by session token
+    carts: RefCell<HashMap<String, FixtureCart>>,
+}
+
+/// A session's cart.
+#[derive(Clone, Debug, Default)]
+struct FixtureCart {
+    /// The items
+    items: Vec<FixtureCartItem>,
+    /// The removed items, which can be restored until the cart is emptied
+    removed: Vec<FixtureCartItem>,
+    /// The applied coupon codes
+    coupons: Vec<String>,
+    /// The shipping destination, as set by `updateCustomer`
+    shipping: Option<Value>,
+    /// The chosen shipping rate ID
+    shipping_method: Option<String>,
+}
+
+/// An item in a session's cart.
+#[derive(Clone, Debug)]
+struct FixtureCartItem {
+    /// The cart item key
+    key: String,
+    /// The product node, from the `ProductsQuery` fixture
+    product: Value,
+    /// The quantity
+    quantity: i64,
+}
+
+impl FixtureTransport {
+    /// Creates a fixture transport with the fixtures embedded at build time.
+    ///
+    /// **Returns**
+    ///
+    /// * `FixtureTransport` - The transport.
+    pub fn embedded() -> Self {
+        let fixtures = FIXTURES
+            .iter()
+            .filter_map(
+                |(operation_name, fixture)| match serde_json::from_str(fixture) {
+                    Ok(fixture) => Some((operation_name.to_string(), fixture)),
+                    Err(err) => {
+                        tracing::error!("Invalid fixture {operation_name}.json: {err}");
+                        None
+                    }
+                },
+            )
+            .collect();
+
+        Self {
+            fixtures,
+            carts: RefCell::default(),
+        }
+    }
+
+    /// Creates a fixture transport with the fixtures in a directory.
+    ///
+    /// **Arguments**
+    ///
+    /// * `dir` - The directory containing the `<OperationName>.json` fixtures.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<FixtureTransport, String>` - The transport, or which fixture could not be read.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn from_dir(dir: &std::path::Path) -> Result<Self, String> {
+        let mut fixtures: HashMap<String, Value> = HashMap::new();
+        let entries = std::fs::read_dir(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+        for entry in entries {
+            let path = entry.map_err(|err| err.to_string())?.path();
+            let Some(operation_name) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .filter(|_| path.extension().is_some_and(|ext| ext == "json"))
+            else {
+                continue;
+            };
+
+            let fixture: String = std::fs::read_to_string(&path)
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+            let fixture: Value = serde_json::from_str(&fixture)
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+            fixtures.insert(operation_name.to_string(), fixture);
+        }
+
+        Ok(Self {
+            fixtures,
+            carts: RefCell::default(),
+        })
+    }
+
+    /// Gets the names of the operations with a fixture.
+    ///
+    /// **Returns**
+    ///
+    /// * `Vec<&str>` - The operation names.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn operation_names(&self) -> Vec<&str> {
+        self.fixtures.keys().map(String::as_str).collect()
+    }
+
+    /// Answers a single operation.
+    ///
+    /// Operations without a fixture are answered with a GraphQL error, as WPGraphQL
+    /// does for unknown fields.
+    ///
+    /// **Arguments**
+    ///
+    /// * `session` - The session token.
+    /// * `operation` - The operation, with its `operationName` and `variables`.
+    ///
+    /// **Returns**
+    ///
+    /// * `Value` - The GraphQL response.
+    pub fn respond(&self, session: &str, operation: &Value) -> Value {
+        let operation_name: &str = operation["operationName"].as_str().unwrap_or_default();
+        let variables: &Value = &operation["variables"];
+        let mut carts = self.carts.borrow_mut();
+        let cart: &mut FixtureCart = carts.entry(session.to_string()).or_default();
+
+        match operation_name {
+            cart_query::OPERATION_NAME => json!({ "data": { "cart": Self::cart(cart) } }),
+            add_to_cart::OPERATION_NAME => {
+                let product_id: i64 = variables["productId"].as_i64().unwrap_or_default();
+                let quantity: i64 = variables["quantity"].as_i64().unwrap_or(1);
+                let Some(product) = self.product(product_id) else {
+                    return json!({
+                        "data": { "addToCart": null },
+                        "errors": [{
+                            "message": format!("No product exists with the ID {product_id}"),
+                            "extensions": { "category": "user" },
+                        }],
+                    });
+                };
+
+                let key: String = format!("{:032x}", product_id);
+                match cart.items.iter_mut().find(|item| item.key == key) {
+                    Some(item) => item.quantity += quantity,
+                    None => cart.items.push(FixtureCartItem {
+                        key,
+                        product,
+                        quantity,
+                    }),
+                }
+                json!({ "data": { "addToCart": { "cart": Self::cart(cart) } } })
+            }
+            update_item_quantities::OPERATION_NAME => {
+                for update in variables["items"].as_array().into_iter().flatten() {
+                    let key: &str = update["key"].as_str().unwrap_or_default();
+                    let quantity: i64 = update["quantity"].as_i64().unwrap_or_default();
+                    if let Some(item) = cart.items.iter_mut().find(|item| item.key == key) {
+                        item.quantity = quantity;
+                    }
+                }
+                Self::remove_items(cart, |item| item.quantity <= 0);
+                json!({ "data": { "updateItemQuantities": { "cart": Self::cart(cart) } } })
+            }
+            remove_items_from_cart::OPERATION_NAME => {
+                let keys: Vec<&str> = variables["keys"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .collect();
+                Self::remove_items(cart, |item| keys.contains(&item.key.as_str()));
+                json!({ "data": { "removeItemsFromCart": { "cart": Self::cart(cart) } } })
+            }
+            restore_cart_items::OPERATION_NAME => {
+                let keys: Vec<&str> = variables["keys"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .collect();
+                let (restored, removed): (Vec<FixtureCartItem>, Vec<FixtureCartItem>) = cart
+                    .removed
+                    .drain(..)
+                    .partition(|item| keys.contains(&item.key.as_str()));
+                cart.removed = removed;
+                cart.items.extend(restored);
+                json!({ "data": { "restoreCartItems": { "cart": Self::cart(cart) } } })
+            }
+            empty_cart::OPERATION_NAME => {
+                cart.items.clear();
+                cart.removed.clear();
+                cart.coupons.clear();
+                json!({ "data": { "emptyCart": { "cart": Self::cart(cart) } } })
+            }
+            apply_coupon::OPERATION_NAME => {
+                // WooCommerce stores coupon codes in lowercase
+                let code: String = variables["code"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_lowercase();
+                let message: Option<String> = if code == EXPIRED_COUPON {
+                    Some("This coupon has expired.".to_string())
+                } else if !FIXTURE_COUPONS.iter().any(|(known, ..)| *known == code) {
+                    Some(format!("Coupon \"{code}\" does not exist!"))
+                } else if cart.coupons.contains(&code) {
+                    Some("Coupon code already applied!".to_string())
+                } else {
+                    None
+                };
+                if let Some(message) = message {
+                    return json!({
+                        "data": { "applyCoupon": null },
+                        "errors": [{
+                            "message": message,
+                            "extensions": { "category": "user" },
+                        }],
+                    });
+                }
+
+                cart.coupons.push(code.clone());
+                json!({
+                    "data": {
+                        "applyCoupon": { "applied": { "code": code }, "cart": Self::cart(cart) },
+                    },
+                })
+            }
+            remove_coupons::OPERATION_NAME => {
+                let codes: Vec<String> = variables["codes"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .map(str::to_lowercase)
+                    .collect();
+                cart.coupons.retain(|code| !codes.contains(code));
+                json!({ "data": { "removeCoupons": { "cart": Self::cart(cart) } } })
+            }
+            update_shipping_address::OPERATION_NAME => {
+                let shipping: &Value = &variables["shipping"];
+                let destination: Value = json!({
+                    "country": shipping["country"],
+                    "state": shipping["state"],
+                    "postcode": shipping["postcode"],
+                });
+                cart.shipping = Some(destination.clone());
+                json!({ "data": { "updateCustomer": { "customer": { "shipping": destination } } } })
+            }
+            update_shipping_method::OPERATION_NAME => {
+                let rate_id: &str = variables["shippingMethods"][0].as_str().unwrap_or_default();
+                if FIXTURE_SHIPPING_RATES.iter().any(|(id, ..)| *id == rate_id) {
+                    cart.shipping_method = Some(rate_id.to_string());
+                }
+                json!({ "data": { "updateShippingMethod": { "cart": Self::cart(cart) } } })
+            }
+            country_states_query::OPERATION_NAME => {
+                // Only one country's states are recorded, the others have none
+                match variables["country"].as_str() {
+                    Some(country) if country != FIXTURE_STATES_COUNTRY => {
+                        json!({ "data": { "countryStates": [] } })
+                    }
+                    _ => self.fixture(operation_name),
+                }
+            }
+            checkout::OPERATION_NAME => {
+                // Placing an order empties the cart, as it does in WooCommerce
+                *cart = FixtureCart::default();
+                self.fixture(operation_name)
+            }
+            _ => self.fixture(operation_name),
+        }
+    }
+
+    /// Moves items out of a cart, keeping them so they can be restored, as
+    /// WooCommerce does.
+    ///
+    /// **Arguments**
+    ///
+    /// * `cart` - The cart.
+    /// * `removed` - Whether an item is removed.
+    fn remove_items(cart: &mut FixtureCart, removed: impl Fn(&FixtureCartItem) -> bool) {
+        let (removed, items): (Vec<FixtureCartItem>, Vec<FixtureCartItem>) =
+            cart.items.drain(..).partition(removed);
+        cart.items = items;
+        for item in removed {
+            cart.removed.retain(|old| old.key != item.key);
+            cart.removed.push(item);
+        }
+    }
+
+    /// Gets the recorded response of an operation.
+    ///
+    /// **Arguments**
+    ///
+    /// * `operation_name` - The operation name.
+    ///
+    /// **Returns**
+    ///
+    /// * `Value` - The recorded response, or a GraphQL error if there is none.
+    fn fixture(&self, operation_name: &str) -> Value {
+        self.fixtures.get(operation_name).cloned().unwrap_or_else(|| {
+            json!({
+                "errors": [{ "message": format!("No fixture for operation `{operation_name}`") }],
+            })
+        })
+    }
+
+    /// Finds a product in the `ProductsQuery` fixture.
+    ///
+    /// **Arguments**
+    ///
+    /// * `database_id` - The product's database ID.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<Value>` - The product node.
+    fn product(&self, database_id: i64) -> Option<Value> {
+        self.fixtures.get(products_query::OPERATION_NAME)?["data"]["products"]["nodes"]
+            .as_array()?
+            .iter()
+            .find(|product| product["databaseId"].as_i64() == Some(database_id))
+            .cloned()
+    }
+
+    /// Builds a cart in the shape WooGraphQL returns it.
+    ///
+    /// Every cart operation gets the same fields, which covers each of their
+    /// selections.
+    ///
+    /// **Arguments**
+    ///
+    /// * `cart` - The cart.
+    ///
+    /// **Returns**
+    ///
+    /// * `Value` - The cart.
+    fn cart(cart: &FixtureCart) -> Value {
+        let mut cart_total: f64 = 0.0;
+        let nodes: Vec<Value> = cart
+            .items
+            .iter()
+            .map(|item| {
+                let price: f64 = item.product["rawPrice"]
+                    .as_str()
+                    .and_then(|price| price.parse().ok())
+                    .unwrap_or_default();
+                let total: f64 = price * item.quantity as f64;
+                cart_total += total;
+
+                json!({
+                    "__typename": "SimpleCartItem",
+                    "key": item.key,
+                    "product": {
+                        "node": {
+                            "__typename": item.product["__typename"],
+                            "databaseId": item.product["databaseId"],
+                            "name": item.product["name"],
+                            "slug": item.product["slug"],
+                            "image": item.product["image"],
+                        },
+                    },
+                    "variation": null,
+                    "quantity": item.quantity,
+                    "total": format!("${total:.2}"),
+                    "subtotal": format!("${total:.2}"),
+                })
+            })
+            .collect();
+
+        let mut discount_total: f64 = 0.0;
+        let applied_coupons: Vec<Value> = FIXTURE_COUPONS
+            .iter()
+            .filter(|(code, ..)| cart.coupons.iter().any(|applied| applied == code))
+            .map(|(code, description, percent)| {
+                let discount: f64 = cart_total * percent / 100.0;
+                discount_total += discount;
+                json!({
+                    "code": code,
+                    "description": description,
+                    "discountAmount": format!("${discount:.2}"),
+                })
+            })
+            .collect();
+
+        // Rates are only shown once the shopper enters a shipping country
+        let ships: bool = !cart.items.is_empty()
+            && cart
+                .shipping
+                .as_ref()
+                .is_some_and(|shipping| shipping["country"].is_string());
+        let shipping_method: Option<&(&str, &str, f64)> = FIXTURE_SHIPPING_RATES
+            .iter()
+            .find(|(id, ..)| Some(*id) == cart.shipping_method.as_deref())
+            .or(FIXTURE_SHIPPING_RATES.first())
+            .filter(|_| ships);
+        let shipping_total: f64 = shipping_method.map(|(.., cost)| *cost).unwrap_or_default();
+        let package_details: Vec<String> = cart
+            .items
+            .iter()
+            .map(|item| {
+                format!(
+                    "{} &times;{}",
+                    item.product["name"].as_str().unwrap_or_default(),
+                    item.quantity
+                )
+            })
+            .collect();
+        let available_shipping_methods: Vec<Value> = if ships {
+            vec![json!({
+                "packageDetails": package_details.join(", "),
+                "rates": FIXTURE_SHIPPING_RATES
+                    .iter()
+                    .map(|(id, label, cost)| json!({ "id": id, "label": label, "cost": format!("{cost:.2}") }))
+                    .collect::<Vec<Value>>(),
+            })]
+        } else {
+            Vec::new()
+        };
+
+        json!({
+            "contents": { "nodes": nodes },
+            "appliedCoupons": applied_coupons,
+            "needsShippingAddress": !cart.items.is_empty(),
+            "availableShippingMethods": available_shipping_methods,
+            "chosenShippingMethods": shipping_method.map(|(id, ..)| vec![*id]).unwrap_or_default(),
+            "fees": [],
+            "feeTotal": "$0.00",
+            "subtotal": format!("${cart_total:.2}"),
+            "discountTotal": format!("${discount_total:.2}"),
+            "shippingTotal": format!("${shipping_total:.2}"),
+            "totalTax": "$0.00",
+            "total": format!("${:.2}", cart_total - discount_total + shipping_total),
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl Transport for FixtureTransport {
+    async fn send(
+        &self,
+        request: TransportRequest,
+    ) -> Result<TransportResponse, GraphQLClientError> {
+        // Like WooGraphQL, start a session for requests that don't have one
+        let issued_token: Option<String> = match request.session_token {
+            Some(_) => None,
+            None => Some(Uuid::new_v4().to_string()),
+        };
+        let session: &str = request
+            .session_token
+            .as_deref()
+            .or(issued_token.as_deref())
+            .unwrap_or_default();
+
+        let response: Value = match &request.body {
+            Some(Value::Array(batch)) => batch
+                .iter()
+                .map(|operation| self.respond(session, operation))
+                .collect(),
+            Some(operation) => self.respond(session, operation),
+            None => {
+                let param = |name: &str| {
+                    request
+                        .params
+                        .iter()
+                        .find(|(key, _)| *key == name)
+                        .map(|(_, value)| value.as_str())
+                };
+                let variables: Value = param("variables")
+                    .and_then(|variables| serde_json::from_str(variables).ok())
+                    .unwrap_or_default();
+                self.respond(
+                    session,
+                    &json!({ "operationName": param("operationName"), "variables": variables }),
+                )
+            }
+        };
+
+        Ok(TransportResponse {
+            status: StatusCode::OK,
+            session_token: issued_token,
+            body: response.to_string(),
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use reqwest::{Client, Method};
+
+// Modules
+use crate::{
+    app::{config::AppConfig, state::SESSION_TOKEN_KEY},
+    graphql::{
+        error::GraphQLClientError,
+        transport::{Transport, TransportRequest, TransportResponse},
+    },
+};
+
+/// # HTTP Transport
+///
+/// Sends requests to the configured WordPress backend.
+#[derive(Clone, Debug, Default)]
+pub struct HttpTransport {
+    /// HTTP client for making network requests
+    client: Client,
+    /// The GraphQL endpoint URL
+    endpoint: String,
+}
+
+impl HttpTransport {
+    /// Creates a new HTTP transport for the configured backend.
+    pub fn new() -> Self {
+        Self::with_endpoint(Self::get_endpoint())
+    }
+
+    /// Creates a new HTTP transport for an endpoint.
+    ///
+    /// **Arguments**
+    ///
+    /// * `endpoint` - The GraphQL endpoint URL.
+    ///
+    /// **Returns**
+    ///
+    /// * `HttpTransport` - The transport.
+    pub fn with_endpoint(endpoint: String) -> Self {
+        Self {
+            client: Client::new(),
+            endpoint,
+        }
+    }
+
+    /// Get the GraphQL endpoint URL
+    ///
+    /// Empty when the configuration is invalid, which `MainLayout` reports instead
+    /// of rendering any page that would make a request.
+    fn get_endpoint() -> String {
+        AppConfig::get()
+            .map(|config| format!("{}/{}", config.backend_host, config.backend_path))
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait(?Send)]
+impl Transport for HttpTransport {
+    async fn send(
+        &self,
+        request: TransportRequest,
+    ) -> Result<TransportResponse, GraphQLClientError> {
+        let mut builder = if request.method == Method::GET {
+            self.client.get(&self.endpoint).query(&request.params)
+        } else {
+            self.client.post(&self.endpoint).json(&request.body)
+        };
+        if let Some(token) = request.session_token {
+            builder = builder.header(SESSION_TOKEN_KEY, format!("Session {}", token));
+        }
+        if let Some(token) = request.auth_token {
+            builder = builder.bearer_auth(token);
+        }
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let response = builder.send().await?;
+        let session_token: Option<String> = response
+            .headers()
+            .get(SESSION_TOKEN_KEY)
+            .and_then(|token| token.to_str().ok())
+            .map(str::to_string);
+
+        Ok(TransportResponse {
+            status: response.status(),
+            session_token,
+            body: response.text().await?,
+        })
+    }
+}
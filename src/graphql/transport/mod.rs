@@ -0,0 +1,111 @@
+pub mod fixture;
+pub mod http;
+#[cfg(all(test, not(target_family = "wasm")))]
+pub mod stub;
+
+use async_trait::async_trait;
+use reqwest::{Method, StatusCode};
+use serde_json::Value;
+use std::{fmt::Debug, time::Duration};
+
+// Modules
+use crate::graphql::error::GraphQLClientError;
+
+/// # Transport
+///
+/// Delivers a GraphQL request to a backend and returns its raw response.
+///
+/// `GraphQLClient` handles everything else (retries, batching, caching and
+/// sessions), so a transport only has to answer requests.
+#[async_trait(?Send)]
+pub trait Transport: Debug {
+    /// Sends a request.
+    ///
+    /// **Arguments**
+    ///
+    /// * `request` - The request to send.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<TransportResponse, GraphQLClientError>` - The response, or why none was received.
+    async fn send(
+        &self,
+        request: TransportRequest,
+    ) -> Result<TransportResponse, GraphQLClientError>;
+}
+
+/// # Transport Request
+///
+/// A GraphQL request, either a POST with a JSON body or a GET with query parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransportRequest {
+    /// The HTTP method
+    pub method: Method,
+    /// The query parameters of a GET request
+    pub params: Vec<(&'static str, String)>,
+    /// The JSON body of a POST request, a single operation or a batch of them
+    pub body: Option<Value>,
+    /// The WooCommerce session token
+    pub session_token: Option<String>,
+    /// The signed-in customer's JWT auth token
+    pub auth_token: Option<String>,
+    /// Maximum time to wait for the response
+    pub timeout: Option<Duration>,
+}
+
+impl TransportRequest {
+    /// Creates a POST request.
+    ///
+    /// **Arguments**
+    ///
+    /// * `body` - The JSON body.
+    /// * `timeout` - Maximum time to wait for the response.
+    ///
+    /// **Returns**
+    ///
+    /// * `TransportRequest` - The request.
+    pub fn post(body: Value, timeout: Option<Duration>) -> Self {
+        Self {
+            method: Method::POST,
+            params: Vec::new(),
+            body: Some(body),
+            session_token: None,
+            auth_token: None,
+            timeout,
+        }
+    }
+
+    /// Creates a GET request.
+    ///
+    /// **Arguments**
+    ///
+    /// * `params` - The query parameters.
+    /// * `timeout` - Maximum time to wait for the response.
+    ///
+    /// **Returns**
+    ///
+    /// * `TransportRequest` - The request.
+    pub fn get(params: Vec<(&'static str, String)>, timeout: Option<Duration>) -> Self {
+        Self {
+            method: Method::GET,
+            params,
+            body: None,
+            session_token: None,
+            auth_token: None,
+            timeout,
+        }
+    }
+}
+
+/// # Transport Response
+///
+/// The raw response to a GraphQL request.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransportResponse {
+    /// The HTTP status
+    pub status: StatusCode,
+    /// The WooCommerce session token, if the backend issued or renewed one
+    pub session_token: Option<String>,
+    /// The response body
+    pub body: String,
+}
@@ -0,0 +1,204 @@
+use serde_json::Value;
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+/// # Stub Reply
+///
+/// How the stub server answers a request.
+#[derive(Clone, Debug)]
+pub enum StubReply {
+    /// Respond with a status and a body, after a delay
+    Respond {
+        status: u16,
+        body: String,
+        delay: Duration,
+    },
+    /// Close the connection without responding
+    HangUp,
+}
+
+impl StubReply {
+    /// Creates a reply with a JSON body.
+    ///
+    /// **Arguments**
+    ///
+    /// * `status` - The HTTP status.
+    /// * `body` - The JSON body.
+    ///
+    /// **Returns**
+    ///
+    /// * `StubReply` - The reply, sent at once.
+    pub fn json(status: u16, body: Value) -> Self {
+        Self::Respond {
+            status,
+            body: body.to_string(),
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Delays a reply.
+    ///
+    /// **Arguments**
+    ///
+    /// * `delay` - How long to wait before responding.
+    ///
+    /// **Returns**
+    ///
+    /// * `StubReply` - The delayed reply.
+    pub fn after(self, delay: Duration) -> Self {
+        match self {
+            Self::Respond { status, body, .. } => Self::Respond {
+                status,
+                body,
+                delay,
+            },
+            Self::HangUp => Self::HangUp,
+        }
+    }
+}
+
+/// # Stub Request
+///
+/// A request received by the stub server.
+#[derive(Clone, Debug)]
+pub struct StubRequest {
+    /// The HTTP method
+    pub method: String,
+    /// The request target, with its query string
+    pub target: String,
+    /// The `Authorization` header, if any
+    pub authorization: Option<String>,
+    /// The body
+    pub body: String,
+}
+
+/// # Stub Server
+///
+/// A local HTTP server for tests, which answers with scripted replies so a
+/// backend can be slow or fail on cue.
+///
+/// Each request gets its own connection, which is closed after the reply.
+pub struct StubServer {
+    /// The URL of the server's `/graphql` endpoint
+    endpoint: String,
+    /// The requests received so far
+    requests: Arc<Mutex<Vec<StubRequest>>>,
+}
+
+impl StubServer {
+    /// Starts a server that answers with the replies in order, repeating the last one.
+    ///
+    /// **Arguments**
+    ///
+    /// * `replies` - The replies.
+    ///
+    /// **Returns**
+    ///
+    /// * `StubServer` - The running server, which stops with the test process.
+    pub fn start(replies: Vec<StubReply>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind the stub server");
+        let endpoint = format!("http://{}/graphql", listener.local_addr().expect("address"));
+        let requests: Arc<Mutex<Vec<StubRequest>>> = Arc::default();
+
+        let received = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (index, stream) in listener.incoming().enumerate() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let Some(request) = read_request(&mut stream) else {
+                    continue;
+                };
+                received.lock().expect("requests").push(request);
+
+                match replies.get(index).or(replies.last()) {
+                    Some(StubReply::Respond {
+                        status,
+                        body,
+                        delay,
+                    }) => {
+                        thread::sleep(*delay);
+                        let _ = write!(
+                            stream,
+                            "HTTP/1.1 {status} Stub\r\nContent-Type: application/json\r\n\
+                             Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                            body.len()
+                        );
+                    }
+                    Some(StubReply::HangUp) | None => {}
+                }
+            }
+        });
+
+        Self { endpoint, requests }
+    }
+
+    /// Gets the URL of the server's `/graphql` endpoint.
+    ///
+    /// **Returns**
+    ///
+    /// * `String` - The endpoint URL.
+    pub fn endpoint(&self) -> String {
+        self.endpoint.clone()
+    }
+
+    /// Gets the requests received so far.
+    ///
+    /// **Returns**
+    ///
+    /// * `Vec<StubRequest>` - The requests, in the order they were received.
+    pub fn requests(&self) -> Vec<StubRequest> {
+        self.requests.lock().expect("requests").clone()
+    }
+}
+
+/// Reads an HTTP request from a connection.
+///
+/// **Arguments**
+///
+/// * `stream` - The connection.
+///
+/// **Returns**
+///
+/// * `Option<StubRequest>` - The request, or `None` if it could not be read.
+fn read_request(stream: &mut impl Read) -> Option<StubRequest> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line: String = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    let mut parts = request_line.split_whitespace();
+    let method: String = parts.next()?.to_string();
+    let target: String = parts.next()?.to_string();
+
+    let mut content_length: usize = 0;
+    let mut authorization: Option<String> = None;
+    loop {
+        let mut header: String = String::new();
+        reader.read_line(&mut header).ok()?;
+        let header: &str = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().ok()?;
+            } else if name.eq_ignore_ascii_case("authorization") {
+                authorization = Some(value.trim().to_string());
+            }
+        }
+    }
+
+    let mut body: Vec<u8> = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(StubRequest {
+        method,
+        target,
+        authorization,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
@@ -3,9 +3,12 @@ use dioxus::prelude::*;
 
 // Modules
 mod app;
+#[cfg(not(target_family = "wasm"))]
+mod cli;
 mod controllers;
 mod graphql;
 mod models;
+mod payments;
 mod routes;
 mod views;
 use routes::Routes;
@@ -21,6 +24,12 @@ fn main() {
         tracing_subscriber::fmt::init();
     }
 
+    // Run a command-line tool instead of the app, if one was requested
+    #[cfg(not(target_family = "wasm"))]
+    if let Some(exit_code) = cli::run(std::env::args().skip(1)) {
+        std::process::exit(exit_code);
+    }
+
     launch(move || {
         rsx! {
             // Tailwind stylesheet
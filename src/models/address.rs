@@ -0,0 +1,316 @@
+use serde::{Deserialize, Serialize};
+
+// Modules
+use crate::graphql::models::{
+    checkout::checkout,
+    customer::{customer_query, register_customer, update_customer},
+    enum_value,
+    order::order_query,
+    shipping::update_shipping_address,
+};
+
+/// Address entity representing a WooCommerce billing or shipping address
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct Address {
+    /// First name
+    pub first_name: String,
+    /// Last name
+    pub last_name: String,
+    /// Company name
+    pub company: String,
+    /// Address line 1
+    pub address_1: String,
+    /// Address line 2
+    pub address_2: String,
+    /// City or suburb
+    pub city: String,
+    /// State or county
+    pub state: String,
+    /// Postcode
+    pub postcode: String,
+    /// ISO 3166-1 alpha-2 country code
+    pub country: String,
+    /// Email address
+    pub email: String,
+    /// Phone number
+    pub phone: String,
+}
+
+impl Address {
+    /// Gets the address as lines, for display.
+    ///
+    /// **Returns**
+    ///
+    /// * `Vec<String>` - The name, company, street, locality and country lines that are set.
+    pub fn lines(&self) -> Vec<String> {
+        let name: String = format!("{} {}", self.first_name, self.last_name);
+        let locality: String = format!("{} {} {}", self.city, self.state, self.postcode);
+
+        [
+            name.as_str(),
+            &self.company,
+            &self.address_1,
+            &self.address_2,
+            locality.as_str(),
+            &self.country,
+        ]
+        .into_iter()
+        .filter_map(non_empty)
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect()
+    }
+}
+
+/// Converts a form value to an optional input, leaving out blank values.
+///
+/// **Arguments**
+///
+/// * `value` - The form value.
+///
+/// **Returns**
+///
+/// * `Option<String>` - The trimmed value, or `None` if it is blank.
+pub fn non_empty(value: &str) -> Option<String> {
+    Some(value.trim().to_string()).filter(|value| !value.is_empty())
+}
+
+impl From<&Address> for register_customer::CustomerAddressInput {
+    /// Convert an Address to a CustomerAddressInput
+    ///
+    /// **Arguments**
+    ///
+    /// * `address` - The address to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `CustomerAddressInput` - The converted CustomerAddressInput
+    fn from(address: &Address) -> Self {
+        Self {
+            first_name: non_empty(&address.first_name),
+            last_name: non_empty(&address.last_name),
+            company: non_empty(&address.company),
+            address1: non_empty(&address.address_1),
+            address2: non_empty(&address.address_2),
+            city: non_empty(&address.city),
+            state: non_empty(&address.state),
+            postcode: non_empty(&address.postcode),
+            // The backend validates the code, and reports unknown countries
+            country: non_empty(&address.country)
+                .map(|country| register_customer::CountriesEnum::Other(country.to_uppercase())),
+            email: non_empty(&address.email),
+            phone: non_empty(&address.phone),
+            overwrite: None,
+        }
+    }
+}
+
+impl From<&Address> for update_customer::CustomerAddressInput {
+    /// Convert an Address to a CustomerAddressInput
+    ///
+    /// **Arguments**
+    ///
+    /// * `address` - The address to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `CustomerAddressInput` - The converted CustomerAddressInput
+    fn from(address: &Address) -> Self {
+        Self {
+            first_name: non_empty(&address.first_name),
+            last_name: non_empty(&address.last_name),
+            company: non_empty(&address.company),
+            address1: non_empty(&address.address_1),
+            address2: non_empty(&address.address_2),
+            city: non_empty(&address.city),
+            state: non_empty(&address.state),
+            postcode: non_empty(&address.postcode),
+            // The backend validates the code, and reports unknown countries
+            country: non_empty(&address.country)
+                .map(|country| update_customer::CountriesEnum::Other(country.to_uppercase())),
+            email: non_empty(&address.email),
+            phone: non_empty(&address.phone),
+            overwrite: None,
+        }
+    }
+}
+
+impl From<&Address> for checkout::CustomerAddressInput {
+    /// Convert an Address to a CustomerAddressInput
+    ///
+    /// **Arguments**
+    ///
+    /// * `address` - The address to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `CustomerAddressInput` - The converted CustomerAddressInput
+    fn from(address: &Address) -> Self {
+        Self {
+            first_name: non_empty(&address.first_name),
+            last_name: non_empty(&address.last_name),
+            company: non_empty(&address.company),
+            address1: non_empty(&address.address_1),
+            address2: non_empty(&address.address_2),
+            city: non_empty(&address.city),
+            state: non_empty(&address.state),
+            postcode: non_empty(&address.postcode),
+            // The backend validates the code, and reports unknown countries
+            country: non_empty(&address.country)
+                .map(|country| checkout::CountriesEnum::Other(country.to_uppercase())),
+            email: non_empty(&address.email),
+            phone: non_empty(&address.phone),
+            overwrite: None,
+        }
+    }
+}
+
+impl From<&Address> for update_shipping_address::CustomerAddressInput {
+    /// Convert an Address to a CustomerAddressInput
+    ///
+    /// **Arguments**
+    ///
+    /// * `address` - The address to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `CustomerAddressInput` - The converted CustomerAddressInput
+    fn from(address: &Address) -> Self {
+        Self {
+            first_name: non_empty(&address.first_name),
+            last_name: non_empty(&address.last_name),
+            company: non_empty(&address.company),
+            address1: non_empty(&address.address_1),
+            address2: non_empty(&address.address_2),
+            city: non_empty(&address.city),
+            state: non_empty(&address.state),
+            postcode: non_empty(&address.postcode),
+            // The backend validates the code, and reports unknown countries
+            country: non_empty(&address.country).map(|country| {
+                update_shipping_address::CountriesEnum::Other(country.to_uppercase())
+            }),
+            email: non_empty(&address.email),
+            phone: non_empty(&address.phone),
+            overwrite: None,
+        }
+    }
+}
+
+impl From<customer_query::AddressFields> for Address {
+    /// Convert an AddressFields fragment to an Address
+    ///
+    /// **Arguments**
+    ///
+    /// * `address` - The GraphQL address to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Address` - The converted Address
+    fn from(address: customer_query::AddressFields) -> Self {
+        Self {
+            first_name: address.first_name.unwrap_or_default(),
+            last_name: address.last_name.unwrap_or_default(),
+            company: address.company.unwrap_or_default(),
+            address_1: address.address1.unwrap_or_default(),
+            address_2: address.address2.unwrap_or_default(),
+            city: address.city.unwrap_or_default(),
+            state: address.state.unwrap_or_default(),
+            postcode: address.postcode.unwrap_or_default(),
+            country: address
+                .country
+                .map(|country| enum_value(&country))
+                .unwrap_or_default(),
+            email: address.email.unwrap_or_default(),
+            phone: address.phone.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<update_customer::AddressFields> for Address {
+    /// Convert an AddressFields fragment to an Address
+    ///
+    /// **Arguments**
+    ///
+    /// * `address` - The GraphQL address to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Address` - The converted Address
+    fn from(address: update_customer::AddressFields) -> Self {
+        Self {
+            first_name: address.first_name.unwrap_or_default(),
+            last_name: address.last_name.unwrap_or_default(),
+            company: address.company.unwrap_or_default(),
+            address_1: address.address1.unwrap_or_default(),
+            address_2: address.address2.unwrap_or_default(),
+            city: address.city.unwrap_or_default(),
+            state: address.state.unwrap_or_default(),
+            postcode: address.postcode.unwrap_or_default(),
+            country: address
+                .country
+                .map(|country| enum_value(&country))
+                .unwrap_or_default(),
+            email: address.email.unwrap_or_default(),
+            phone: address.phone.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<order_query::AddressFields> for Address {
+    /// Convert an AddressFields fragment to an Address
+    ///
+    /// **Arguments**
+    ///
+    /// * `address` - The GraphQL address to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Address` - The converted Address
+    fn from(address: order_query::AddressFields) -> Self {
+        Self {
+            first_name: address.first_name.unwrap_or_default(),
+            last_name: address.last_name.unwrap_or_default(),
+            company: address.company.unwrap_or_default(),
+            address_1: address.address1.unwrap_or_default(),
+            address_2: address.address2.unwrap_or_default(),
+            city: address.city.unwrap_or_default(),
+            state: address.state.unwrap_or_default(),
+            postcode: address.postcode.unwrap_or_default(),
+            country: address
+                .country
+                .map(|country| enum_value(&country))
+                .unwrap_or_default(),
+            email: address.email.unwrap_or_default(),
+            phone: address.phone.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<checkout::AddressFields> for Address {
+    /// Convert an AddressFields fragment to an Address
+    ///
+    /// **Arguments**
+    ///
+    /// * `address` - The GraphQL address to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Address` - The converted Address
+    fn from(address: checkout::AddressFields) -> Self {
+        Self {
+            first_name: address.first_name.unwrap_or_default(),
+            last_name: address.last_name.unwrap_or_default(),
+            company: address.company.unwrap_or_default(),
+            address_1: address.address1.unwrap_or_default(),
+            address_2: address.address2.unwrap_or_default(),
+            city: address.city.unwrap_or_default(),
+            state: address.state.unwrap_or_default(),
+            postcode: address.postcode.unwrap_or_default(),
+            country: address
+                .country
+                .map(|country| enum_value(&country))
+                .unwrap_or_default(),
+            email: address.email.unwrap_or_default(),
+            phone: address.phone.unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,445 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// Modules
+use crate::{
+    graphql::models::{
+        cart::{
+            add_to_cart, apply_coupon, cart_query, empty_cart, remove_coupons,
+            remove_items_from_cart, restore_cart_items, update_item_quantities,
+        },
+        checkout::{checkout_query::CheckoutQueryCartFees, update_shipping_method},
+    },
+    models::{
+        checkout::{ShippingPackage, ShippingRate},
+        product::ProductImage,
+    },
+};
+
+/// The version of the cart's format in local storage
+///
+/// Bump it whenever `Cart` changes shape, and migrate the older versions in
+/// `Cart::from_stored`.
+pub const CART_STORAGE_VERSION: u32 = 2;
+
+/// Cart entity representing the shopper's WooCommerce cart
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct Cart {
+    /// The lines of the cart, one per product or variation
+    pub lines: Vec<CartLine>,
+    /// The coupons applied to the cart
+    pub applied_coupons: Vec<AppliedCoupon>,
+    /// Whether the cart has items that are shipped
+    pub needs_shipping_address: bool,
+    /// The shipping packages, each with the rates available for the customer's address
+    pub shipping_packages: Vec<ShippingPackage>,
+    /// The chosen rate ID of each shipping package
+    pub chosen_shipping_methods: Vec<String>,
+    /// The totals of the cart
+    pub totals: CartTotals,
+}
+
+/// A line of the cart: a product, or a variation of a variable product
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct CartLine {
+    /// Cart item key
+    pub key: String,
+    /// Product database ID
+    pub product_id: i64,
+    /// Variation database ID, for variable products
+    pub variation_id: Option<i64>,
+    /// Name, which is the variation's name for variable products
+    pub name: String,
+    /// Product slug
+    pub slug: Option<String>,
+    /// The attributes chosen for the variation, e.g. `Size: Large`
+    pub attributes: Vec<CartLineAttribute>,
+    /// Product image
+    pub image: Option<ProductImage>,
+    /// Quantity
+    pub quantity: i64,
+    /// Subtotal, before discounts
+    pub subtotal: String,
+    /// Total
+    pub total: String,
+}
+
+/// An attribute chosen for a variation in the cart
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct CartLineAttribute {
+    /// Attribute label, e.g. `Size`
+    pub label: String,
+    /// Attribute value, e.g. `Large`
+    pub value: String,
+}
+
+/// A cart in local storage, with the version of its format
+#[derive(Serialize, Deserialize)]
+struct StoredCart {
+    /// The version of the cart's format
+    version: u32,
+    /// The cart, in that version's format
+    cart: Value,
+}
+
+impl Cart {
+    /// Counts the items in the cart.
+    ///
+    /// **Returns**
+    ///
+    /// * `i64` - The sum of the quantities of the lines.
+    pub fn item_count(&self) -> i64 {
+        self.lines.iter().map(|line| line.quantity).sum()
+    }
+
+    /// Converts the cart for local storage.
+    ///
+    /// **Returns**
+    ///
+    /// * `Value` - The cart, tagged with `CART_STORAGE_VERSION`.
+    pub fn to_stored(&self) -> Value {
+        serde_json::to_value(StoredCart {
+            version: CART_STORAGE_VERSION,
+            cart: serde_json::to_value(self).unwrap_or_default(),
+        })
+        .unwrap_or_default()
+    }
+
+    /// Restores a cart from local storage, migrating older formats.
+    ///
+    /// Carts that can't be migrated are discarded: the WooCommerce session
+    /// keeps the cart, and it is refetched when the app starts. This includes
+    /// carts stored before the format was versioned, which held the generated
+    /// GraphQL types.
+    ///
+    /// **Arguments**
+    ///
+    /// * `stored` - The stored cart.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<Cart>` - The cart, or `None` if it was discarded.
+    pub fn from_stored(stored: Value) -> Option<Self> {
+        let stored: StoredCart = serde_json::from_value(stored).ok()?;
+        match stored.version {
+            CART_STORAGE_VERSION => serde_json::from_value(stored.cart).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// The totals of a cart, as formatted by WooCommerce
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct CartTotals {
+    /// Subtotal, before discounts, fees, shipping and taxes
+    pub subtotal: String,
+    /// Total discount of the applied coupons
+    pub discount_total: String,
+    /// Fees added to the cart
+    pub fees: Vec<Fee>,
+    /// Total of the fees
+    pub fee_total: String,
+    /// Shipping total
+    pub shipping_total: String,
+    /// Tax total
+    pub total_tax: String,
+    /// Total
+    pub total: String,
+}
+
+impl CartTotals {
+    /// Checks whether the cart is discounted.
+    ///
+    /// **Returns**
+    ///
+    /// * `bool` - Whether the discount total is more than zero.
+    pub fn has_discount(&self) -> bool {
+        // The currency symbol may be an HTML entity, e.g. `&#36;0.00`
+        let mut in_entity: bool = false;
+        self.discount_total.chars().any(|c| match c {
+            '&' => {
+                in_entity = true;
+                false
+            }
+            ';' if in_entity => {
+                in_entity = false;
+                false
+            }
+            c => !in_entity && ('1'..='9').contains(&c),
+        })
+    }
+}
+
+/// A coupon applied to the cart
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct AppliedCoupon {
+    /// Coupon code
+    pub code: String,
+    /// Coupon description
+    pub description: Option<String>,
+    /// The discount the coupon gives
+    pub discount_amount: String,
+}
+
+/// A fee added to the cart, such as a surcharge
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct Fee {
+    /// Fee ID
+    pub id: String,
+    /// Fee name
+    pub name: String,
+    /// Fee total
+    pub total: Option<f64>,
+}
+
+/// Implements the conversions from the `CartFields` fragment of a cart operation
+///
+/// Every cart operation selects the same fragment, so each of their payloads
+/// carries the whole cart, but the generated types are per operation.
+macro_rules! impl_from_cart_fields {
+    ($operation:ident) => {
+        impl From<$operation::CartFields> for Cart {
+            /// Convert a CartFields fragment to a Cart
+            ///
+            /// **Arguments**
+            ///
+            /// * `cart` - The CartFields fragment to convert
+            ///
+            /// **Returns**
+            ///
+            /// * `Cart` - The converted Cart
+            fn from(cart: $operation::CartFields) -> Self {
+                Self {
+                    lines: cart
+                        .contents
+                        .map(|contents| contents.nodes)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(CartLine::from)
+                        .collect(),
+                    applied_coupons: cart
+                        .applied_coupons
+                        .unwrap_or_default()
+                        .into_iter()
+                        .flatten()
+                        .map(|coupon| AppliedCoupon {
+                            code: coupon.code,
+                            description: coupon.description,
+                            discount_amount: coupon.discount_amount,
+                        })
+                        .collect(),
+                    needs_shipping_address: cart.needs_shipping_address.unwrap_or_default(),
+                    shipping_packages: cart
+                        .available_shipping_methods
+                        .unwrap_or_default()
+                        .into_iter()
+                        .flatten()
+                        .map(|package| ShippingPackage {
+                            details: package.package_details,
+                            rates: package
+                                .rates
+                                .unwrap_or_default()
+                                .into_iter()
+                                .flatten()
+                                .map(|rate| ShippingRate {
+                                    id: rate.id,
+                                    label: rate.label,
+                                    cost: rate.cost,
+                                })
+                                .collect(),
+                        })
+                        .collect(),
+                    chosen_shipping_methods: cart
+                        .chosen_shipping_methods
+                        .unwrap_or_default()
+                        .into_iter()
+                        .flatten()
+                        .collect(),
+                    totals: CartTotals {
+                        subtotal: cart.subtotal.unwrap_or_default(),
+                        discount_total: cart.discount_total.unwrap_or_default(),
+                        fees: cart
+                            .fees
+                            .unwrap_or_default()
+                            .into_iter()
+                            .flatten()
+                            .map(|fee| Fee {
+                                id: fee.id,
+                                name: fee.name,
+                                total: fee.total,
+                            })
+                            .collect(),
+                        fee_total: cart.fee_total.unwrap_or_default(),
+                        shipping_total: cart.shipping_total.unwrap_or_default(),
+                        total_tax: cart.total_tax.unwrap_or_default(),
+                        total: cart.total.unwrap_or_default(),
+                    },
+                }
+            }
+        }
+
+        impl From<$operation::CartFieldsContentsNodes> for CartLine {
+            /// Convert a CartFieldsContentsNodes to a CartLine
+            ///
+            /// **Arguments**
+            ///
+            /// * `item` - The GraphQL cart item to convert
+            ///
+            /// **Returns**
+            ///
+            /// * `CartLine` - The converted CartLine
+            fn from(item: $operation::CartFieldsContentsNodes) -> Self {
+                let product = item.product.map(|product| product.node);
+                let variation = item.variation;
+                let variation_name: Option<String> = variation
+                    .as_ref()
+                    .and_then(|variation| variation.node.name.clone());
+
+                Self {
+                    key: item.key,
+                    product_id: product
+                        .as_ref()
+                        .map(|product| product.database_id)
+                        .unwrap_or_default(),
+                    variation_id: variation
+                        .as_ref()
+                        .map(|variation| variation.node.database_id),
+                    name: variation_name
+                        .or_else(|| product.as_ref().and_then(|product| product.name.clone()))
+                        .unwrap_or_default(),
+                    slug: product.as_ref().and_then(|product| product.slug.clone()),
+                    attributes: variation
+                        .and_then(|variation| variation.attributes)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .flatten()
+                        .map(|attribute| CartLineAttribute {
+                            label: attribute.label.unwrap_or_default(),
+                            value: attribute.value.unwrap_or_default(),
+                        })
+                        .collect(),
+                    image: product
+                        .and_then(|product| product.image)
+                        .map(|image| ProductImage {
+                            id: Some(image.id),
+                            source_url: image.source_url,
+                            alt_text: image.alt_text,
+                            title: image.title,
+                        }),
+                    quantity: item.quantity.unwrap_or_default(),
+                    subtotal: item.subtotal.unwrap_or_default(),
+                    total: item.total.unwrap_or_default(),
+                }
+            }
+        }
+    };
+}
+
+impl_from_cart_fields!(cart_query);
+impl_from_cart_fields!(add_to_cart);
+impl_from_cart_fields!(update_item_quantities);
+impl_from_cart_fields!(remove_items_from_cart);
+impl_from_cart_fields!(empty_cart);
+impl_from_cart_fields!(restore_cart_items);
+impl_from_cart_fields!(apply_coupon);
+impl_from_cart_fields!(remove_coupons);
+impl_from_cart_fields!(update_shipping_method);
+
+impl From<CheckoutQueryCartFees> for Fee {
+    /// Convert a CheckoutQueryCartFees to a Fee
+    ///
+    /// **Arguments**
+    ///
+    /// * `fee` - The GraphQL cart fee to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Fee` - The converted Fee
+    fn from(fee: CheckoutQueryCartFees) -> Self {
+        Self {
+            id: fee.id,
+            name: fee.name,
+            total: fee.total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Builds a cart with a line and a coupon.
+    fn cart() -> Cart {
+        Cart {
+            lines: vec![CartLine {
+                key: "a1b2".to_string(),
+                product_id: 11,
+                name: "Album".to_string(),
+                quantity: 2,
+                subtotal: "$30.00".to_string(),
+                total: "$27.00".to_string(),
+                ..CartLine::default()
+            }],
+            applied_coupons: vec![AppliedCoupon {
+                code: "summer".to_string(),
+                description: None,
+                discount_amount: "$3.00".to_string(),
+            }],
+            chosen_shipping_methods: vec!["flat_rate:1".to_string()],
+            totals: CartTotals {
+                subtotal: "$30.00".to_string(),
+                discount_total: "$3.00".to_string(),
+                total: "$27.00".to_string(),
+                ..CartTotals::default()
+            },
+            ..Cart::default()
+        }
+    }
+
+    #[test]
+    fn a_stored_cart_round_trips() {
+        let stored: Value = cart().to_stored();
+
+        assert_eq!(stored["version"], json!(CART_STORAGE_VERSION));
+        assert_eq!(Cart::from_stored(stored), Some(cart()));
+    }
+
+    #[test]
+    fn a_cart_of_an_unknown_version_is_discarded() {
+        let mut stored: Value = cart().to_stored();
+        stored["version"] = json!(CART_STORAGE_VERSION + 1);
+
+        assert_eq!(Cart::from_stored(stored), None);
+    }
+
+    #[test]
+    fn a_cart_stored_before_versioning_is_discarded() {
+        let legacy: Value = json!({
+            "contents": {
+                "itemCount": 2,
+                "nodes": [{ "key": "a1b2", "quantity": 2, "total": "$27.00" }],
+            },
+            "subtotal": "$30.00",
+            "total": "$27.00",
+        });
+
+        assert_eq!(Cart::from_stored(legacy), None);
+        assert_eq!(Cart::from_stored(Value::Null), None);
+    }
+
+    #[test]
+    fn a_cart_is_discounted_when_the_discount_total_is_more_than_zero() {
+        let totals = |discount_total: &str| CartTotals {
+            discount_total: discount_total.to_string(),
+            ..CartTotals::default()
+        };
+
+        assert!(totals("$3.00").has_discount());
+        assert!(totals("&#36;0.50").has_discount());
+        assert!(totals("-€10,00").has_discount());
+        assert!(!totals("$0.00").has_discount());
+        assert!(!totals("&#36;0.00").has_discount());
+        assert!(!totals("").has_discount());
+    }
+}
@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+
+// Modules
+use crate::{
+    graphql::models::checkout::checkout_query::{
+        CheckoutQueryCart, CheckoutQueryCartAvailableShippingMethods,
+        CheckoutQueryCartAvailableShippingMethodsRates, CheckoutQueryPaymentGatewaysNodes,
+        ResponseData,
+    },
+    models::{
+        address::Address,
+        cart::{CartTotals, Fee},
+    },
+    payments::PaymentData,
+};
+
+/// The choices a shopper makes at checkout, with the cart totals they lead to
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct CheckoutOptions {
+    /// Whether the cart has items that are shipped
+    pub needs_shipping_address: bool,
+    /// The shipping packages, each with the rates available for it
+    pub shipping_packages: Vec<ShippingPackage>,
+    /// The chosen rate ID of each shipping package
+    pub chosen_shipping_methods: Vec<String>,
+    /// The payment gateways enabled on the store
+    pub payment_gateways: Vec<PaymentGateway>,
+    /// The totals of the cart
+    pub totals: CartTotals,
+}
+
+/// A shipping package, with the rates available for it
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct ShippingPackage {
+    /// The items in the package, as described by WooCommerce
+    pub details: Option<String>,
+    /// The available rates
+    pub rates: Vec<ShippingRate>,
+}
+
+/// A shipping rate
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct ShippingRate {
+    /// Rate ID, e.g. `flat_rate:1`
+    pub id: String,
+    /// Rate label
+    pub label: Option<String>,
+    /// Rate cost
+    pub cost: Option<String>,
+}
+
+/// A payment gateway
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct PaymentGateway {
+    /// Gateway ID, e.g. `bacs`
+    pub id: String,
+    /// Gateway title
+    pub title: Option<String>,
+    /// Gateway description, which may contain HTML
+    pub description: Option<String>,
+}
+
+/// The details a shopper checks out with
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct CheckoutDetails {
+    /// Billing address
+    pub billing: Address,
+    /// Shipping address, if it differs from the billing address
+    pub shipping: Option<Address>,
+    /// The chosen rate ID of each shipping package
+    pub shipping_methods: Vec<String>,
+    /// The payment, as returned by the chosen gateway's payment provider
+    pub payment: PaymentData,
+    /// Order notes
+    pub customer_note: String,
+}
+
+impl From<ResponseData> for CheckoutOptions {
+    /// Convert a CheckoutQuery response to CheckoutOptions
+    ///
+    /// **Arguments**
+    ///
+    /// * `response` - The GraphQL response to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `CheckoutOptions` - The converted CheckoutOptions
+    fn from(response: ResponseData) -> Self {
+        let payment_gateways: Vec<PaymentGateway> = response
+            .payment_gateways
+            .map(|gateways| gateways.nodes)
+            .unwrap_or_default()
+            .into_iter()
+            .map(PaymentGateway::from)
+            .collect();
+        let Some(cart) = response.cart else {
+            return Self {
+                payment_gateways,
+                ..Self::default()
+            };
+        };
+        let CheckoutQueryCart {
+            needs_shipping_address,
+            available_shipping_methods,
+            chosen_shipping_methods,
+            fees,
+            fee_total,
+            subtotal,
+            discount_total,
+            shipping_total,
+            total_tax,
+            total,
+        } = cart;
+
+        Self {
+            needs_shipping_address: needs_shipping_address.unwrap_or_default(),
+            shipping_packages: available_shipping_methods
+                .unwrap_or_default()
+                .into_iter()
+                .flatten()
+                .map(ShippingPackage::from)
+                .collect(),
+            chosen_shipping_methods: chosen_shipping_methods
+                .unwrap_or_default()
+                .into_iter()
+                .flatten()
+                .collect(),
+            payment_gateways,
+            totals: CartTotals {
+                subtotal: subtotal.unwrap_or_default(),
+                discount_total: discount_total.unwrap_or_default(),
+                fees: fees
+                    .unwrap_or_default()
+                    .into_iter()
+                    .flatten()
+                    .map(Fee::from)
+                    .collect(),
+                fee_total: fee_total.unwrap_or_default(),
+                shipping_total: shipping_total.unwrap_or_default(),
+                total_tax: total_tax.unwrap_or_default(),
+                total: total.unwrap_or_default(),
+            },
+        }
+    }
+}
+
+impl From<CheckoutQueryCartAvailableShippingMethods> for ShippingPackage {
+    /// Convert a CheckoutQueryCartAvailableShippingMethods to a ShippingPackage
+    ///
+    /// **Arguments**
+    ///
+    /// * `package` - The GraphQL shipping package to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `ShippingPackage` - The converted ShippingPackage
+    fn from(package: CheckoutQueryCartAvailableShippingMethods) -> Self {
+        Self {
+            details: package.package_details,
+            rates: package
+                .rates
+                .unwrap_or_default()
+                .into_iter()
+                .flatten()
+                .map(ShippingRate::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<CheckoutQueryCartAvailableShippingMethodsRates> for ShippingRate {
+    /// Convert a CheckoutQueryCartAvailableShippingMethodsRates to a ShippingRate
+    ///
+    /// **Arguments**
+    ///
+    /// * `rate` - The GraphQL shipping rate to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `ShippingRate` - The converted ShippingRate
+    fn from(rate: CheckoutQueryCartAvailableShippingMethodsRates) -> Self {
+        Self {
+            id: rate.id,
+            label: rate.label,
+            cost: rate.cost,
+        }
+    }
+}
+
+impl From<CheckoutQueryPaymentGatewaysNodes> for PaymentGateway {
+    /// Convert a CheckoutQueryPaymentGatewaysNodes to a PaymentGateway
+    ///
+    /// **Arguments**
+    ///
+    /// * `gateway` - The GraphQL payment gateway to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `PaymentGateway` - The converted PaymentGateway
+    fn from(gateway: CheckoutQueryPaymentGatewaysNodes) -> Self {
+        Self {
+            id: gateway.id,
+            title: gateway.title,
+            description: gateway.description,
+        }
+    }
+}
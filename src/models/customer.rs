@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display};
+
+// Modules
+use crate::{
+    graphql::models::customer::{
+        customer_query, login::LoginLoginCustomer,
+        register_customer::RegisterCustomerRegisterCustomerCustomer, update_customer,
+    },
+    models::address::Address,
+};
+
+/// Customer entity representing a signed-in WooCommerce customer
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct Customer {
+    /// Customer ID
+    pub id: String,
+    /// Customer database ID
+    pub database_id: Option<i64>,
+    /// Customer email address
+    pub email: Option<String>,
+    /// Customer first name
+    pub first_name: Option<String>,
+    /// Customer last name
+    pub last_name: Option<String>,
+    /// Customer display name
+    pub display_name: Option<String>,
+    /// Customer username
+    pub username: Option<String>,
+    /// Billing address, if it was fetched
+    pub billing: Option<Address>,
+    /// Shipping address, if it was fetched
+    pub shipping: Option<Address>,
+}
+
+/// The details a shopper registers with
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct Registration {
+    /// Email address, also used to sign in
+    pub email: String,
+    /// Password
+    pub password: String,
+    /// First name
+    pub first_name: String,
+    /// Last name
+    pub last_name: String,
+    /// Billing address, if the shopper provided one
+    pub billing: Option<Address>,
+}
+
+/// The account details a customer can change
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct AccountDetails {
+    /// First name
+    pub first_name: String,
+    /// Last name
+    pub last_name: String,
+    /// The name shown on reviews and comments
+    pub display_name: String,
+    /// Email address
+    pub email: String,
+    /// New password, left blank to keep the current one
+    pub password: String,
+}
+
+/// The query of a password reset link, as emailed by WordPress
+///
+/// The page it links to is configured on the backend, with the
+/// `retrieve_password_message` filter.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct PasswordResetLink {
+    /// The reset key
+    pub key: String,
+    /// The username of the account
+    pub login: String,
+}
+
+impl From<&str> for PasswordResetLink {
+    /// Parse a PasswordResetLink from a query string
+    ///
+    /// The router decodes the query before it is parsed, so values are used as is.
+    ///
+    /// **Arguments**
+    ///
+    /// * `query` - The decoded query string, without the leading `?`
+    ///
+    /// **Returns**
+    ///
+    /// * `PasswordResetLink` - The link, with any missing values left empty
+    fn from(query: &str) -> Self {
+        let mut link: Self = Self::default();
+        for (name, value) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+            match name {
+                "key" => link.key = value.to_string(),
+                "login" => link.login = value.to_string(),
+                _ => {}
+            }
+        }
+
+        link
+    }
+}
+
+impl Display for PasswordResetLink {
+    /// Formats the link as a query string, which the router encodes
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "key={}&login={}", self.key, self.login)
+    }
+}
+
+impl Customer {
+    /// Gets the name to greet the customer by.
+    ///
+    /// **Returns**
+    ///
+    /// * `String` - The first name, display name, username or email, whichever is set first.
+    pub fn name(&self) -> String {
+        [
+            &self.first_name,
+            &self.display_name,
+            &self.username,
+            &self.email,
+        ]
+        .into_iter()
+        .flatten()
+        .find(|name| !name.is_empty())
+        .cloned()
+        .unwrap_or_default()
+    }
+}
+
+impl From<customer_query::CustomerFields> for Customer {
+    /// Convert a CustomerFields fragment to a Customer
+    ///
+    /// **Arguments**
+    ///
+    /// * `customer` - The GraphQL customer to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Customer` - The converted Customer
+    fn from(customer: customer_query::CustomerFields) -> Self {
+        Self {
+            id: customer.id,
+            database_id: customer.database_id,
+            email: customer.email,
+            first_name: customer.first_name,
+            last_name: customer.last_name,
+            display_name: customer.display_name,
+            username: customer.username,
+            billing: customer.billing.map(Address::from),
+            shipping: customer.shipping.map(Address::from),
+        }
+    }
+}
+
+impl From<update_customer::CustomerFields> for Customer {
+    /// Convert a CustomerFields fragment to a Customer
+    ///
+    /// **Arguments**
+    ///
+    /// * `customer` - The GraphQL customer to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Customer` - The converted Customer
+    fn from(customer: update_customer::CustomerFields) -> Self {
+        Self {
+            id: customer.id,
+            database_id: customer.database_id,
+            email: customer.email,
+            first_name: customer.first_name,
+            last_name: customer.last_name,
+            display_name: customer.display_name,
+            username: customer.username,
+            billing: customer.billing.map(Address::from),
+            shipping: customer.shipping.map(Address::from),
+        }
+    }
+}
+
+impl From<LoginLoginCustomer> for Customer {
+    /// Convert a LoginLoginCustomer to a Customer
+    ///
+    /// **Arguments**
+    ///
+    /// * `customer` - The GraphQL customer to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Customer` - The converted Customer
+    fn from(customer: LoginLoginCustomer) -> Self {
+        Self {
+            id: customer.id,
+            database_id: customer.database_id,
+            email: customer.email,
+            first_name: customer.first_name,
+            last_name: customer.last_name,
+            display_name: customer.display_name,
+            username: customer.username,
+            billing: None,
+            shipping: None,
+        }
+    }
+}
+
+impl From<RegisterCustomerRegisterCustomerCustomer> for Customer {
+    /// Convert a RegisterCustomerRegisterCustomerCustomer to a Customer
+    ///
+    /// **Arguments**
+    ///
+    /// * `customer` - The GraphQL customer to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Customer` - The converted Customer
+    fn from(customer: RegisterCustomerRegisterCustomerCustomer) -> Self {
+        Self {
+            id: customer.id,
+            database_id: customer.database_id,
+            email: customer.email,
+            first_name: customer.first_name,
+            last_name: customer.last_name,
+            display_name: customer.display_name,
+            username: customer.username,
+            billing: None,
+            shipping: None,
+        }
+    }
+}
@@ -2,8 +2,14 @@
 ///
 /// This module defines the core business entities of the application,
 /// such as `Post` and `Product`.
+pub mod address;
+pub mod cart;
 pub mod category;
+pub mod checkout;
+pub mod customer;
+pub mod order;
 pub mod page;
 pub mod pagination;
 pub mod post;
 pub mod product;
+pub mod shipping;
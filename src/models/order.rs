@@ -0,0 +1,287 @@
+use serde::{Deserialize, Serialize};
+
+// Modules
+use crate::{
+    graphql::models::{
+        checkout::checkout,
+        enum_value,
+        order::{
+            customer_orders_query::{
+                CustomerOrdersQueryCustomerOrders, CustomerOrdersQueryCustomerOrdersNodes,
+                CustomerOrdersQueryCustomerOrdersPageInfo,
+            },
+            order_query,
+        },
+    },
+    models::{address::Address, pagination::Pagination},
+};
+
+/// Order entity representing a customer's WooCommerce order
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct Order {
+    /// Order ID
+    pub id: String,
+    /// Order database ID
+    pub database_id: Option<i64>,
+    /// Order number, as shown to the customer
+    pub order_number: Option<String>,
+    /// Date the order was placed
+    pub date: Option<String>,
+    /// Order status, e.g. `PROCESSING`
+    pub status: Option<String>,
+    /// Order subtotal
+    pub subtotal: Option<String>,
+    /// Shipping total
+    pub shipping_total: Option<String>,
+    /// Tax total
+    pub total_tax: Option<String>,
+    /// Order total
+    pub total: Option<String>,
+    /// Title of the payment method
+    pub payment_method_title: Option<String>,
+    /// Note left by the customer at checkout
+    pub customer_note: Option<String>,
+    /// Billing address
+    pub billing: Option<Address>,
+    /// Shipping address
+    pub shipping: Option<Address>,
+    /// The ordered items, which are only fetched for a single order
+    pub line_items: Vec<OrderLineItem>,
+}
+
+/// An item of an order
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct OrderLineItem {
+    /// Line item database ID
+    pub database_id: Option<i64>,
+    /// Product name
+    pub name: Option<String>,
+    /// Product slug, if the product still exists
+    pub slug: Option<String>,
+    /// Quantity ordered
+    pub quantity: Option<i64>,
+    /// Line total
+    pub total: Option<String>,
+}
+
+impl Order {
+    /// Gets the order number to show the customer.
+    ///
+    /// **Returns**
+    ///
+    /// * `String` - The order number, or the database ID if it has none.
+    pub fn number(&self) -> String {
+        self.order_number
+            .clone()
+            .or_else(|| self.database_id.map(|id| id.to_string()))
+            .unwrap_or_default()
+    }
+
+    /// Gets the order status as a label.
+    ///
+    /// **Returns**
+    ///
+    /// * `String` - The status in sentence case, e.g. `On hold` for `ON_HOLD`.
+    pub fn status_label(&self) -> String {
+        let status: String = self
+            .status
+            .as_deref()
+            .unwrap_or_default()
+            .replace('_', " ")
+            .to_lowercase();
+        let mut chars = status.chars();
+
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl From<order_query::OrderFields> for Order {
+    /// Convert an OrderFields fragment to an Order
+    ///
+    /// **Arguments**
+    ///
+    /// * `order` - The GraphQL order to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Order` - The converted Order
+    fn from(order: order_query::OrderFields) -> Self {
+        let line_items: Vec<OrderLineItem> = order
+            .line_items
+            .map(|line_items| line_items.nodes)
+            .unwrap_or_default()
+            .into_iter()
+            .map(OrderLineItem::from)
+            .collect();
+
+        Self {
+            id: order.id,
+            database_id: order.database_id,
+            order_number: order.order_number,
+            date: order.date,
+            status: order.status.map(|status| enum_value(&status)),
+            subtotal: order.subtotal,
+            shipping_total: order.shipping_total,
+            total_tax: order.total_tax,
+            total: order.total,
+            payment_method_title: order.payment_method_title,
+            customer_note: order.customer_note,
+            billing: order.billing.map(Address::from),
+            shipping: order.shipping.map(Address::from),
+            line_items,
+        }
+    }
+}
+
+impl From<order_query::OrderFieldsLineItemsNodes> for OrderLineItem {
+    /// Convert an OrderFieldsLineItemsNodes to an OrderLineItem
+    ///
+    /// **Arguments**
+    ///
+    /// * `line_item` - The GraphQL line item to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `OrderLineItem` - The converted OrderLineItem
+    fn from(line_item: order_query::OrderFieldsLineItemsNodes) -> Self {
+        let product = line_item.product.map(|product| product.node);
+
+        Self {
+            database_id: line_item.database_id,
+            name: product.as_ref().and_then(|product| product.name.clone()),
+            slug: product.and_then(|product| product.slug),
+            quantity: line_item.quantity,
+            total: line_item.total,
+        }
+    }
+}
+
+impl From<checkout::OrderFields> for Order {
+    /// Convert an OrderFields fragment to an Order
+    ///
+    /// **Arguments**
+    ///
+    /// * `order` - The GraphQL order to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Order` - The converted Order
+    fn from(order: checkout::OrderFields) -> Self {
+        let line_items: Vec<OrderLineItem> = order
+            .line_items
+            .map(|line_items| line_items.nodes)
+            .unwrap_or_default()
+            .into_iter()
+            .map(OrderLineItem::from)
+            .collect();
+
+        Self {
+            id: order.id,
+            database_id: order.database_id,
+            order_number: order.order_number,
+            date: order.date,
+            status: order.status.map(|status| enum_value(&status)),
+            subtotal: order.subtotal,
+            shipping_total: order.shipping_total,
+            total_tax: order.total_tax,
+            total: order.total,
+            payment_method_title: order.payment_method_title,
+            customer_note: order.customer_note,
+            billing: order.billing.map(Address::from),
+            shipping: order.shipping.map(Address::from),
+            line_items,
+        }
+    }
+}
+
+impl From<checkout::OrderFieldsLineItemsNodes> for OrderLineItem {
+    /// Convert an OrderFieldsLineItemsNodes to an OrderLineItem
+    ///
+    /// **Arguments**
+    ///
+    /// * `line_item` - The GraphQL line item to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `OrderLineItem` - The converted OrderLineItem
+    fn from(line_item: checkout::OrderFieldsLineItemsNodes) -> Self {
+        let product = line_item.product.map(|product| product.node);
+
+        Self {
+            database_id: line_item.database_id,
+            name: product.as_ref().and_then(|product| product.name.clone()),
+            slug: product.and_then(|product| product.slug),
+            quantity: line_item.quantity,
+            total: line_item.total,
+        }
+    }
+}
+
+impl From<CustomerOrdersQueryCustomerOrdersNodes> for Order {
+    /// Convert a CustomerOrdersQueryCustomerOrdersNodes to an Order
+    ///
+    /// **Arguments**
+    ///
+    /// * `order` - The GraphQL order to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Order` - The converted Order, without its totals breakdown, addresses or items
+    fn from(order: CustomerOrdersQueryCustomerOrdersNodes) -> Self {
+        Self {
+            id: order.id,
+            database_id: order.database_id,
+            order_number: order.order_number,
+            date: order.date,
+            status: order.status.map(|status| enum_value(&status)),
+            total: order.total,
+            ..Self::default()
+        }
+    }
+}
+
+/// Collection of orders with pagination information
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
+pub struct Orders {
+    pub orders: Vec<Order>,
+    pub page_info: Option<Pagination>,
+}
+
+impl From<CustomerOrdersQueryCustomerOrders> for Orders {
+    /// Convert a CustomerOrdersQueryCustomerOrders to Orders
+    ///
+    /// **Arguments**
+    ///
+    /// * `orders` - The GraphQL orders to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Orders` - The converted Orders
+    fn from(orders: CustomerOrdersQueryCustomerOrders) -> Self {
+        let page_info: Option<Pagination> = Some(Pagination::from(orders.page_info));
+        let orders: Vec<Order> = orders.nodes.into_iter().map(Order::from).collect();
+
+        Self { orders, page_info }
+    }
+}
+
+impl From<CustomerOrdersQueryCustomerOrdersPageInfo> for Pagination {
+    /// Convert a CustomerOrdersQueryCustomerOrdersPageInfo to a Pagination
+    ///
+    /// **Arguments**
+    ///
+    /// * `page_info` - The GraphQL page info to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `Pagination` - The converted Pagination
+    fn from(page_info: CustomerOrdersQueryCustomerOrdersPageInfo) -> Self {
+        Self {
+            end_cursor: page_info.end_cursor,
+            has_next_page: page_info.has_next_page,
+        }
+    }
+}
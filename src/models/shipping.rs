@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+// Modules
+use crate::graphql::models::shipping::country_states_query::CountryStatesQueryCountryStates;
+
+/// A state, province or county of a country
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct CountryState {
+    /// State code, e.g. `NSW`
+    pub code: String,
+    /// State name
+    pub name: String,
+}
+
+impl From<CountryStatesQueryCountryStates> for CountryState {
+    /// Convert a CountryStatesQueryCountryStates to a CountryState
+    ///
+    /// **Arguments**
+    ///
+    /// * `state` - The GraphQL country state to convert
+    ///
+    /// **Returns**
+    ///
+    /// * `CountryState` - The converted CountryState
+    fn from(state: CountryStatesQueryCountryStates) -> Self {
+        Self {
+            code: state.code,
+            name: state.name,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+
+// Modules
+use crate::{
+    app::error::AppError,
+    models::checkout::PaymentGateway,
+    payments::{PaymentData, PaymentFields, PaymentProvider},
+};
+
+/// # Bank Transfer
+///
+/// WooCommerce's direct bank transfer gateway. The store's bank details are in the
+/// gateway description, and the order is paid for once the transfer clears.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BankTransfer;
+
+#[async_trait(?Send)]
+impl PaymentProvider for BankTransfer {
+    fn gateway_id(&self) -> &str {
+        "bacs"
+    }
+
+    async fn tokenize(
+        &self,
+        gateway: &PaymentGateway,
+        _fields: &PaymentFields,
+    ) -> Result<PaymentData, AppError> {
+        Ok(PaymentData::unpaid(gateway))
+    }
+}
@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+
+// Modules
+use crate::{
+    app::error::AppError,
+    models::checkout::PaymentGateway,
+    payments::{PaymentData, PaymentFields, PaymentProvider},
+};
+
+/// # Cash On Delivery
+///
+/// WooCommerce's cash on delivery gateway. The order is paid for on delivery.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CashOnDelivery;
+
+#[async_trait(?Send)]
+impl PaymentProvider for CashOnDelivery {
+    fn gateway_id(&self) -> &str {
+        "cod"
+    }
+
+    async fn tokenize(
+        &self,
+        gateway: &PaymentGateway,
+        _fields: &PaymentFields,
+    ) -> Result<PaymentData, AppError> {
+        Ok(PaymentData::unpaid(gateway))
+    }
+}
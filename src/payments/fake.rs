@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use dioxus::prelude::*;
+
+// Modules
+use crate::{
+    app::error::{AppError, AppErrorKind},
+    models::checkout::PaymentGateway,
+    payments::{render_description, PaymentData, PaymentFields, PaymentProvider},
+};
+
+/// The card number that is always approved
+pub const APPROVED_CARD: &str = "4242424242424242";
+
+/// The card number that is always declined
+pub const DECLINED_CARD: &str = "4000000000000002";
+
+/// # Fake Payment Provider
+///
+/// An offline card provider for testing checkout. It takes no payments and never
+/// calls out, and its outcome depends only on the card number: `APPROVED_CARD` is
+/// approved, `DECLINED_CARD` is declined, and any other number is invalid. An
+/// approved card only adds its last four digits to the order's meta data, so the
+/// order is left for the backend to mark as paid.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FakePaymentProvider;
+
+#[async_trait(?Send)]
+impl PaymentProvider for FakePaymentProvider {
+    fn gateway_id(&self) -> &str {
+        "wept_fake"
+    }
+
+    fn render(&self, gateway: &PaymentGateway, fields: Signal<PaymentFields>) -> Element {
+        let mut fields = fields;
+        let card_number: String = fields
+            .read()
+            .get("card_number")
+            .cloned()
+            .unwrap_or_default();
+
+        rsx! {
+            div {
+                class: "space-y-2",
+                {render_description(gateway)}
+                label {
+                    class: "block mb-2 font-medium",
+                    r#for: "card_number",
+                    "Card number"
+                }
+                input {
+                    id: "card_number",
+                    r#type: "text",
+                    class: "block w-full p-3 border border-gray-300 rounded-lg",
+                    inputmode: "numeric",
+                    autocomplete: "cc-number",
+                    value: "{card_number}",
+                    oninput: move |event| {
+                        fields.write().insert("card_number".to_string(), event.value());
+                    },
+                }
+            }
+        }
+    }
+
+    async fn tokenize(
+        &self,
+        gateway: &PaymentGateway,
+        fields: &PaymentFields,
+    ) -> Result<PaymentData, AppError> {
+        let card_number: String = fields
+            .get("card_number")
+            .map(|number| number.chars().filter(char::is_ascii_digit).collect())
+            .unwrap_or_default();
+
+        match card_number.as_str() {
+            APPROVED_CARD => {
+                let last4: &str = &card_number[card_number.len() - 4..];
+                Ok(PaymentData {
+                    payment_method: gateway.id.clone(),
+                    meta_data: vec![("_wept_card_last4".to_string(), last4.to_string())],
+                })
+            }
+            DECLINED_CARD => Err(AppError::new(
+                AppErrorKind::Payment,
+                "Your card was declined.",
+                Some("The fake payment provider declined the card"),
+                None,
+            )),
+            _ => Err(AppError::new(
+                AppErrorKind::Payment,
+                "Please enter a valid card number.",
+                Some("The fake payment provider rejected the card number"),
+                None,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    /// Tokenizes a card number with the fake provider.
+    fn tokenize(card_number: &str) -> Result<PaymentData, AppError> {
+        let gateway: PaymentGateway = PaymentGateway {
+            id: "wept_fake".to_string(),
+            ..PaymentGateway::default()
+        };
+        let fields: PaymentFields =
+            PaymentFields::from([("card_number".to_string(), card_number.to_string())]);
+        block_on(FakePaymentProvider.tokenize(&gateway, &fields))
+    }
+
+    #[test]
+    fn the_approved_card_is_approved_without_being_marked_paid() {
+        let expected: PaymentData = PaymentData {
+            payment_method: "wept_fake".to_string(),
+            meta_data: vec![("_wept_card_last4".to_string(), "4242".to_string())],
+        };
+
+        assert_eq!(tokenize(APPROVED_CARD).unwrap(), expected);
+        assert_eq!(tokenize("4242 4242 4242 4242").unwrap(), expected);
+    }
+
+    #[test]
+    fn the_declined_card_is_declined() {
+        let error: AppError = tokenize(DECLINED_CARD).unwrap_err();
+
+        assert_eq!(error.kind, AppErrorKind::Payment);
+        assert_eq!(error.public_message, "Your card was declined.");
+    }
+
+    #[test]
+    fn other_card_numbers_are_invalid() {
+        for card_number in ["", "4242", "4111111111111111"] {
+            let error: AppError = tokenize(card_number).unwrap_err();
+
+            assert_eq!(error.kind, AppErrorKind::Payment);
+            assert_eq!(error.public_message, "Please enter a valid card number.");
+        }
+    }
+}
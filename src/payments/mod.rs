@@ -0,0 +1,191 @@
+pub mod bacs;
+pub mod cod;
+pub mod fake;
+
+use async_trait::async_trait;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt::Debug, rc::Rc};
+
+// Modules
+use crate::{
+    app::{error::AppError, html},
+    models::checkout::PaymentGateway,
+    payments::{bacs::BankTransfer, cod::CashOnDelivery, fake::FakePaymentProvider},
+};
+
+/// The values entered in a payment form, by field name
+pub type PaymentFields = HashMap<String, String>;
+
+/// # Payment Provider
+///
+/// Takes the payment for an order placed with one of the store's payment gateways.
+///
+/// The checkout page renders the provider's payment form, asks the provider to
+/// tokenize what was entered, then places the order with the returned payment data.
+/// Stores plug their own providers in by providing `PaymentProviders` as context.
+#[async_trait(?Send)]
+pub trait PaymentProvider: Debug {
+    /// Gets the ID of the payment gateway the provider takes payments for.
+    ///
+    /// **Returns**
+    ///
+    /// * `&str` - The gateway ID, e.g. `cod`.
+    fn gateway_id(&self) -> &str;
+
+    /// Renders the payment form, shown while the gateway is chosen.
+    ///
+    /// By default the gateway's description is shown, for gateways that take no
+    /// details at checkout.
+    ///
+    /// **Arguments**
+    ///
+    /// * `gateway` - The payment gateway.
+    /// * `fields` - The values entered in the form, for the form to bind to.
+    ///
+    /// **Returns**
+    ///
+    /// * `Element` - The rendered form.
+    fn render(&self, gateway: &PaymentGateway, fields: Signal<PaymentFields>) -> Element {
+        let _ = fields;
+        render_description(gateway)
+    }
+
+    /// Turns the entered payment details into the payment data to check out with.
+    ///
+    /// **Arguments**
+    ///
+    /// * `gateway` - The payment gateway.
+    /// * `fields` - The values entered in the payment form.
+    ///
+    /// **Returns**
+    ///
+    /// * `Result<PaymentData, AppError>` - The payment data, or why the payment was declined.
+    async fn tokenize(
+        &self,
+        gateway: &PaymentGateway,
+        fields: &PaymentFields,
+    ) -> Result<PaymentData, AppError>;
+}
+
+/// Renders the description of a payment gateway.
+///
+/// The description may contain HTML, which is rendered as text rather than
+/// trusted as markup.
+///
+/// **Arguments**
+///
+/// * `gateway` - The payment gateway.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered description.
+pub fn render_description(gateway: &PaymentGateway) -> Element {
+    let description: Option<String> = gateway.description.as_deref().map(html::to_text);
+
+    rsx! {
+        if let Some(description) = description {
+            p {
+                class: "text-gray-600 whitespace-pre-line",
+                "{description}"
+            }
+        }
+    }
+}
+
+/// # Payment Data
+///
+/// The payment details an order is placed with.
+///
+/// Whether the order is paid for is up to the gateway on the backend, which
+/// confirms the payment with its provider, and never up to the client.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default)]
+pub struct PaymentData {
+    /// The ID of the payment gateway
+    pub payment_method: String,
+    /// Meta data to store on the order, as key and value pairs
+    pub meta_data: Vec<(String, String)>,
+}
+
+impl PaymentData {
+    /// Creates the payment data of an order paid for after checkout.
+    ///
+    /// **Arguments**
+    ///
+    /// * `gateway` - The payment gateway.
+    ///
+    /// **Returns**
+    ///
+    /// * `PaymentData` - The payment data.
+    pub fn unpaid(gateway: &PaymentGateway) -> Self {
+        Self {
+            payment_method: gateway.id.clone(),
+            ..Self::default()
+        }
+    }
+}
+
+/// # Payment Providers
+///
+/// The payment providers of the store, by gateway ID.
+///
+/// The default providers cover cash on delivery and bank transfers, plus the fake
+/// provider when serving fixtures. Stores add their own with `with`:
+///
+/// ```ignore
+/// use_context_provider(|| PaymentProviders::default().with(MyProvider::new()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PaymentProviders {
+    providers: Vec<Rc<dyn PaymentProvider>>,
+}
+
+impl Default for PaymentProviders {
+    fn default() -> Self {
+        let providers: Self = Self {
+            providers: Vec::new(),
+        }
+        .with(CashOnDelivery)
+        .with(BankTransfer);
+
+        if cfg!(feature = "fixtures") {
+            providers.with(FakePaymentProvider)
+        } else {
+            providers
+        }
+    }
+}
+
+impl PaymentProviders {
+    /// Adds a provider, replacing any provider of the same gateway.
+    ///
+    /// **Arguments**
+    ///
+    /// * `provider` - The provider to add.
+    ///
+    /// **Returns**
+    ///
+    /// * `PaymentProviders` - The providers, with the provider added.
+    pub fn with(mut self, provider: impl PaymentProvider + 'static) -> Self {
+        self.providers
+            .retain(|existing| existing.gateway_id() != provider.gateway_id());
+        self.providers.push(Rc::new(provider));
+        self
+    }
+
+    /// Gets the provider of a payment gateway.
+    ///
+    /// **Arguments**
+    ///
+    /// * `gateway_id` - The gateway ID.
+    ///
+    /// **Returns**
+    ///
+    /// * `Option<Rc<dyn PaymentProvider>>` - The provider, if there is one.
+    pub fn get(&self, gateway_id: &str) -> Option<Rc<dyn PaymentProvider>> {
+        self.providers
+            .iter()
+            .find(|provider| provider.gateway_id() == gateway_id)
+            .cloned()
+    }
+}
@@ -1,12 +1,33 @@
 use dioxus::prelude::*;
 
 // Modules
-use crate::views::{
-    layouts::MainLayout,
-    pages::{
-        cart::CartPage, categories::CategoriesPage, category::CategoryPage, errors::NotFoundPage,
-        home::HomePage, list::PagesListPage, page::PagePage, post::PostPage, posts::PostsPage,
-        product::ProductPage, search::SearchPage,
+use crate::{
+    models::customer::PasswordResetLink,
+    views::{
+        layouts::{AccountLayout, MainLayout},
+        pages::{
+            account::{
+                addresses::AccountAddressesPage, dashboard::AccountPage,
+                details::AccountDetailsPage, order::AccountOrderPage, orders::AccountOrdersPage,
+            },
+            cart::CartPage,
+            categories::CategoriesPage,
+            category::CategoryPage,
+            checkout::CheckoutPage,
+            errors::NotFoundPage,
+            forgot_password::ForgotPasswordPage,
+            home::HomePage,
+            list::PagesListPage,
+            login::LoginPage,
+            order_received::OrderReceivedPage,
+            page::PagePage,
+            post::PostPage,
+            posts::PostsPage,
+            product::ProductPage,
+            register::RegisterPage,
+            reset_password::ResetPasswordPage,
+            search::SearchPage,
+        },
     },
 };
 
@@ -25,6 +46,54 @@ pub enum Routes {
     #[route("/cart")]
     CartPage {},
 
+    /// Route for the Checkout page.
+    #[route("/checkout")]
+    CheckoutPage {},
+
+    /// Route for the Order Received page, shown once an order is placed.
+    #[route("/checkout/order-received/:order_id")]
+    OrderReceivedPage { order_id: String },
+
+    /// Route for the Login page.
+    #[route("/login")]
+    LoginPage {},
+
+    /// Route for the Registration page.
+    #[route("/register")]
+    RegisterPage {},
+
+    /// Route for the Forgot Password page.
+    #[route("/forgot-password")]
+    ForgotPasswordPage {},
+
+    /// Route for the Reset Password page, linked from the password reset email.
+    #[route("/reset-password?:..link")]
+    ResetPasswordPage { link: PasswordResetLink },
+
+    /// Account area, for signed-in customers only
+    #[nest("/account")]
+        #[layout(AccountLayout)]
+            /// Route for the Account dashboard page.
+            #[route("/")]
+            AccountPage {},
+
+            /// Route for the Account orders page.
+            #[route("/orders")]
+            AccountOrdersPage {},
+
+            /// Route for the Account order page.
+            #[route("/orders/:order_id")]
+            AccountOrderPage { order_id: String },
+
+            /// Route for the Account addresses page.
+            #[route("/addresses")]
+            AccountAddressesPage {},
+
+            /// Route for the Account details page.
+            #[route("/details")]
+            AccountDetailsPage {},
+        #[end_layout]
+    #[end_nest]
     /// Route for the Product page.
     #[route("/product/:product_slug")]
     ProductPage { product_slug: String },
@@ -0,0 +1,47 @@
+use dioxus::prelude::*;
+
+// Modules
+use crate::models::address::Address;
+
+/// # Address Card Component
+///
+/// A component that displays a billing or shipping address.
+///
+/// **Arguments**
+///
+/// * `title` - The heading of the card, e.g. "Billing address".
+/// * `address` - The address to display, if there is one.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered component.
+#[component]
+pub fn AddressCard(title: String, address: Option<Address>) -> Element {
+    let lines: Vec<String> = address.as_ref().map(Address::lines).unwrap_or_default();
+
+    rsx! {
+        div {
+            class: "bg-gray-100 p-6 rounded-lg",
+            h3 {
+                class: "text-xl font-bold mb-2",
+                "{title}"
+            }
+            if lines.is_empty() {
+                p { class: "text-gray-500", "No address saved." }
+            } else {
+                div {
+                    class: "space-y-1",
+                    for line in lines {
+                        p { "{line}" }
+                    }
+                    if let Some(address) = address.as_ref().filter(|address| !address.email.is_empty()) {
+                        p { "{address.email}" }
+                    }
+                    if let Some(address) = address.as_ref().filter(|address| !address.phone.is_empty()) {
+                        p { "{address.phone}" }
+                    }
+                }
+            }
+        }
+    }
+}
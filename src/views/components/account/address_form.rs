@@ -0,0 +1,185 @@
+use dioxus::prelude::*;
+
+// Modules
+use crate::{
+    app::error::FieldErrors, models::address::Address, views::components::common::form::FormField,
+};
+
+/// The signals of an address form, one per field
+#[derive(Clone, Copy, PartialEq)]
+pub struct AddressSignals {
+    first_name: Signal<String>,
+    last_name: Signal<String>,
+    company: Signal<String>,
+    address_1: Signal<String>,
+    address_2: Signal<String>,
+    city: Signal<String>,
+    state: Signal<String>,
+    postcode: Signal<String>,
+    country: Signal<String>,
+    email: Signal<String>,
+    phone: Signal<String>,
+}
+
+impl AddressSignals {
+    /// Creates the signals of an address form, as a hook.
+    ///
+    /// **Arguments**
+    ///
+    /// * `address` - The address to fill the form with.
+    ///
+    /// **Returns**
+    ///
+    /// * `AddressSignals` - The signals, which keep their values across renders.
+    pub fn use_address(address: Address) -> Self {
+        Self {
+            first_name: use_signal(|| address.first_name.clone()),
+            last_name: use_signal(|| address.last_name.clone()),
+            company: use_signal(|| address.company.clone()),
+            address_1: use_signal(|| address.address_1.clone()),
+            address_2: use_signal(|| address.address_2.clone()),
+            city: use_signal(|| address.city.clone()),
+            state: use_signal(|| address.state.clone()),
+            postcode: use_signal(|| address.postcode.clone()),
+            country: use_signal(|| address.country.clone()),
+            email: use_signal(|| address.email.clone()),
+            phone: use_signal(|| address.phone.clone()),
+        }
+    }
+
+    /// Gets the address entered in the form.
+    ///
+    /// **Returns**
+    ///
+    /// * `Address` - The entered address.
+    pub fn address(&self) -> Address {
+        Address {
+            first_name: self.first_name.read().clone(),
+            last_name: self.last_name.read().clone(),
+            company: self.company.read().clone(),
+            address_1: self.address_1.read().clone(),
+            address_2: self.address_2.read().clone(),
+            city: self.city.read().clone(),
+            state: self.state.read().clone(),
+            postcode: self.postcode.read().clone(),
+            country: self.country.read().clone(),
+            email: self.email.read().clone(),
+            phone: self.phone.read().clone(),
+        }
+    }
+}
+
+/// # Address Form Component
+///
+/// The fields of a billing or shipping address, for use inside a form.
+///
+/// **Arguments**
+///
+/// * `prefix` - The prefix of the field IDs, e.g. "billing".
+/// * `legend` - The legend of the fieldset.
+/// * `address` - The signals the fields are bound to.
+/// * `with_contact` - Whether to include the email and phone fields.
+/// * `errors` - The errors of the form, by `{prefix}_{field}` field name.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered component.
+#[component]
+pub fn AddressForm(
+    prefix: String,
+    legend: String,
+    address: AddressSignals,
+    #[props(default)] with_contact: bool,
+    #[props(default)] errors: FieldErrors,
+) -> Element {
+    rsx! {
+        fieldset {
+            class: "space-y-4",
+            legend {
+                class: "text-xl font-bold mb-4",
+                "{legend}"
+            }
+            FormField {
+                id: "{prefix}_first_name",
+                label: "First name",
+                value: address.first_name,
+                autocomplete: "{prefix} given-name",
+                error: errors.field(&format!("{prefix}_first_name")),
+            }
+            FormField {
+                id: "{prefix}_last_name",
+                label: "Last name",
+                value: address.last_name,
+                autocomplete: "{prefix} family-name",
+                error: errors.field(&format!("{prefix}_last_name")),
+            }
+            FormField {
+                id: "{prefix}_company",
+                label: "Company",
+                value: address.company,
+                autocomplete: "{prefix} organization",
+                error: errors.field(&format!("{prefix}_company")),
+            }
+            FormField {
+                id: "{prefix}_address_1",
+                label: "Street address",
+                value: address.address_1,
+                autocomplete: "{prefix} address-line1",
+                error: errors.field(&format!("{prefix}_address_1")),
+            }
+            FormField {
+                id: "{prefix}_address_2",
+                label: "Apartment, suite, unit, etc.",
+                value: address.address_2,
+                autocomplete: "{prefix} address-line2",
+                error: errors.field(&format!("{prefix}_address_2")),
+            }
+            FormField {
+                id: "{prefix}_city",
+                label: "City",
+                value: address.city,
+                autocomplete: "{prefix} address-level2",
+                error: errors.field(&format!("{prefix}_city")),
+            }
+            FormField {
+                id: "{prefix}_state",
+                label: "State",
+                value: address.state,
+                autocomplete: "{prefix} address-level1",
+                error: errors.field(&format!("{prefix}_state")),
+            }
+            FormField {
+                id: "{prefix}_postcode",
+                label: "Postcode",
+                value: address.postcode,
+                autocomplete: "{prefix} postal-code",
+                error: errors.field(&format!("{prefix}_postcode")),
+            }
+            FormField {
+                id: "{prefix}_country",
+                label: "Country code",
+                value: address.country,
+                autocomplete: "{prefix} country",
+                error: errors.field(&format!("{prefix}_country")),
+            }
+            if with_contact {
+                FormField {
+                    id: "{prefix}_email",
+                    label: "Email address",
+                    value: address.email,
+                    input_type: "email",
+                    autocomplete: "{prefix} email",
+                    error: errors.field(&format!("{prefix}_email")),
+                }
+                FormField {
+                    id: "{prefix}_phone",
+                    label: "Phone",
+                    value: address.phone,
+                    input_type: "tel",
+                    autocomplete: "{prefix} tel",
+                    error: errors.field(&format!("{prefix}_phone")),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod address_card;
+pub mod address_form;
+pub mod nav;
+pub mod order_details;
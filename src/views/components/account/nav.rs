@@ -0,0 +1,50 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+
+// Modules
+use crate::{controllers::auth::AuthController, routes::Routes};
+
+/// # Account Navigation Component
+///
+/// Links to the pages of the account area, with a sign out button.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered component.
+#[component]
+pub fn AccountNav() -> Element {
+    let links: [(Routes, &str); 4] = [
+        (Routes::AccountPage {}, "Dashboard"),
+        (Routes::AccountOrdersPage {}, "Orders"),
+        (Routes::AccountAddressesPage {}, "Addresses"),
+        (Routes::AccountDetailsPage {}, "Account details"),
+    ];
+
+    rsx! {
+        nav {
+            aria_label: "Account",
+            ul {
+                class: "space-y-2",
+                for (route, label) in links {
+                    li {
+                        Link {
+                            class: "block px-4 py-2 rounded-lg hover:bg-gray-100",
+                            active_class: "bg-gray-100 font-bold",
+                            to: route,
+                            "{label}"
+                        }
+                    }
+                }
+                li {
+                    button {
+                        class: "block w-full text-left px-4 py-2 rounded-lg hover:bg-gray-100",
+                        onclick: move |_| {
+                            spawn(async move { AuthController::new().logout().await });
+                        },
+                        "Sign out"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+
+// Modules
+use crate::{
+    models::order::Order, routes::Routes, views::components::account::address_card::AddressCard,
+};
+
+/// # Order Details Component
+///
+/// A component that displays an order, with its items, totals and addresses.
+///
+/// **Arguments**
+///
+/// * `order` - The order to display.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered component.
+#[component]
+pub fn OrderDetails(order: Order) -> Element {
+    let totals: Vec<(&str, String)> = [
+        ("Subtotal", &order.subtotal),
+        ("Shipping", &order.shipping_total),
+        ("Tax", &order.total_tax),
+        ("Total", &order.total),
+    ]
+    .into_iter()
+    .filter_map(|(label, value)| value.clone().map(|value| (label, value)))
+    .collect();
+
+    rsx! {
+        div {
+            class: "space-y-8",
+            div {
+                h2 {
+                    class: "text-2xl font-bold mb-2",
+                    "Order #{order.number()}"
+                }
+                p {
+                    class: "text-gray-600",
+                    "Placed on {order.date.clone().unwrap_or_default()} and currently "
+                    strong { "{order.status_label()}" }
+                    "."
+                }
+            }
+
+            // Line items
+            table {
+                class: "w-full text-left",
+                thead {
+                    tr {
+                        class: "border-b",
+                        th { class: "py-2", "Product" }
+                        th { class: "py-2", "Quantity" }
+                        th { class: "py-2", "Total" }
+                    }
+                }
+                tbody {
+                    for item in order.line_items.iter() {
+                        tr {
+                            class: "border-b",
+                            td {
+                                class: "py-2",
+                                if let Some(slug) = &item.slug {
+                                    Link {
+                                        class: "text-blue-700 hover:underline",
+                                        to: Routes::ProductPage { product_slug: slug.clone() },
+                                        {item.name.clone().unwrap_or_default()}
+                                    }
+                                } else {
+                                    {item.name.clone().unwrap_or_default()}
+                                }
+                            }
+                            td { class: "py-2", "{item.quantity.unwrap_or_default()}" }
+                            td { class: "py-2", {item.total.clone().unwrap_or_default()} }
+                        }
+                    }
+                }
+                tfoot {
+                    for (label, value) in totals {
+                        tr {
+                            th { class: "py-2", colspan: 2, "{label}" }
+                            td { class: "py-2", "{value}" }
+                        }
+                    }
+                }
+            }
+
+            if let Some(payment_method_title) = &order.payment_method_title {
+                p { "Paid with {payment_method_title}." }
+            }
+            if let Some(customer_note) = order.customer_note.as_ref().filter(|note| !note.is_empty()) {
+                p { "Note: {customer_note}" }
+            }
+
+            div {
+                class: "grid grid-cols-1 md:grid-cols-2 gap-8",
+                AddressCard { title: "Billing address", address: order.billing.clone() }
+                AddressCard { title: "Shipping address", address: order.shipping.clone() }
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use dioxus::prelude::*;
+
+// Modules
+use crate::{
+    app::{
+        cart::use_cart,
+        error::{ErrorField, FieldErrors},
+        state::STATE,
+    },
+    views::components::common::form::FormField,
+};
+
+/// The coupon form field, with the codes and message keywords of the errors about it
+///
+/// WooCommerce names the coupon in each message, e.g. `Coupon "summer" does not
+/// exist!` or "This coupon has expired."
+const FIELDS: &[ErrorField] = &[ErrorField::new(
+    "coupon_code",
+    &[
+        "invalid_coupon",
+        "coupon_not_found",
+        "coupon_already_applied",
+    ],
+    &["coupon"],
+)];
+
+/// # Coupons Component
+///
+/// The coupons applied to the cart, each with a remove button, and a form to
+/// apply another.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered component.
+#[component]
+pub fn Coupons() -> Element {
+    let mut code = use_signal(String::new);
+    let mut errors = use_signal(FieldErrors::default);
+    let mut submitting = use_signal(|| false);
+    let cart = use_cart();
+
+    let onsubmit = move |event: FormEvent| {
+        event.stop_propagation();
+        if *submitting.read() {
+            return;
+        }
+
+        let entered: String = code.read().trim().to_string();
+        if entered.is_empty() {
+            return;
+        }
+
+        submitting.set(true);
+        errors.set(FieldErrors::default());
+        spawn(async move {
+            match cart.apply_coupon(entered).await {
+                Ok(()) => code.set(String::new()),
+                Err(e) => errors.set(FieldErrors::from_error(&e, FIELDS)),
+            }
+            submitting.set(false);
+        });
+    };
+
+    let state = STATE.read();
+    let field_errors = errors.read();
+
+    rsx! {
+        div {
+            class: "space-y-4",
+            for coupon in state.cart.applied_coupons.iter() {
+                div {
+                    class: "flex justify-between items-center",
+                    span {
+                        class: "font-medium",
+                        title: coupon.description.clone().unwrap_or_default(),
+                        "Coupon: {coupon.code}"
+                    }
+                    span {
+                        class: "flex items-center space-x-2",
+                        span { "-{coupon.discount_amount}" }
+                        button {
+                            r#type: "button",
+                            class: "text-sm text-blue-700 hover:underline",
+                            aria_label: "Remove coupon {coupon.code}",
+                            onclick: {
+                                let coupon_code: String = coupon.code.clone();
+                                move |_| {
+                                    let coupon_code: String = coupon_code.clone();
+                                    spawn(async move {
+                                        if let Err(e) = cart.remove_coupon(coupon_code).await {
+                                            errors.set(FieldErrors::from_error(&e, FIELDS));
+                                        }
+                                    });
+                                }
+                            },
+                            "Remove"
+                        }
+                    }
+                }
+            }
+
+            form {
+                class: "space-y-2",
+                onsubmit,
+                for message in &field_errors.form {
+                    p {
+                        class: "text-red-600",
+                        role: "alert",
+                        "{message}"
+                    }
+                }
+                FormField {
+                    id: "coupon_code",
+                    label: "Coupon code",
+                    value: code,
+                    autocomplete: "off",
+                    required: true,
+                    error: field_errors.field("coupon_code"),
+                }
+                button {
+                    r#type: "submit",
+                    class: "w-full bg-gray-200 hover:bg-gray-300 font-medium rounded-lg px-4 py-2 disabled:opacity-50",
+                    disabled: *submitting.read(),
+                    if *submitting.read() { "Applying..." } else { "Apply coupon" }
+                }
+            }
+        }
+    }
+}
@@ -1,138 +1,57 @@
 use dioxus::prelude::*;
 
 // Modules
-use crate::{
-    app::state::STATE,
-    controllers::cart::CartController,
-    graphql::models::cart::{cart_query, cart_query::CartQueryCartContentsNodesProductNode},
-};
+use crate::{app::cart::use_cart, models::cart::CartLine};
 
 /// # Cart Item Component
 ///
-/// A component that displays a single item in the cart.
+/// A component that displays a single line of the cart.
 ///
 /// **Arguments**
 ///
-/// * `item` - The item to display.
+/// * `line` - The line to display.
 ///
 /// **Returns**
 ///
 /// * `Element` - The rendered component.
 #[component]
-pub fn CartItem(item: cart_query::CartQueryCartContentsNodes) -> Element {
-    // Get the product name
-    let product_name = &item
-        .product
-        .map(|product| match product.node {
-            CartQueryCartContentsNodesProductNode::SimpleProduct(sp) => sp.name.unwrap_or_default(),
-            _ => String::new(),
-        })
-        .unwrap_or_default();
+pub fn CartItem(line: CartLine) -> Element {
+    // Get the cart service
+    let cart = use_cart();
 
-    // Get the quantity of the item
-    let quantity = item.quantity.unwrap_or(0);
+    // The edits are optimistic, and made to the quantity shown when clicked,
+    // so rapid clicks add up. A rolled back edit's error is shown below the item.
+    let key_decrease = line.key.clone();
+    let decrease_fn = move |_| cart.adjust(key_decrease.clone(), -1);
 
-    // Get the cart controller
-    let mut cart_controller = use_signal(CartController::new);
+    let key_increase = line.key.clone();
+    let increase_fn = move |_| cart.adjust(key_increase.clone(), 1);
 
-    // Decrease quantity method
-    let key_decrease = item.key.clone();
-    let decrease_fn = move |_| -> () {
-        // Decrease the quantity of the item.
-        let key_decrease = key_decrease.clone();
-        spawn(async move {
-            // Update the quantity of the item.
-            if let Err(e) = cart_controller
-                .write()
-                .update_item_quantity(key_decrease, quantity - 1)
-                .await
-            {
-                tracing::error!("Error updating item quantity: {}", e);
-            }
-
-            // Update the cart state.
-            if let Ok(Some(cart_data)) = cart_controller.write().get_cart().await {
-                if let Some(cart) = cart_data.cart {
-                    // Update the cart state.
-                    let mut state = STATE.write();
-
-                    // Update the cart items.
-                    if let Some(contents) = cart.contents {
-                        state.cart.items = contents.nodes.into_iter().collect();
-                    }
-
-                    // Update the cart total.
-                    state.cart.total = cart.total.unwrap_or_default();
-
-                    // Update the cart subtotal.
-                    state.cart.subtotal = cart.subtotal.unwrap_or_default();
-
-                    // Save the cart state.
-                    state.save_cart();
-                }
-            }
-        });
-    };
-
-    // Increase quantity method
-    let key_increase = item.key.clone();
-    let increase_fn = move |_| -> () {
-        // Increase the quantity of the item.
-        let key_increase = key_increase.clone();
-        spawn(async move {
-            if let Err(e) = cart_controller
-                .write()
-                .update_item_quantity(key_increase, quantity + 1)
-                .await
-            {
-                tracing::error!("Error updating item quantity: {}", e);
-            }
-            if let Ok(Some(cart_data)) = cart_controller.write().get_cart().await {
-                if let Some(cart) = cart_data.cart {
-                    let mut state = STATE.write();
-                    if let Some(contents) = cart.contents {
-                        state.cart.items = contents.nodes.into_iter().collect();
-                    }
-                    state.cart.total = cart.total.unwrap_or_default();
-                    state.cart.subtotal = cart.subtotal.unwrap_or_default();
-                    state.save_cart();
-                }
-            }
-        });
-    };
-
-    // Remove item method
-    let key_remove = item.key.clone();
-    let remove_fn = move |_| -> () {
-        // Remove the item from the cart.
-        let key_remove = key_remove.clone();
-        spawn(async move {
-            if let Err(e) = cart_controller
-                .write()
-                .remove_item_from_cart(key_remove)
-                .await
-            {
-                tracing::error!("Error removing item from cart: {}", e);
-            }
-            if let Ok(Some(cart_data)) = cart_controller.write().get_cart().await {
-                if let Some(cart) = cart_data.cart {
-                    let mut state = STATE.write();
-                    if let Some(contents) = cart.contents {
-                        state.cart.items = contents.nodes.into_iter().collect();
-                    }
-                    state.cart.total = cart.total.unwrap_or_default();
-                    state.cart.subtotal = cart.subtotal.unwrap_or_default();
-                    state.save_cart();
-                }
-            }
-        });
-    };
+    let key_remove = line.key.clone();
+    let remove_fn = move |_| cart.remove(key_remove.clone());
 
     // Render the cart item.
     rsx! {
-        div { class: "flex items-center justify-between py-4 border-b",
+        div {
+            class: "flex items-center justify-between py-4 border-b",
+            aria_busy: cart.is_pending(&line.key),
             div { class: "flex items-center space-x-4",
-                span { "{product_name}" }
+                div {
+                    span { "{line.name}" }
+                    for attribute in line.attributes.iter() {
+                        p {
+                            class: "text-sm text-gray-600",
+                            "{attribute.label}: {attribute.value}"
+                        }
+                    }
+                    if let Some(error) = cart.line_error(&line.key) {
+                        p {
+                            class: "text-sm text-red-600",
+                            role: "alert",
+                            "{error}"
+                        }
+                    }
+                }
             }
             div { class: "flex items-center space-x-4",
                 div { class: "flex items-center space-x-2",
@@ -141,7 +60,7 @@ pub fn CartItem(item: cart_query::CartQueryCartContentsNodes) -> Element {
                         onclick: decrease_fn,
                         "-"
                     }
-                    span { "{quantity}" }
+                    span { "{line.quantity}" }
                     button {
                         class: "px-2 py-1 bg-gray-200 rounded",
                         onclick: increase_fn,
@@ -0,0 +1,250 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+use std::rc::Rc;
+
+// Modules
+use crate::{
+    app::{
+        cart::use_cart,
+        state::{MINI_CART_OPEN, STATE},
+    },
+    models::cart::CartLine,
+    routes::Routes,
+    views::{components::cart::removed::RemovedLines, icons::CartIcon},
+};
+
+/// # Mini Cart Component
+///
+/// The cart button of the header, and the drawer it opens: the cart's lines,
+/// which can be edited, and its subtotal, with links to the cart and checkout.
+/// The drawer also opens after adding to the cart.
+///
+/// The drawer is a modal dialog. Focus moves into it when it opens, is kept
+/// inside it, and returns to the cart button when it closes. Changes to the
+/// cart are announced to screen readers.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered component.
+#[component]
+pub fn MiniCart() -> Element {
+    let mut toggle: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut first: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+    let mut last: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+
+    // Close the drawer, returning focus to the cart button
+    let close = move || {
+        *MINI_CART_OPEN.write() = false;
+        if let Some(toggle) = toggle() {
+            spawn(async move {
+                let _ = toggle.set_focus(true).await;
+            });
+        }
+    };
+
+    // Move focus to an element of the drawer
+    let focus = move |element: Signal<Option<Rc<MountedData>>>| {
+        if let Some(element) = element() {
+            spawn(async move {
+                let _ = element.set_focus(true).await;
+            });
+        }
+    };
+
+    let open: bool = *MINI_CART_OPEN.read();
+    let state = STATE.read();
+    let cart = &state.cart;
+    let item_count: i64 = cart.item_count();
+
+    rsx! {
+        button {
+            r#type: "button",
+            class: "block",
+            aria_haspopup: "dialog",
+            aria_expanded: open,
+            aria_controls: "mini-cart",
+            onmounted: move |event| toggle.set(Some(event.data())),
+            onclick: move |_| *MINI_CART_OPEN.write() = true,
+            CartIcon {}
+        }
+
+        // Announce the changes to the cart, whether the drawer is open or not
+        p {
+            class: "sr-only",
+            aria_live: "polite",
+            aria_atomic: "true",
+            if item_count > 0 {
+                "Your cart has {item_count} item(s), with a subtotal of {cart.totals.subtotal}."
+            } else {
+                "Your cart is empty."
+            }
+        }
+
+        if open {
+            div {
+                class: "fixed inset-0 z-40 bg-black bg-opacity-50",
+                aria_hidden: "true",
+                onclick: move |_| close(),
+            }
+            div {
+                id: "mini-cart",
+                class: "fixed inset-y-0 right-0 z-50 flex flex-col w-full max-w-sm bg-white shadow-xl",
+                role: "dialog",
+                aria_modal: "true",
+                aria_labelledby: "mini-cart-title",
+                onkeydown: move |event| {
+                    if event.key() == Key::Escape {
+                        close();
+                    }
+                },
+
+                // Wrap focus from the start of the drawer to its end
+                div { tabindex: "0", onfocus: move |_| focus(last) }
+
+                div {
+                    class: "flex items-center justify-between p-4 border-b",
+                    h2 {
+                        id: "mini-cart-title",
+                        class: "text-xl font-bold",
+                        "Your Cart"
+                    }
+                    button {
+                        r#type: "button",
+                        class: "text-gray-600 hover:text-gray-900",
+                        aria_label: "Close cart",
+                        onmounted: move |event| {
+                            let element: Rc<MountedData> = event.data();
+                            first.set(Some(element.clone()));
+                            spawn(async move {
+                                let _ = element.set_focus(true).await;
+                            });
+                        },
+                        onclick: move |_| close(),
+                        "✕"
+                    }
+                }
+
+                div {
+                    class: "flex-grow overflow-y-auto p-4",
+                    RemovedLines {}
+                    if cart.lines.is_empty() {
+                        p { "Your cart is empty." }
+                    } else {
+                        ul {
+                            for line in &cart.lines {
+                                MiniCartLine { key: "{line.key}", line: line.clone() }
+                            }
+                        }
+                    }
+                }
+
+                div {
+                    class: "p-4 border-t space-y-4",
+                    div {
+                        class: "flex justify-between font-bold",
+                        span { "Subtotal" }
+                        span { "{cart.totals.subtotal}" }
+                    }
+                    if !cart.lines.is_empty() {
+                        Link {
+                            class: "block w-full bg-blue-600 hover:bg-blue-700 text-white text-center font-medium rounded-lg px-4 py-2",
+                            to: Routes::CheckoutPage {},
+                            onclick: move |_| *MINI_CART_OPEN.write() = false,
+                            "Checkout"
+                        }
+                    }
+                    Link {
+                        class: "block w-full bg-gray-200 hover:bg-gray-300 text-center font-medium rounded-lg px-4 py-2",
+                        to: Routes::CartPage {},
+                        onmounted: move |event: MountedEvent| last.set(Some(event.data())),
+                        onclick: move |_| *MINI_CART_OPEN.write() = false,
+                        "View cart"
+                    }
+                }
+
+                // Wrap focus from the end of the drawer to its start
+                div { tabindex: "0", onfocus: move |_| focus(first) }
+            }
+        }
+    }
+}
+
+/// # Mini Cart Line Component
+///
+/// A line of the mini cart, with its thumbnail and quantity controls.
+///
+/// **Arguments**
+///
+/// * `line` - The line to display.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered component.
+#[component]
+fn MiniCartLine(line: CartLine) -> Element {
+    let cart = use_cart();
+
+    // The edits are optimistic, as on the cart page
+    let key_decrease = line.key.clone();
+    let key_increase = line.key.clone();
+    let key_remove = line.key.clone();
+
+    rsx! {
+        li {
+            class: "flex py-4 space-x-4 border-b",
+            aria_busy: cart.is_pending(&line.key),
+            if let Some(source_url) = line.image.as_ref().and_then(|image| image.source_url.clone()) {
+                img {
+                    class: "w-16 h-16 object-cover rounded",
+                    src: "{source_url}",
+                    alt: "",
+                }
+            } else {
+                div { class: "w-16 h-16 bg-gray-100 rounded" }
+            }
+            div {
+                class: "flex-grow space-y-1",
+                p { class: "font-medium", "{line.name}" }
+                for attribute in line.attributes.iter() {
+                    p {
+                        class: "text-sm text-gray-600",
+                        "{attribute.label}: {attribute.value}"
+                    }
+                }
+                div {
+                    class: "flex items-center space-x-2",
+                    button {
+                        r#type: "button",
+                        class: "px-2 bg-gray-200 rounded",
+                        aria_label: "Decrease the quantity of {line.name}",
+                        onclick: move |_| cart.adjust(key_decrease.clone(), -1),
+                        "-"
+                    }
+                    span { "{line.quantity}" }
+                    button {
+                        r#type: "button",
+                        class: "px-2 bg-gray-200 rounded",
+                        aria_label: "Increase the quantity of {line.name}",
+                        onclick: move |_| cart.adjust(key_increase.clone(), 1),
+                        "+"
+                    }
+                    button {
+                        r#type: "button",
+                        class: "text-sm text-red-500",
+                        aria_label: "Remove {line.name}",
+                        onclick: move |_| cart.remove(key_remove.clone()),
+                        "Remove"
+                    }
+                }
+                if let Some(error) = cart.line_error(&line.key) {
+                    p {
+                        class: "text-sm text-red-600",
+                        role: "alert",
+                        "{error}"
+                    }
+                }
+            }
+            span { class: "text-sm", "{line.total}" }
+        }
+    }
+}
@@ -1 +1,6 @@
+pub mod coupons;
 pub mod item;
+pub mod mini_cart;
+pub mod removed;
+pub mod shipping;
+pub mod totals;
@@ -0,0 +1,51 @@
+use dioxus::prelude::*;
+
+// Modules
+use crate::app::cart::use_cart;
+
+/// # Removed Lines Component
+///
+/// A notice for each line removed from the cart, with an undo button, while
+/// the line can still be restored. The notices are announced to screen readers.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered component.
+#[component]
+pub fn RemovedLines() -> Element {
+    let cart = use_cart();
+
+    rsx! {
+        div {
+            class: "space-y-2 mb-4",
+            role: "status",
+            aria_live: "polite",
+            for line in cart.removed_lines() {
+                div {
+                    key: "{line.key}",
+                    class: "flex items-center justify-between bg-gray-100 rounded-lg p-3",
+                    div {
+                        p { "{line.name} was removed from your cart." }
+                        if let Some(error) = cart.line_error(&line.key) {
+                            p {
+                                class: "text-sm text-red-600",
+                                role: "alert",
+                                "{error}"
+                            }
+                        }
+                    }
+                    button {
+                        r#type: "button",
+                        class: "text-blue-700 hover:underline",
+                        aria_label: "Undo removing {line.name}",
+                        onclick: {
+                            let key: String = line.key.clone();
+                            move |_| cart.undo_remove(&key)
+                        },
+                        "Undo"
+                    }
+                }
+            }
+        }
+    }
+}
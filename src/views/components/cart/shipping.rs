@@ -0,0 +1,289 @@
+use dioxus::prelude::*;
+
+// Modules
+use crate::{
+    app::{
+        cart::use_cart,
+        error::{ErrorField, FieldErrors},
+        state::{CUSTOMER, STATE},
+    },
+    controllers::shipping::ShippingController,
+    models::address::Address,
+};
+
+/// The shipping calculator fields, with the codes and message keywords of the errors about them
+const FIELDS: &[ErrorField] = &[
+    ErrorField::new(
+        "shipping_country",
+        &["shipping_country_required", "shipping_country_validation"],
+        &["country"],
+    ),
+    ErrorField::new(
+        "shipping_state",
+        &["shipping_state_required", "shipping_state_validation"],
+        &["state", "county"],
+    ),
+    ErrorField::new(
+        "shipping_postcode",
+        &["shipping_postcode_required", "shipping_postcode_validation"],
+        &["postcode", "zip"],
+    ),
+];
+
+/// # Shipping Rates Component
+///
+/// The shipping rates of each shipping package in the cart, for the shopper to
+/// choose from, and a calculator to set the address they are calculated for.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered component.
+#[component]
+pub fn ShippingRates() -> Element {
+    let mut errors = use_signal(FieldErrors::default);
+    let cart_service = use_cart();
+
+    // Choose a shipping rate, which changes the totals
+    let mut choose_rate = move |package: usize, rate_id: String| {
+        let mut methods: Vec<String> = STATE.read().cart.chosen_shipping_methods.clone();
+        methods.resize(methods.len().max(package + 1), String::new());
+        methods[package] = rate_id;
+
+        errors.set(FieldErrors::default());
+        spawn(async move {
+            if let Err(e) = cart_service.select_shipping_method(methods).await {
+                errors.set(FieldErrors::from_error(&e, FIELDS));
+            }
+        });
+    };
+
+    let state = STATE.read();
+    let cart = &state.cart;
+    let field_errors = errors.read();
+
+    rsx! {
+        div {
+            class: "space-y-4",
+            h3 {
+                class: "font-bold",
+                "Shipping"
+            }
+            for message in &field_errors.form {
+                p {
+                    class: "text-red-600",
+                    role: "alert",
+                    "{message}"
+                }
+            }
+            if cart.shipping_packages.iter().all(|package| package.rates.is_empty()) {
+                p {
+                    class: "text-gray-600",
+                    "Enter your address to see the shipping options."
+                }
+            }
+            for (package, shipping_package) in cart.shipping_packages.iter().enumerate() {
+                fieldset {
+                    class: "space-y-2",
+                    legend {
+                        class: "sr-only",
+                        "Shipping method"
+                    }
+                    if let Some(details) = &shipping_package.details {
+                        p { class: "text-gray-600", dangerous_inner_html: "{details}" }
+                    }
+                    for rate in shipping_package.rates.iter() {
+                        label {
+                            class: "flex items-center space-x-2",
+                            input {
+                                r#type: "radio",
+                                name: "cart_shipping_method_{package}",
+                                checked: cart.chosen_shipping_methods.get(package) == Some(&rate.id),
+                                onchange: {
+                                    let rate_id: String = rate.id.clone();
+                                    move |_| choose_rate(package, rate_id.clone())
+                                },
+                            }
+                            span { {rate.label.clone().unwrap_or_default()} }
+                            span { class: "text-gray-600", {rate.cost.clone().unwrap_or_default()} }
+                        }
+                    }
+                }
+            }
+            ShippingCalculator { errors }
+        }
+    }
+}
+
+/// # Shipping Calculator Component
+///
+/// A form to set the country, state and postcode the cart's shipping rates are
+/// calculated for. The states of the chosen country are offered when it has any.
+///
+/// **Arguments**
+///
+/// * `errors` - The errors of the shipping rates, by `shipping_{field}` field name.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered component.
+#[component]
+fn ShippingCalculator(errors: Signal<FieldErrors>) -> Element {
+    let mut errors = errors;
+
+    // Start from the signed-in customer's shipping address
+    let shipping: Address = CUSTOMER
+        .read()
+        .as_ref()
+        .and_then(|customer| customer.shipping.clone())
+        .unwrap_or_default();
+    let mut country = use_signal(|| shipping.country.clone());
+    let mut country_state = use_signal(|| shipping.state.clone());
+    let mut postcode = use_signal(|| shipping.postcode.clone());
+    let mut submitting = use_signal(|| false);
+    let cart = use_cart();
+
+    // Fetch the countries, and the states of the chosen country
+    let countries_resource =
+        use_resource(|| async { ShippingController::new().get_countries().await });
+    let states_resource = use_resource(move || {
+        let country: String = country.read().clone();
+        async move {
+            if country.is_empty() {
+                return Ok(Vec::new());
+            }
+            ShippingController::new().get_country_states(&country).await
+        }
+    });
+
+    let onsubmit = move |event: FormEvent| {
+        event.stop_propagation();
+        if *submitting.read() {
+            return;
+        }
+        submitting.set(true);
+        errors.set(FieldErrors::default());
+
+        let destination = Address {
+            country: country.read().clone(),
+            state: country_state.read().clone(),
+            postcode: postcode.read().clone(),
+            ..Address::default()
+        };
+        spawn(async move {
+            if let Err(e) = cart.update_shipping_address(destination).await {
+                errors.set(FieldErrors::from_error(&e, FIELDS));
+            }
+            submitting.set(false);
+        });
+    };
+
+    let countries = countries_resource.read();
+    let states = states_resource.read();
+    let field_errors = errors.read();
+
+    rsx! {
+        details {
+            summary {
+                class: "cursor-pointer text-blue-700 hover:underline",
+                "Calculate shipping"
+            }
+            form {
+                class: "space-y-2 mt-2",
+                onsubmit,
+
+                label {
+                    class: "block font-medium",
+                    r#for: "shipping_country",
+                    "Country"
+                }
+                select {
+                    id: "shipping_country",
+                    class: "block w-full p-3 border border-gray-300 rounded-lg",
+                    autocomplete: "shipping country",
+                    aria_invalid: field_errors.field("shipping_country").is_some(),
+                    onchange: move |event| {
+                        country.set(event.value());
+                        country_state.set(String::new());
+                    },
+                    option { value: "", "Select a country" }
+                    if let Some(Ok(countries)) = countries.as_ref() {
+                        for code in countries.iter() {
+                            option {
+                                value: "{code}",
+                                selected: *country.read() == *code,
+                                "{code}"
+                            }
+                        }
+                    }
+                }
+                if let Some(error) = field_errors.field("shipping_country") {
+                    p { class: "text-sm text-red-600", "{error}" }
+                }
+
+                label {
+                    class: "block font-medium",
+                    r#for: "shipping_state",
+                    "State / County"
+                }
+                match states.as_ref() {
+                    Some(Ok(states)) if !states.is_empty() => rsx! {
+                        select {
+                            id: "shipping_state",
+                            class: "block w-full p-3 border border-gray-300 rounded-lg",
+                            autocomplete: "shipping address-level1",
+                            aria_invalid: field_errors.field("shipping_state").is_some(),
+                            onchange: move |event| country_state.set(event.value()),
+                            option { value: "", "Select a state" }
+                            for state in states.iter() {
+                                option {
+                                    value: "{state.code}",
+                                    selected: *country_state.read() == state.code,
+                                    "{state.name}"
+                                }
+                            }
+                        }
+                    },
+                    _ => rsx! {
+                        input {
+                            id: "shipping_state",
+                            r#type: "text",
+                            class: "block w-full p-3 border border-gray-300 rounded-lg",
+                            autocomplete: "shipping address-level1",
+                            aria_invalid: field_errors.field("shipping_state").is_some(),
+                            value: "{country_state}",
+                            oninput: move |event| country_state.set(event.value()),
+                        }
+                    },
+                }
+                if let Some(error) = field_errors.field("shipping_state") {
+                    p { class: "text-sm text-red-600", "{error}" }
+                }
+
+                label {
+                    class: "block font-medium",
+                    r#for: "shipping_postcode",
+                    "Postcode / ZIP"
+                }
+                input {
+                    id: "shipping_postcode",
+                    r#type: "text",
+                    class: "block w-full p-3 border border-gray-300 rounded-lg",
+                    autocomplete: "shipping postal-code",
+                    aria_invalid: field_errors.field("shipping_postcode").is_some(),
+                    value: "{postcode}",
+                    oninput: move |event| postcode.set(event.value()),
+                }
+                if let Some(error) = field_errors.field("shipping_postcode") {
+                    p { class: "text-sm text-red-600", "{error}" }
+                }
+
+                button {
+                    r#type: "submit",
+                    class: "w-full bg-gray-200 hover:bg-gray-300 font-medium rounded-lg px-4 py-2 disabled:opacity-50",
+                    disabled: *submitting.read(),
+                    if *submitting.read() { "Updating..." } else { "Update" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use dioxus::prelude::*;
+
+// Modules
+use crate::{app::html, models::cart::CartTotals};
+
+/// # Totals Component
+///
+/// The totals breakdown of a cart: subtotal, discount, fees, shipping, tax and total.
+///
+/// The totals are formatted by WooCommerce, whose currency symbols may be HTML
+/// entities, so they're decoded before they're shown.
+///
+/// **Arguments**
+///
+/// * `totals` - The totals to show.
+/// * `show_shipping` - Whether to show the shipping total, for carts that are shipped.
+/// * `children` - Shown above the shipping total, such as the shipping rates.
+///
+/// **Returns**
+///
+/// * `Element` - The rendered component.
+#[component]
+pub fn Totals(
+    totals: CartTotals,
+    #[props(default)] show_shipping: bool,
+    #[props(default)] children: Element,
+) -> Element {
+    // WooGraphQL only formats the total of the fees, so they share a row
+    let fees: Option<String> = match totals.fees.as_slice() {
+        [] => None,
+        [fee] => Some(html::to_text(&fee.name)),
+        _ => Some("Fees".to_string()),
+    };
+
+    rsx! {
+        div {
+            class: "space-y-2",
+            div {
+                class: "flex justify-between",
+                span { "Subtotal" }
+                span { "{html::to_text(&totals.subtotal)}" }
+            }
+            if totals.has_discount() {
+                div {
+                    class: "flex justify-between text-green-700",
+                    span { "Discount" }
+                    span { "-{html::to_text(&totals.discount_total)}" }
+                }
+            }
+            if let Some(fees) = fees {
+                div {
+                    class: "flex justify-between",
+                    span { "{fees}" }
+                    span { "{html::to_text(&totals.fee_total)}" }
+                }
+            }
+            {children}
+            if show_shipping {
+                div {
+                    class: "flex justify-between",
+                    span { "Shipping" }
+                    span { "{html::to_text(&totals.shipping_total)}" }
+                }
+            }
+            div {
+                class: "flex justify-between",
+                span { "Tax" }
+                span { "{html::to_text(&totals.total_tax)}" }
+            }
+            div {
+                class: "flex justify-between font-bold text-xl",
+                span { "Total" }
+                span { "{html::to_text(&totals.total)}" }
+            }
+        }
+    }
+}
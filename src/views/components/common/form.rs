@@ -0,0 +1,50 @@
+use dioxus::prelude::*;
+
+/// Form field component
+///
+/// A labelled input bound to a signal, with the field's error below it.
+#[component]
+pub fn FormField(
+    id: String,
+    label: String,
+    value: Signal<String>,
+    #[props(default = "text".to_string())] input_type: String,
+    #[props(default)] autocomplete: Option<String>,
+    #[props(default)] required: bool,
+    #[props(default)] error: Option<String>,
+) -> Element {
+    let mut value = value;
+    let error_id: String = format!("{id}-error");
+
+    rsx! {
+        div {
+            label {
+                class: "block mb-2 font-medium",
+                r#for: "{id}",
+                "{label}"
+            }
+            input {
+                id: "{id}",
+                r#type: "{input_type}",
+                class: if error.is_some() {
+                    "block w-full p-3 border border-red-500 rounded-lg"
+                } else {
+                    "block w-full p-3 border border-gray-300 rounded-lg"
+                },
+                autocomplete,
+                required,
+                aria_invalid: error.is_some(),
+                aria_describedby: error.as_ref().map(|_| error_id.clone()),
+                value: "{value}",
+                oninput: move |event| value.set(event.value()),
+            }
+            if let Some(error) = &error {
+                p {
+                    id: "{error_id}",
+                    class: "mt-1 text-sm text-red-600",
+                    "{error}"
+                }
+            }
+        }
+    }
+}
@@ -3,4 +3,5 @@
 /// This module contains common, generic UI components that can be reused in various parts of the application.
 pub mod card;
 pub mod entity_list;
+pub mod form;
 pub mod loader;
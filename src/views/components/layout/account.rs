@@ -0,0 +1,40 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+
+// Modules
+use crate::{app::state::CUSTOMER, controllers::auth::AuthController, routes::Routes};
+
+/// Account menu component
+///
+/// Greets the signed-in customer with a link to their account and a sign out
+/// button, or links guests to the Login page.
+#[component]
+pub fn AccountMenu() -> Element {
+    let customer = CUSTOMER.read();
+
+    rsx! {
+        div {
+            class: "flex items-center space-x-4",
+            if let Some(customer) = &*customer {
+                Link {
+                    class: "hover:text-gray-600",
+                    to: Routes::AccountPage {},
+                    "Hi, {customer.name()}"
+                }
+                button {
+                    class: "hover:text-gray-600",
+                    onclick: move |_| {
+                        spawn(async move { AuthController::new().logout().await });
+                    },
+                    "Sign out"
+                }
+            } else {
+                Link {
+                    class: "hover:text-gray-600",
+                    to: Routes::LoginPage {},
+                    "Sign in"
+                }
+            }
+        }
+    }
+}
@@ -3,9 +3,10 @@ use dioxus::prelude::*;
 // Modules
 use crate::{
     controllers::settings::SettingsController,
-    views::{
-        components::{common::loader::LoaderComponent, layout::nav::Nav},
-        icons::CartIcon,
+    views::components::{
+        cart::mini_cart::MiniCart,
+        common::loader::LoaderComponent,
+        layout::{account::AccountMenu, nav::Nav},
     },
 };
 
@@ -63,9 +64,12 @@ pub fn Header() -> Element {
                         }
                     }
 
+                    // Account
+                    AccountMenu {}
+
                     // Cart
                     div {
-                        CartIcon {}
+                        MiniCart {}
                     }
 
                     // Navigation
@@ -1,3 +1,4 @@
+pub mod account;
 pub mod footer;
 pub mod header;
 pub mod nav;
@@ -1,61 +1,40 @@
 use dioxus::prelude::*;
-use gloo_storage::{LocalStorage, Storage};
 
 // Modules
-use crate::{app::state::STATE, controllers::cart::CartController, models::product::Product};
+use crate::{
+    app::{cart::use_cart, state::MINI_CART_OPEN},
+    models::product::Product,
+};
 
 /// Add to cart form component
 #[component]
 pub fn AddToCartForm(product: Product) -> Element {
+    let cart = use_cart();
+
     rsx! {
         div { class: "flex flex-wrap -mx-4 mb-14 items-center",
             div { class: "w-full xl:w-2/3 px-4 mb-4 xl:mb-0",
                 button {
-                    class: "block bg-orange-300 hover:bg-orange-400 text-center text-white font-bold font-heading py-5 px-8 rounded-md uppercase transition duration-200",
+                    class: "block bg-orange-300 hover:bg-orange-400 text-center text-white font-bold font-heading py-5 px-8 rounded-md uppercase transition duration-200 disabled:opacity-50",
+                    disabled: cart.is_loading(),
                     onclick: move |_| {
                         if let Some(product_id) = product.database_id {
-                            let mut cart_controller = CartController::new();
+                            // The service keeps the error, which is shown below
                             spawn(async move {
-                                match cart_controller.add_to_cart(product_id, 1).await {
-                                    Ok(_) => {
-                                        // Refetch cart after adding an item
-                                        match cart_controller.get_cart().await {
-                                            Ok(Some(response_data)) => {
-                                                if let Some(cart) = response_data.cart {
-                                                    let mut state = STATE.write();
-                                                    if let Some(contents) = cart.contents {
-                                                        state.cart.items =
-                                                            contents.nodes.into_iter().collect();
-                                                    }
-                                                    state.cart.total = cart.total.unwrap_or_default();
-                                                    state.cart.subtotal =
-                                                        cart.subtotal.unwrap_or_default();
-
-                                                    // Save cart to local storage
-                                                    if let Err(e) =
-                                                        LocalStorage::set("cart", state.cart.clone())
-                                                    {
-                                                        tracing::error!(
-                                                            "Failed to save cart to local storage: {}",
-                                                            e
-                                                        );
-                                                    }
-                                                }
-                                            }
-                                            Ok(None) => {}
-                                            Err(e) => {
-                                                tracing::error!("Error refetching cart: {}", e);
-                                            }
-                                        }
-                                    }
-                                    Err(e) => {
-                                        tracing::error!("Error adding to cart: {}", e);
-                                    }
+                                if cart.add(product_id, 1).await.is_ok() {
+                                    *MINI_CART_OPEN.write() = true;
                                 }
                             });
                         }
                     },
-                    "Add to cart"
+                    if cart.is_loading() { "Adding..." } else { "Add to cart" }
+                }
+                if let Some(error) = cart.error() {
+                    p {
+                        class: "mt-2 text-red-600",
+                        role: "alert",
+                        "{error}"
+                    }
                 }
             }
         }
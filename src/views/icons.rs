@@ -1,25 +1,36 @@
 use dioxus::prelude::*;
 
 // Modules
-use crate::{app::state::STATE, routes::Routes};
+use crate::app::state::STATE;
 
 /// Cart icon component
+///
+/// The icon, with the number of items in the cart, and a description of the
+/// cart for screen readers. Used inside the button that opens the mini cart.
 #[component]
 pub fn CartIcon() -> Element {
     let state = STATE.read();
 
-    let cart_count = state
-        .cart
-        .items
-        .iter()
-        .map(|item| item.quantity.unwrap_or(0))
-        .sum::<i64>();
+    let cart_count = state.cart.item_count();
+
+    // Describe the cart, with the savings of any coupons
+    let summary: String = if cart_count == 0 {
+        "Cart".to_string()
+    } else if state.cart.totals.has_discount() {
+        format!(
+            "Cart: {cart_count} item(s), {} after {} discount",
+            state.cart.totals.total, state.cart.totals.discount_total
+        )
+    } else {
+        format!("Cart: {cart_count} item(s), {}", state.cart.totals.total)
+    };
 
     rsx! {
-        Link {
-            to: Routes::CartPage {},
-            class: "relative",
+        span {
+            class: "relative block",
+            title: "{summary}",
             svg {
+                "aria-hidden": "true",
                 xmlns: "http://www.w3.org/2000/svg",
                 width: "24",
                 height: "24",
@@ -34,8 +45,10 @@ pub fn CartIcon() -> Element {
                 path { d: "M3 6h18" }
                 path { d: "M16 10a4 4 0 0 1-8 0" }
             },
+            span { class: "sr-only", "{summary}" }
             if cart_count > 0 {
                 span {
+                    aria_hidden: "true",
                     class: "absolute -top-1 -right-1 bg-red-500 text-white text-xs rounded-full h-4 w-4 flex items-center justify-center",
                     "{cart_count}"
                 }
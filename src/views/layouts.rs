@@ -1,10 +1,19 @@
 use dioxus::prelude::*;
+use dioxus_router::prelude::navigator;
 
 // Modules
 use crate::{
+    app::{
+        cart::{use_cart, CartService},
+        config::AppConfig,
+        error::{AppError, AppErrorKind},
+        state::{RestoreStatus, State, CUSTOMER, CUSTOMER_RESTORE},
+    },
+    controllers::auth::AuthController,
     routes::Routes,
     views::components::{
-        common::loader::ChildrenOrLoading,
+        account::nav::AccountNav,
+        common::loader::{ChildrenOrLoading, LoaderComponent},
         layout::{footer::Footer, header::Header},
     },
 };
@@ -12,6 +21,38 @@ use crate::{
 /// Main layout component
 #[component]
 pub fn MainLayout() -> Element {
+    // Nothing can be fetched without a valid configuration
+    let configured: bool = AppConfig::get().is_ok();
+
+    // Restore the signed-in customer from the stored auth token, once
+    use_hook(|| configured.then(|| spawn(async { AuthController::new().restore().await })));
+
+    // Restore the session's cart, once, which replaces the saved cart
+    let cart = use_cart();
+    use_hook(|| {
+        configured.then(|| {
+            spawn(async move {
+                if let Err(e) = cart.restore().await {
+                    tracing::error!("Error restoring cart: {}", e);
+                }
+            })
+        })
+    });
+
+    // Follow the cart changes of the shopper's other tabs
+    use_hook(CartService::sync_tabs);
+
+    // The hooks run first, so they keep their order on every render
+    if let Err(err) = AppConfig::get() {
+        return AppError::new_with_source(
+            AppErrorKind::Config,
+            "The store is not configured correctly.".to_string(),
+            Some(err.to_string()),
+            err.clone(),
+        )
+        .render(Vec::new());
+    }
+
     rsx! {
         div {
             class: "flex flex-col min-h-screen",
@@ -32,3 +73,57 @@ pub fn MainLayout() -> Element {
         }
     }
 }
+
+/// Account layout component
+///
+/// Guards the account area: guests are redirected to the Login page, and the
+/// pages wait for the signed-in customer to be restored. If that fails, the
+/// restore can be retried.
+#[component]
+pub fn AccountLayout() -> Element {
+    // Redirect guests, including customers who sign out in the account area
+    use_effect(|| {
+        if CUSTOMER.read().is_none() && State::get_auth_token().is_none() {
+            navigator().replace(Routes::LoginPage {});
+        }
+    });
+
+    let signed_in: bool = CUSTOMER.read().is_some();
+    let restore: RestoreStatus = CUSTOMER_RESTORE();
+
+    rsx! {
+        div {
+            class: "container mx-auto p-4",
+            h1 {
+                class: "text-3xl font-bold mb-8",
+                "My account"
+            }
+            div {
+                class: "grid grid-cols-1 md:grid-cols-4 gap-8",
+                AccountNav {}
+                div {
+                    class: "md:col-span-3",
+                    if signed_in {
+                        Outlet::<Routes> {}
+                    } else if let RestoreStatus::Failed(message) = restore {
+                        div {
+                            class: "space-y-4",
+                            role: "alert",
+                            p { "Your account could not be loaded. {message}" }
+                            button {
+                                r#type: "button",
+                                class: "bg-blue-600 hover:bg-blue-700 text-white font-medium rounded-lg px-4 py-2",
+                                onclick: move |_| {
+                                    spawn(async { AuthController::new().restore().await });
+                                },
+                                "Try again"
+                            }
+                        }
+                    } else {
+                        LoaderComponent {}
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use dioxus::prelude::*;
+
+// Modules
+use crate::{
+    app::{error::FieldErrors, state::CUSTOMER},
+    controllers::customer::CustomerController,
+    views::components::account::address_form::{AddressForm, AddressSignals},
+};
+
+/// Account addresses page component
+#[component]
+pub fn AccountAddressesPage() -> Element {
+    // The account layout only renders this page for a signed-in customer
+    let customer = CUSTOMER.read().clone();
+    let billing = AddressSignals::use_address(
+        customer
+            .as_ref()
+            .and_then(|customer| customer.billing.clone())
+            .unwrap_or_default(),
+    );
+    let shipping = AddressSignals::use_address(
+        customer
+            .and_then(|customer| customer.shipping)
+            .unwrap_or_default(),
+    );
+    let mut errors = use_signal(FieldErrors::default);
+    let mut saved = use_signal(|| false);
+    let mut submitting = use_signal(|| false);
+
+    let onsubmit = move |event: FormEvent| {
+        event.stop_propagation();
+        if *submitting.read() {
+            return;
+        }
+        submitting.set(true);
+        saved.set(false);
+        errors.set(FieldErrors::default());
+
+        let billing_address = billing.address();
+        let shipping_address = shipping.address();
+        spawn(async move {
+            match CustomerController::new()
+                .update_addresses(&billing_address, &shipping_address)
+                .await
+            {
+                Ok(_) => saved.set(true),
+                // The backend doesn't say which address an error is about
+                Err(e) => errors.set(FieldErrors::from_error(&e, &[])),
+            }
+            submitting.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            h2 {
+                class: "text-2xl font-bold mb-4",
+                "Addresses"
+            }
+
+            form {
+                class: "space-y-8",
+                onsubmit,
+
+                for message in &errors.read().form {
+                    p {
+                        class: "text-red-600",
+                        role: "alert",
+                        "{message}"
+                    }
+                }
+                if *saved.read() {
+                    p {
+                        class: "text-green-700",
+                        role: "status",
+                        "Your addresses were saved."
+                    }
+                }
+
+                div {
+                    class: "grid grid-cols-1 md:grid-cols-2 gap-8",
+                    AddressForm {
+                        prefix: "billing",
+                        legend: "Billing address",
+                        address: billing,
+                        with_contact: true,
+                    }
+                    AddressForm {
+                        prefix: "shipping",
+                        legend: "Shipping address",
+                        address: shipping,
+                    }
+                }
+
+                button {
+                    r#type: "submit",
+                    class: "bg-blue-700 hover:bg-blue-800 text-white font-medium rounded-lg px-4 py-3 disabled:opacity-50",
+                    disabled: *submitting.read(),
+                    if *submitting.read() { "Saving..." } else { "Save addresses" }
+                }
+            }
+        }
+    }
+}
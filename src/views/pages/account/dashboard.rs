@@ -0,0 +1,51 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+
+// Modules
+use crate::{
+    app::state::CUSTOMER, routes::Routes, views::components::account::address_card::AddressCard,
+};
+
+/// Account dashboard page component
+#[component]
+pub fn AccountPage() -> Element {
+    let Some(customer) = CUSTOMER.read().clone() else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div {
+            class: "space-y-8",
+            p {
+                class: "text-xl",
+                "Hello {customer.name()}!"
+            }
+            p {
+                "From your account you can view your "
+                Link {
+                    class: "text-blue-700 hover:underline",
+                    to: Routes::AccountOrdersPage {},
+                    "recent orders"
+                }
+                ", manage your "
+                Link {
+                    class: "text-blue-700 hover:underline",
+                    to: Routes::AccountAddressesPage {},
+                    "billing and shipping addresses"
+                }
+                ", and edit your "
+                Link {
+                    class: "text-blue-700 hover:underline",
+                    to: Routes::AccountDetailsPage {},
+                    "name, email address and password"
+                }
+                "."
+            }
+            div {
+                class: "grid grid-cols-1 md:grid-cols-2 gap-8",
+                AddressCard { title: "Billing address", address: customer.billing }
+                AddressCard { title: "Shipping address", address: customer.shipping }
+            }
+        }
+    }
+}
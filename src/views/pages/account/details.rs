@@ -0,0 +1,166 @@
+use dioxus::prelude::*;
+
+// Modules
+use crate::{
+    app::{
+        error::{ErrorField, FieldErrors},
+        state::CUSTOMER,
+    },
+    controllers::customer::CustomerController,
+    models::customer::AccountDetails,
+    views::components::common::form::FormField,
+};
+
+/// The account details form fields, with the codes and message keywords of the errors about them
+const FIELDS: &[ErrorField] = &[
+    ErrorField::new(
+        "email",
+        &["empty_email", "invalid_email", "existing_user_email"],
+        &["email"],
+    ),
+    ErrorField::new("password", &["pass"], &["password"]),
+    ErrorField::new("first_name", &[], &["first name", "firstname"]),
+    ErrorField::new("last_name", &[], &["last name", "lastname"]),
+    ErrorField::new("display_name", &[], &["display name", "displayname"]),
+];
+
+/// Account details page component
+#[component]
+pub fn AccountDetailsPage() -> Element {
+    // The account layout only renders this page for a signed-in customer
+    let customer = CUSTOMER.read().clone();
+    let first_name = use_signal(|| {
+        customer
+            .as_ref()
+            .and_then(|customer| customer.first_name.clone())
+            .unwrap_or_default()
+    });
+    let last_name = use_signal(|| {
+        customer
+            .as_ref()
+            .and_then(|customer| customer.last_name.clone())
+            .unwrap_or_default()
+    });
+    let display_name = use_signal(|| {
+        customer
+            .as_ref()
+            .and_then(|customer| customer.display_name.clone())
+            .unwrap_or_default()
+    });
+    let email = use_signal(|| {
+        customer
+            .as_ref()
+            .and_then(|customer| customer.email.clone())
+            .unwrap_or_default()
+    });
+    let mut password = use_signal(String::new);
+    let mut errors = use_signal(FieldErrors::default);
+    let mut saved = use_signal(|| false);
+    let mut submitting = use_signal(|| false);
+
+    let onsubmit = move |event: FormEvent| {
+        event.stop_propagation();
+        if *submitting.read() {
+            return;
+        }
+        submitting.set(true);
+        saved.set(false);
+        errors.set(FieldErrors::default());
+
+        let details = AccountDetails {
+            first_name: first_name.read().clone(),
+            last_name: last_name.read().clone(),
+            display_name: display_name.read().clone(),
+            email: email.read().clone(),
+            password: password.read().clone(),
+        };
+        spawn(async move {
+            match CustomerController::new().update_details(&details).await {
+                Ok(_) => {
+                    saved.set(true);
+                    password.set(String::new());
+                }
+                Err(e) => errors.set(FieldErrors::from_error(&e, FIELDS)),
+            }
+            submitting.set(false);
+        });
+    };
+
+    let field_errors = errors.read();
+
+    rsx! {
+        div {
+            class: "max-w-md",
+            h2 {
+                class: "text-2xl font-bold mb-4",
+                "Account details"
+            }
+
+            form {
+                class: "space-y-4",
+                onsubmit,
+
+                for message in &field_errors.form {
+                    p {
+                        class: "text-red-600",
+                        role: "alert",
+                        "{message}"
+                    }
+                }
+                if *saved.read() {
+                    p {
+                        class: "text-green-700",
+                        role: "status",
+                        "Your account details were saved."
+                    }
+                }
+
+                FormField {
+                    id: "first_name",
+                    label: "First name",
+                    value: first_name,
+                    autocomplete: "given-name",
+                    error: field_errors.field("first_name"),
+                }
+                FormField {
+                    id: "last_name",
+                    label: "Last name",
+                    value: last_name,
+                    autocomplete: "family-name",
+                    error: field_errors.field("last_name"),
+                }
+                FormField {
+                    id: "display_name",
+                    label: "Display name",
+                    value: display_name,
+                    autocomplete: "nickname",
+                    error: field_errors.field("display_name"),
+                }
+                FormField {
+                    id: "email",
+                    label: "Email address",
+                    value: email,
+                    input_type: "email",
+                    autocomplete: "email",
+                    required: true,
+                    error: field_errors.field("email"),
+                }
+                FormField {
+                    id: "password",
+                    label: "New password (leave blank to keep your current password)",
+                    value: password,
+                    input_type: "password",
+                    autocomplete: "new-password",
+                    error: field_errors.field("password"),
+                }
+
+                button {
+                    r#type: "submit",
+                    class: "w-full bg-blue-700 hover:bg-blue-800 text-white font-medium rounded-lg px-4 py-3 disabled:opacity-50",
+                    disabled: *submitting.read(),
+                    if *submitting.read() { "Saving..." } else { "Save details" }
+                }
+            }
+        }
+    }
+}
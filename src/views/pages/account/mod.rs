@@ -0,0 +1,8 @@
+/// # Account Pages Module
+///
+/// This module contains the pages of the signed-in customer's account area.
+pub mod addresses;
+pub mod dashboard;
+pub mod details;
+pub mod order;
+pub mod orders;
@@ -0,0 +1,49 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+
+// Modules
+use crate::{
+    app::error::AppError,
+    controllers::{entity::EntityController, order::OrderController},
+    models::order::Order,
+    routes::Routes,
+    views::components::{account::order_details::OrderDetails, common::loader::LoaderComponent},
+};
+
+/// Account order page component
+#[component]
+pub fn AccountOrderPage(order_id: String) -> Element {
+    // Fetch the order
+    let id_for_resource: String = order_id.clone();
+    let order_resource: Resource<Result<Order, AppError>> = use_resource(move || {
+        let id_for_async_operation: String = id_for_resource.clone();
+        async move {
+            OrderController::new()
+                .get_by_slug(&id_for_async_operation)
+                .await
+        }
+    });
+
+    // Wait for order data
+    let order_data = order_resource.read();
+    match order_data.as_ref() {
+        // Loading state
+        None => rsx! { LoaderComponent {} },
+        // Error state
+        Some(Err(app_error)) => {
+            app_error.render(vec!["account".to_string(), "orders".to_string(), order_id])
+        }
+        // Order found
+        Some(Ok(order)) => rsx! {
+            div {
+                class: "space-y-8",
+                OrderDetails { order: order.clone() }
+                Link {
+                    class: "text-blue-700 hover:underline",
+                    to: Routes::AccountOrdersPage {},
+                    "Back to orders"
+                }
+            }
+        },
+    }
+}
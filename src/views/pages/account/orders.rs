@@ -0,0 +1,128 @@
+use dioxus::{hooks::UseResourceState, prelude::*};
+use dioxus_router::prelude::Link;
+
+// Modules
+use crate::{
+    controllers::{entity::EntityController, order::OrderController},
+    models::{order::Order, pagination::Pagination},
+    routes::Routes,
+    views::components::common::loader::LoaderComponent,
+};
+
+/// Account orders page component
+#[component]
+pub fn AccountOrdersPage() -> Element {
+    let mut all_orders = use_signal(Vec::<Order>::new);
+    let mut current_cursor = use_signal(|| None::<String>);
+    let mut page_info = use_signal(|| None::<Pagination>);
+
+    let mut orders_resource = use_resource(move || {
+        let current_cursor_cloned = current_cursor.read().clone();
+        async move {
+            OrderController::new()
+                .get_list(Some(10), current_cursor_cloned)
+                .await
+        }
+    });
+
+    use_effect(move || {
+        if let Some(Ok(fetched_data)) = orders_resource.value().read().as_ref() {
+            if let Some(first_fetched) = fetched_data.orders.first() {
+                if !all_orders
+                    .read()
+                    .iter()
+                    .any(|order| order.id == first_fetched.id)
+                {
+                    all_orders.write().extend(fetched_data.orders.clone());
+                }
+            }
+            page_info.set(fetched_data.page_info.clone());
+        }
+    });
+
+    let handle_load_more = move |_| {
+        if let Some(pi) = page_info.read().as_ref() {
+            if pi.has_next_page {
+                if let Some(end_cursor) = pi.end_cursor.as_ref() {
+                    current_cursor.set(Some(end_cursor.clone()));
+                    orders_resource.restart();
+                }
+            }
+        }
+    };
+
+    let is_loading = matches!(*orders_resource.state().read(), UseResourceState::Pending);
+
+    rsx! {
+        div {
+            h2 {
+                class: "text-2xl font-bold mb-4",
+                "Orders"
+            }
+
+            if let Some(Err(app_error)) = orders_resource.value().read().as_ref() {
+                p {
+                    class: "text-red-600",
+                    role: "alert",
+                    "{app_error.public_message}"
+                }
+            } else if all_orders.read().is_empty() && !is_loading {
+                p { "You haven't placed any orders yet." }
+            }
+
+            if !all_orders.read().is_empty() {
+                table {
+                    class: "w-full text-left",
+                    thead {
+                        tr {
+                            class: "border-b",
+                            th { class: "py-2", "Order" }
+                            th { class: "py-2", "Date" }
+                            th { class: "py-2", "Status" }
+                            th { class: "py-2", "Total" }
+                        }
+                    }
+                    tbody {
+                        for order in all_orders.read().iter() {
+                            tr {
+                                class: "border-b",
+                                td {
+                                    class: "py-2",
+                                    if let Some(database_id) = order.database_id {
+                                        Link {
+                                            class: "text-blue-700 hover:underline",
+                                            to: Routes::AccountOrderPage { order_id: database_id.to_string() },
+                                            "#{order.number()}"
+                                        }
+                                    } else {
+                                        "#{order.number()}"
+                                    }
+                                }
+                                td { class: "py-2", {order.date.clone().unwrap_or_default()} }
+                                td { class: "py-2", "{order.status_label()}" }
+                                td { class: "py-2", {order.total.clone().unwrap_or_default()} }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if is_loading {
+                div { class: "flex justify-center items-center py-8",
+                    LoaderComponent {}
+                }
+            }
+            if let Some(pi) = page_info.read().as_ref() {
+                if pi.has_next_page && !is_loading {
+                    div { class: "text-center mt-8",
+                        button {
+                            class: "px-8 py-3 bg-indigo-600 text-white font-semibold rounded-lg shadow-md hover:bg-indigo-700",
+                            onclick: handle_load_more,
+                            "Load More Orders"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,11 +1,20 @@
 use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
 
 // Modules
-use crate::{app::state::STATE, views::components::cart::item::CartItem};
+use crate::{
+    app::{cart::use_cart, state::STATE},
+    routes::Routes,
+    views::components::cart::{
+        coupons::Coupons, item::CartItem, removed::RemovedLines, shipping::ShippingRates,
+        totals::Totals,
+    },
+};
 
 /// Cart page component
 #[component]
 pub fn CartPage() -> Element {
+    let cart_service = use_cart();
     let state = STATE.read();
     let cart = &state.cart;
 
@@ -17,15 +26,39 @@ pub fn CartPage() -> Element {
                 "Your Cart"
             }
 
-            if cart.items.is_empty() {
+            RemovedLines {}
+
+            if cart.lines.is_empty() {
                 p { "Your cart is empty." }
             } else {
                 div {
                     class: "grid grid-cols-1 md:grid-cols-3 gap-8",
                     div {
                         class: "md:col-span-2",
-                        for item in &cart.items {
-                            CartItem { item: item.clone() }
+                        for line in &cart.lines {
+                            CartItem { key: "{line.key}", line: line.clone() }
+                        }
+                        div {
+                            class: "flex items-center justify-end space-x-4 mt-4",
+                            if let Some(error) = cart_service.error() {
+                                p {
+                                    class: "text-red-600",
+                                    role: "alert",
+                                    "{error}"
+                                }
+                            }
+                            button {
+                                r#type: "button",
+                                class: "text-blue-700 hover:underline disabled:opacity-50",
+                                disabled: cart_service.is_loading(),
+                                onclick: move |_| {
+                                    // The service keeps the error, which is shown beside the button
+                                    spawn(async move {
+                                        let _ = cart_service.empty().await;
+                                    });
+                                },
+                                if cart_service.is_loading() { "Emptying..." } else { "Empty cart" }
+                            }
                         }
                     }
                     div {
@@ -34,15 +67,24 @@ pub fn CartPage() -> Element {
                             class: "text-2xl font-bold mb-4",
                             "Summary"
                         }
-                        div {
-                            class: "flex justify-between mb-2",
-                            span { "Subtotal" }
-                            span { "{cart.subtotal}" }
+                        Totals {
+                            totals: cart.totals.clone(),
+                            show_shipping: cart.needs_shipping_address,
+                            if cart.needs_shipping_address {
+                                div {
+                                    class: "my-4",
+                                    ShippingRates {}
+                                }
+                            }
                         }
                         div {
-                            class: "flex justify-between font-bold text-xl",
-                            span { "Total" }
-                            span { "{cart.total}" }
+                            class: "mt-8",
+                            Coupons {}
+                        }
+                        Link {
+                            class: "block mt-8 w-full bg-blue-700 hover:bg-blue-800 text-white text-center font-medium rounded-lg px-4 py-3",
+                            to: Routes::CheckoutPage {},
+                            "Proceed to checkout"
                         }
                     }
                 }
@@ -0,0 +1,480 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::{navigator, Link};
+
+// Modules
+use crate::{
+    app::{
+        cart::use_cart,
+        error::{ErrorField, FieldErrors},
+        state::{CUSTOMER, STATE},
+    },
+    controllers::checkout::CheckoutController,
+    models::{address::Address, checkout::CheckoutDetails},
+    payments::{render_description, PaymentData, PaymentFields, PaymentProviders},
+    routes::Routes,
+    views::components::{
+        account::address_form::{AddressForm, AddressSignals},
+        cart::totals::Totals,
+        common::loader::LoaderComponent,
+    },
+};
+
+/// The checkout form fields, with the codes and message keywords of the errors about them
+///
+/// WooCommerce codes each error `<field>_required` or `<field>_validation`, and
+/// names the field in its message, e.g. "Billing Postcode / ZIP is not a valid
+/// postcode / ZIP."
+const FIELDS: &[ErrorField] = &[
+    ErrorField::new(
+        "billing_first_name",
+        &[
+            "billing_first_name_required",
+            "billing_first_name_validation",
+        ],
+        &["billing first name"],
+    ),
+    ErrorField::new(
+        "billing_last_name",
+        &["billing_last_name_required", "billing_last_name_validation"],
+        &["billing last name"],
+    ),
+    ErrorField::new(
+        "billing_company",
+        &["billing_company_required", "billing_company_validation"],
+        &["billing company"],
+    ),
+    ErrorField::new(
+        "billing_address_1",
+        &["billing_address_1_required", "billing_address_1_validation"],
+        &["billing street address"],
+    ),
+    ErrorField::new(
+        "billing_city",
+        &["billing_city_required", "billing_city_validation"],
+        &["billing town", "billing city", "billing suburb"],
+    ),
+    ErrorField::new(
+        "billing_state",
+        &["billing_state_required", "billing_state_validation"],
+        &["billing state", "billing county"],
+    ),
+    ErrorField::new(
+        "billing_postcode",
+        &["billing_postcode_required", "billing_postcode_validation"],
+        &["billing postcode", "billing zip"],
+    ),
+    ErrorField::new(
+        "billing_country",
+        &["billing_country_required", "billing_country_validation"],
+        &["billing country"],
+    ),
+    ErrorField::new(
+        "billing_email",
+        &["billing_email_required", "billing_email_validation"],
+        &["billing email"],
+    ),
+    ErrorField::new(
+        "billing_phone",
+        &["billing_phone_required", "billing_phone_validation"],
+        &["billing phone"],
+    ),
+    ErrorField::new(
+        "shipping_first_name",
+        &[
+            "shipping_first_name_required",
+            "shipping_first_name_validation",
+        ],
+        &["shipping first name"],
+    ),
+    ErrorField::new(
+        "shipping_last_name",
+        &[
+            "shipping_last_name_required",
+            "shipping_last_name_validation",
+        ],
+        &["shipping last name"],
+    ),
+    ErrorField::new(
+        "shipping_company",
+        &["shipping_company_required", "shipping_company_validation"],
+        &["shipping company"],
+    ),
+    ErrorField::new(
+        "shipping_address_1",
+        &[
+            "shipping_address_1_required",
+            "shipping_address_1_validation",
+        ],
+        &["shipping street address"],
+    ),
+    ErrorField::new(
+        "shipping_city",
+        &["shipping_city_required", "shipping_city_validation"],
+        &["shipping town", "shipping city", "shipping suburb"],
+    ),
+    ErrorField::new(
+        "shipping_state",
+        &["shipping_state_required", "shipping_state_validation"],
+        &["shipping state", "shipping county"],
+    ),
+    ErrorField::new(
+        "shipping_postcode",
+        &["shipping_postcode_required", "shipping_postcode_validation"],
+        &["shipping postcode", "shipping zip"],
+    ),
+    ErrorField::new(
+        "shipping_country",
+        &["shipping_country_required", "shipping_country_validation"],
+        &["shipping country"],
+    ),
+    ErrorField::new("shipping_method", &["shipping"], &["shipping method"]),
+    ErrorField::new("payment_method", &["payment"], &["payment method"]),
+];
+
+/// Checkout page component
+///
+/// Payments are taken by the provider of the chosen gateway, from the
+/// `PaymentProviders` context or the default providers.
+#[component]
+pub fn CheckoutPage() -> Element {
+    let providers: PaymentProviders = try_use_context().unwrap_or_default();
+
+    // Fill the addresses in from the signed-in customer's account
+    let customer = CUSTOMER.read().clone();
+    let billing = AddressSignals::use_address(
+        customer
+            .as_ref()
+            .map(|customer| Address {
+                email: customer.email.clone().unwrap_or_default(),
+                ..customer.billing.clone().unwrap_or_default()
+            })
+            .unwrap_or_default(),
+    );
+    let shipping = AddressSignals::use_address(
+        customer
+            .and_then(|customer| customer.shipping)
+            .unwrap_or_default(),
+    );
+    let mut ship_to_different_address = use_signal(|| false);
+    let mut shipping_methods = use_signal(Vec::<String>::new);
+    let mut payment_method = use_signal(String::new);
+    let mut payment_fields = use_signal(PaymentFields::new);
+    let mut customer_note = use_signal(String::new);
+    let mut errors = use_signal(FieldErrors::default);
+    let mut submitting = use_signal(|| false);
+    let cart = use_cart();
+
+    // Fetch the shipping rates, payment gateways and totals
+    let mut options_resource =
+        use_resource(|| async { CheckoutController::new().get_options().await });
+
+    // Start from the cart's chosen rates and the first payment gateway
+    use_effect(move || {
+        if let Some(Ok(options)) = options_resource.read().as_ref() {
+            shipping_methods.set(options.chosen_shipping_methods.clone());
+            if payment_method.peek().is_empty() {
+                if let Some(gateway) = options.payment_gateways.first() {
+                    payment_method.set(gateway.id.clone());
+                }
+            }
+        }
+    });
+
+    // Choose a shipping rate, which changes the totals
+    let mut choose_rate = move |package: usize, rate_id: String| {
+        let mut methods: Vec<String> = shipping_methods.read().clone();
+        methods.resize(methods.len().max(package + 1), String::new());
+        methods[package] = rate_id;
+        shipping_methods.set(methods.clone());
+
+        spawn(async move {
+            match cart.select_shipping_method(methods).await {
+                Ok(()) => options_resource.restart(),
+                Err(e) => errors.set(FieldErrors::from_error(&e, FIELDS)),
+            }
+        });
+    };
+
+    let submit_providers: PaymentProviders = providers.clone();
+    let onsubmit = move |event: FormEvent| {
+        event.stop_propagation();
+        if *submitting.read() {
+            return;
+        }
+        submitting.set(true);
+        errors.set(FieldErrors::default());
+
+        let gateway = options_resource
+            .read()
+            .as_ref()
+            .and_then(|options| options.as_ref().ok())
+            .and_then(|options| {
+                options
+                    .payment_gateways
+                    .iter()
+                    .find(|gateway| gateway.id == *payment_method.read())
+                    .cloned()
+            })
+            .unwrap_or_default();
+        let provider = submit_providers.get(&gateway.id);
+        let fields: PaymentFields = payment_fields.read().clone();
+        let mut details = CheckoutDetails {
+            billing: billing.address(),
+            shipping: ship_to_different_address.read().then(|| shipping.address()),
+            shipping_methods: shipping_methods.read().clone(),
+            payment: PaymentData::unpaid(&gateway),
+            customer_note: customer_note.read().clone(),
+        };
+        spawn(async move {
+            // Take the payment first, gateways without a provider are paid after checkout
+            if let Some(provider) = provider {
+                match provider.tokenize(&gateway, &fields).await {
+                    Ok(payment) => details.payment = payment,
+                    Err(e) => {
+                        errors.set(FieldErrors::for_field("payment_method", &e));
+                        submitting.set(false);
+                        return;
+                    }
+                }
+            }
+
+            match CheckoutController::new().checkout(&details).await {
+                Ok(order) => {
+                    navigator().push(Routes::OrderReceivedPage {
+                        order_id: order.database_id.unwrap_or_default().to_string(),
+                    });
+                }
+                Err(e) => errors.set(FieldErrors::from_error(&e, FIELDS)),
+            }
+            submitting.set(false);
+        });
+    };
+
+    // Nothing to check out
+    let state = STATE.read();
+    if state.cart.lines.is_empty() {
+        return rsx! {
+            div {
+                class: "container mx-auto p-4",
+                h1 {
+                    class: "text-3xl font-bold mb-8",
+                    "Checkout"
+                }
+                p {
+                    "Your cart is empty. "
+                    Link {
+                        class: "text-blue-700 hover:underline",
+                        to: Routes::HomePage {},
+                        "Continue shopping"
+                    }
+                }
+            }
+        };
+    }
+
+    let options = options_resource.read();
+    let field_errors = errors.read();
+
+    rsx! {
+        div {
+            class: "container mx-auto p-4",
+            h1 {
+                class: "text-3xl font-bold mb-8",
+                "Checkout"
+            }
+
+            form {
+                class: "grid grid-cols-1 md:grid-cols-3 gap-8",
+                onsubmit,
+
+                div {
+                    class: "md:col-span-2 space-y-8",
+
+                    for message in &field_errors.form {
+                        p {
+                            class: "text-red-600",
+                            role: "alert",
+                            "{message}"
+                        }
+                    }
+
+                    // Addresses
+                    AddressForm {
+                        prefix: "billing",
+                        legend: "Billing details",
+                        address: billing,
+                        with_contact: true,
+                        errors: field_errors.clone(),
+                    }
+                    label {
+                        class: "flex items-center space-x-2",
+                        input {
+                            r#type: "checkbox",
+                            checked: *ship_to_different_address.read(),
+                            onchange: move |event| ship_to_different_address.set(event.checked()),
+                        }
+                        span { "Ship to a different address" }
+                    }
+                    if *ship_to_different_address.read() {
+                        AddressForm {
+                            prefix: "shipping",
+                            legend: "Shipping address",
+                            address: shipping,
+                            errors: field_errors.clone(),
+                        }
+                    }
+
+                    match options.as_ref() {
+                        None => rsx! { LoaderComponent {} },
+                        Some(Err(app_error)) => rsx! {
+                            p {
+                                class: "text-red-600",
+                                role: "alert",
+                                "{app_error.public_message}"
+                            }
+                        },
+                        Some(Ok(options)) => rsx! {
+                            // Shipping methods
+                            if options.needs_shipping_address {
+                                fieldset {
+                                    class: "space-y-2",
+                                    aria_describedby: field_errors.field("shipping_method").map(|_| "shipping_method-error"),
+                                    legend {
+                                        class: "text-xl font-bold mb-4",
+                                        "Shipping method"
+                                    }
+                                    if options.shipping_packages.iter().all(|package| package.rates.is_empty()) {
+                                        p { "There are no shipping options for your address." }
+                                    }
+                                    for (package, shipping_package) in options.shipping_packages.iter().enumerate() {
+                                        if let Some(details) = &shipping_package.details {
+                                            p { class: "text-gray-600", "{details}" }
+                                        }
+                                        for rate in shipping_package.rates.iter() {
+                                            label {
+                                                class: "flex items-center space-x-2",
+                                                input {
+                                                    r#type: "radio",
+                                                    name: "shipping_method_{package}",
+                                                    checked: shipping_methods.read().get(package) == Some(&rate.id),
+                                                    onchange: {
+                                                        let rate_id: String = rate.id.clone();
+                                                        move |_| choose_rate(package, rate_id.clone())
+                                                    },
+                                                }
+                                                span { {rate.label.clone().unwrap_or_default()} }
+                                                span { class: "text-gray-600", {rate.cost.clone().unwrap_or_default()} }
+                                            }
+                                        }
+                                    }
+                                    if let Some(error) = field_errors.field("shipping_method") {
+                                        p {
+                                            id: "shipping_method-error",
+                                            class: "text-red-600",
+                                            "{error}"
+                                        }
+                                    }
+                                }
+                            }
+
+                            // Payment methods
+                            fieldset {
+                                class: "space-y-2",
+                                aria_describedby: field_errors.field("payment_method").map(|_| "payment_method-error"),
+                                legend {
+                                    class: "text-xl font-bold mb-4",
+                                    "Payment method"
+                                }
+                                if options.payment_gateways.is_empty() {
+                                    p { "This store doesn't accept payments online." }
+                                }
+                                for gateway in options.payment_gateways.iter() {
+                                    label {
+                                        class: "block",
+                                        span {
+                                            class: "flex items-center space-x-2",
+                                            input {
+                                                r#type: "radio",
+                                                name: "payment_method",
+                                                checked: *payment_method.read() == gateway.id,
+                                                onchange: {
+                                                    let gateway_id: String = gateway.id.clone();
+                                                    move |_| {
+                                                        payment_method.set(gateway_id.clone());
+                                                        payment_fields.set(PaymentFields::new());
+                                                    }
+                                                },
+                                            }
+                                            span { {gateway.title.clone().unwrap_or_else(|| gateway.id.clone())} }
+                                        }
+                                    }
+                                    if *payment_method.read() == gateway.id {
+                                        div {
+                                            class: "ml-6 mt-2",
+                                            match providers.get(&gateway.id) {
+                                                Some(provider) => provider.render(gateway, payment_fields),
+                                                None => render_description(gateway),
+                                            }
+                                        }
+                                    }
+                                }
+                                if let Some(error) = field_errors.field("payment_method") {
+                                    p {
+                                        id: "payment_method-error",
+                                        class: "text-red-600",
+                                        "{error}"
+                                    }
+                                }
+                            }
+                        },
+                    }
+
+                    // Order notes
+                    div {
+                        label {
+                            class: "block mb-2 font-medium",
+                            r#for: "customer_note",
+                            "Order notes (optional)"
+                        }
+                        textarea {
+                            id: "customer_note",
+                            class: "block w-full p-3 border border-gray-300 rounded-lg",
+                            rows: 4,
+                            value: "{customer_note}",
+                            oninput: move |event| customer_note.set(event.value()),
+                        }
+                    }
+                }
+
+                // Order summary
+                div {
+                    class: "bg-gray-100 p-8 h-fit space-y-4",
+                    h2 {
+                        class: "text-2xl font-bold mb-4",
+                        "Your order"
+                    }
+                    for line in state.cart.lines.iter() {
+                        div {
+                            class: "flex justify-between",
+                            span { "{line.name} × {line.quantity}" }
+                            span { "{line.total}" }
+                        }
+                    }
+                    if let Some(Ok(options)) = options.as_ref() {
+                        Totals {
+                            totals: options.totals.clone(),
+                            show_shipping: options.needs_shipping_address,
+                        }
+                    }
+
+                    button {
+                        r#type: "submit",
+                        class: "w-full bg-blue-700 hover:bg-blue-800 text-white font-medium rounded-lg px-4 py-3 disabled:opacity-50",
+                        disabled: *submitting.read(),
+                        if *submitting.read() { "Placing your order..." } else { "Place order" }
+                    }
+                }
+            }
+        }
+    }
+}
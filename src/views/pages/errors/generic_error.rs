@@ -21,6 +21,7 @@ pub fn GenericErrorPage(
     message: String,
     route: Option<Vec<String>>,
 ) -> Element {
+    let advice: Option<&str> = kind.advice();
     let kind: String = kind.to_string();
     let route: Option<String> = route.as_ref().map(|r: &Vec<String>| r.join(" / "));
 
@@ -54,6 +55,12 @@ pub fn GenericErrorPage(
                         class: "mt-2 text-center text-sm text-gray-600",
                         "{message}"
                     }
+                    if let Some(advice) = advice {
+                        p {
+                            class: "mt-2 text-center text-sm text-gray-600",
+                            "{advice}"
+                        }
+                    }
                     if let Some(route) = route {
                         p {
                             class: "mt-2 text-xs text-gray-500",
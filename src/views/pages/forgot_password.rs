@@ -0,0 +1,109 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+
+// Modules
+use crate::{
+    app::error::{ErrorField, FieldErrors},
+    controllers::auth::AuthController,
+    routes::Routes,
+    views::components::common::form::FormField,
+};
+
+/// The forgot password form fields, with the codes and message keywords of the errors about them
+const FIELDS: &[ErrorField] = &[ErrorField::new(
+    "username",
+    &["empty_username", "invalid_email", "invalidcombo"],
+    &["username", "email", "user"],
+)];
+
+/// Forgot password page component
+#[component]
+pub fn ForgotPasswordPage() -> Element {
+    let username = use_signal(String::new);
+    let mut errors = use_signal(FieldErrors::default);
+    let mut submitting = use_signal(|| false);
+    let mut sent = use_signal(|| false);
+
+    let onsubmit = move |event: FormEvent| {
+        event.stop_propagation();
+        if *submitting.read() {
+            return;
+        }
+        submitting.set(true);
+        errors.set(FieldErrors::default());
+
+        let username: String = username.read().trim().to_string();
+        spawn(async move {
+            match AuthController::new()
+                .send_password_reset_email(username)
+                .await
+            {
+                Ok(()) => sent.set(true),
+                Err(e) => errors.set(FieldErrors::from_error(&e, FIELDS)),
+            }
+            submitting.set(false);
+        });
+    };
+
+    let field_errors = errors.read();
+
+    rsx! {
+        div {
+            class: "container mx-auto p-4 max-w-md",
+            h1 {
+                class: "text-3xl font-bold mb-8",
+                "Forgot your password?"
+            }
+
+            if *sent.read() {
+                p {
+                    role: "status",
+                    "We've emailed a link to reset your password to the address on your account. It may take a few minutes to arrive."
+                }
+            } else {
+                p {
+                    class: "mb-4",
+                    "Enter your username or email address, and we'll email you a link to reset your password."
+                }
+
+                form {
+                    class: "space-y-4",
+                    onsubmit,
+
+                    for message in &field_errors.form {
+                        p {
+                            class: "text-red-600",
+                            role: "alert",
+                            "{message}"
+                        }
+                    }
+
+                    FormField {
+                        id: "username",
+                        label: "Username or email address",
+                        value: username,
+                        autocomplete: "username",
+                        required: true,
+                        error: field_errors.field("username"),
+                    }
+
+                    button {
+                        r#type: "submit",
+                        class: "w-full bg-blue-700 hover:bg-blue-800 text-white font-medium rounded-lg px-4 py-3 disabled:opacity-50",
+                        disabled: *submitting.read(),
+                        if *submitting.read() { "Sending..." } else { "Email me a reset link" }
+                    }
+                }
+            }
+
+            p {
+                class: "mt-4",
+                Link {
+                    class: "text-blue-700 hover:underline",
+                    to: Routes::LoginPage {},
+                    "Back to sign in"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::{navigator, Link};
+
+// Modules
+use crate::{
+    app::{cart::use_cart, error::AppErrorKind, state::CUSTOMER},
+    controllers::auth::AuthController,
+    routes::Routes,
+    views::components::common::form::FormField,
+};
+
+/// Login page component
+#[component]
+pub fn LoginPage() -> Element {
+    let username = use_signal(String::new);
+    let mut password = use_signal(String::new);
+    let mut error = use_signal(|| None::<String>);
+    let mut submitting = use_signal(|| false);
+    let cart = use_cart();
+
+    // Already signed in
+    if let Some(customer) = &*CUSTOMER.read() {
+        return rsx! {
+            div {
+                class: "container mx-auto p-4 max-w-md",
+                h1 {
+                    class: "text-3xl font-bold mb-8",
+                    "Sign in"
+                }
+                p { "You are signed in as {customer.name()}." }
+            }
+        };
+    }
+
+    let onsubmit = move |event: FormEvent| {
+        event.stop_propagation();
+        if *submitting.read() {
+            return;
+        }
+        submitting.set(true);
+        error.set(None);
+
+        let username: String = username.read().trim().to_string();
+        let entered_password: String = password.read().clone();
+        spawn(async move {
+            let auth_controller = AuthController::new();
+            match auth_controller.login(username, entered_password).await {
+                Ok(_) => {
+                    // The customer's session has its own cart, so refetch it
+                    if let Err(e) = cart.refresh().await {
+                        tracing::error!("Error refetching cart: {}", e);
+                    }
+
+                    navigator().push(Routes::HomePage {});
+                }
+                Err(e) => {
+                    let message: String = match e.kind {
+                        AppErrorKind::Unauthorized | AppErrorKind::Validation => {
+                            "The username or password is incorrect.".to_string()
+                        }
+                        _ => e
+                            .kind
+                            .advice()
+                            .map(str::to_string)
+                            .unwrap_or(e.public_message),
+                    };
+                    error.set(Some(message));
+                    password.set(String::new());
+                }
+            }
+            submitting.set(false);
+        });
+    };
+
+    rsx! {
+        div {
+            class: "container mx-auto p-4 max-w-md",
+            h1 {
+                class: "text-3xl font-bold mb-8",
+                "Sign in"
+            }
+
+            form {
+                class: "space-y-4",
+                onsubmit,
+
+                if let Some(message) = &*error.read() {
+                    p {
+                        class: "text-red-600",
+                        role: "alert",
+                        "{message}"
+                    }
+                }
+
+                FormField {
+                    id: "username",
+                    label: "Username or email address",
+                    value: username,
+                    autocomplete: "username",
+                    required: true,
+                }
+                FormField {
+                    id: "password",
+                    label: "Password",
+                    value: password,
+                    input_type: "password",
+                    autocomplete: "current-password",
+                    required: true,
+                }
+
+                button {
+                    r#type: "submit",
+                    class: "w-full bg-blue-700 hover:bg-blue-800 text-white font-medium rounded-lg px-4 py-3 disabled:opacity-50",
+                    disabled: *submitting.read(),
+                    if *submitting.read() { "Signing in..." } else { "Sign in" }
+                }
+            }
+
+            p {
+                class: "mt-4",
+                Link {
+                    class: "text-blue-700 hover:underline",
+                    to: Routes::ForgotPasswordPage {},
+                    "Forgot your password?"
+                }
+            }
+            p {
+                class: "mt-2",
+                "New here? "
+                Link {
+                    class: "text-blue-700 hover:underline",
+                    to: Routes::RegisterPage {},
+                    "Create an account"
+                }
+            }
+        }
+    }
+}
@@ -1,11 +1,18 @@
+pub mod account;
 pub mod cart;
 pub mod categories;
+pub mod checkout;
 pub mod category;
 pub mod errors;
+pub mod forgot_password;
 pub mod home;
 pub mod list;
+pub mod login;
+pub mod order_received;
 pub mod page;
 pub mod post;
 pub mod posts;
 pub mod product;
+pub mod register;
+pub mod reset_password;
 pub mod search;
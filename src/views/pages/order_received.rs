@@ -0,0 +1,51 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+
+// Modules
+use crate::{
+    app::state::{CUSTOMER, PLACED_ORDER},
+    models::order::Order,
+    routes::Routes,
+    views::components::account::order_details::OrderDetails,
+};
+
+/// Order received page component
+///
+/// Shows the order placed at checkout. After a reload only the order number is
+/// known, so signed-in customers are linked to the order in their account.
+#[component]
+pub fn OrderReceivedPage(order_id: String) -> Element {
+    let order: Option<Order> = PLACED_ORDER.read().clone().filter(|order| {
+        order.database_id.map(|id| id.to_string()).as_deref() == Some(order_id.as_str())
+    });
+    let signed_in: bool = CUSTOMER.read().is_some();
+
+    rsx! {
+        div {
+            class: "container mx-auto p-4 space-y-8",
+            h1 {
+                class: "text-3xl font-bold",
+                "Order received"
+            }
+            p { "Thank you. Your order has been received." }
+
+            if let Some(order) = order {
+                OrderDetails { order }
+            } else if signed_in {
+                Link {
+                    class: "text-blue-700 hover:underline",
+                    to: Routes::AccountOrderPage { order_id: order_id.clone() },
+                    "View order #{order_id}"
+                }
+            } else {
+                p { "Your order number is #{order_id}." }
+            }
+
+            Link {
+                class: "text-blue-700 hover:underline",
+                to: Routes::HomePage {},
+                "Continue shopping"
+            }
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::{navigator, Link};
+
+// Modules
+use crate::{
+    app::{
+        error::{ErrorField, FieldErrors},
+        state::CUSTOMER,
+    },
+    controllers::auth::AuthController,
+    models::{address::Address, customer::Registration},
+    routes::Routes,
+    views::components::common::form::FormField,
+};
+
+/// The registration form fields, with the codes and message keywords of the errors about them
+///
+/// WooCommerce reports username errors too, as it generates usernames from the
+/// email address.
+const FIELDS: &[ErrorField] = &[
+    ErrorField::new(
+        "email",
+        &[
+            "registration-error-invalid-email",
+            "registration-error-email-exists",
+            "registration-error-invalid-username",
+            "registration-error-username-exists",
+            "existing_user_email",
+            "existing_user_login",
+        ],
+        &["email", "username"],
+    ),
+    ErrorField::new(
+        "password",
+        &["registration-error-missing-password"],
+        &["password"],
+    ),
+    ErrorField::new("first_name", &[], &["first name", "firstname"]),
+    ErrorField::new("last_name", &[], &["last name", "lastname"]),
+    ErrorField::new(
+        "billing_address_1",
+        &[],
+        &["address1", "address 1", "street"],
+    ),
+    ErrorField::new("billing_city", &[], &["city", "suburb", "town"]),
+    ErrorField::new("billing_postcode", &[], &["postcode", "zip"]),
+    ErrorField::new("billing_country", &[], &["country"]),
+    ErrorField::new("billing_phone", &[], &["phone"]),
+];
+
+/// Registration page component
+#[component]
+pub fn RegisterPage() -> Element {
+    let email = use_signal(String::new);
+    let password = use_signal(String::new);
+    let first_name = use_signal(String::new);
+    let last_name = use_signal(String::new);
+    let mut with_billing = use_signal(|| false);
+    let company = use_signal(String::new);
+    let address_1 = use_signal(String::new);
+    let address_2 = use_signal(String::new);
+    let city = use_signal(String::new);
+    let state = use_signal(String::new);
+    let postcode = use_signal(String::new);
+    let country = use_signal(String::new);
+    let phone = use_signal(String::new);
+    let mut errors = use_signal(FieldErrors::default);
+    let mut submitting = use_signal(|| false);
+
+    // Already signed in
+    if let Some(customer) = &*CUSTOMER.read() {
+        return rsx! {
+            div {
+                class: "container mx-auto p-4 max-w-md",
+                h1 {
+                    class: "text-3xl font-bold mb-8",
+                    "Create an account"
+                }
+                p { "You are signed in as {customer.name()}." }
+            }
+        };
+    }
+
+    let onsubmit = move |event: FormEvent| {
+        event.stop_propagation();
+        if *submitting.read() {
+            return;
+        }
+        submitting.set(true);
+        errors.set(FieldErrors::default());
+
+        let billing: Option<Address> = with_billing.read().then(|| Address {
+            first_name: first_name.read().clone(),
+            last_name: last_name.read().clone(),
+            company: company.read().clone(),
+            address_1: address_1.read().clone(),
+            address_2: address_2.read().clone(),
+            city: city.read().clone(),
+            state: state.read().clone(),
+            postcode: postcode.read().clone(),
+            country: country.read().clone(),
+            email: email.read().clone(),
+            phone: phone.read().clone(),
+        });
+        let registration = Registration {
+            email: email.read().clone(),
+            password: password.read().clone(),
+            first_name: first_name.read().clone(),
+            last_name: last_name.read().clone(),
+            billing,
+        };
+
+        spawn(async move {
+            match AuthController::new().register(&registration).await {
+                Ok(_) => {
+                    navigator().push(Routes::HomePage {});
+                }
+                Err(e) => errors.set(FieldErrors::from_error(&e, FIELDS)),
+            }
+            submitting.set(false);
+        });
+    };
+
+    let field_errors = errors.read();
+
+    rsx! {
+        div {
+            class: "container mx-auto p-4 max-w-md",
+            h1 {
+                class: "text-3xl font-bold mb-8",
+                "Create an account"
+            }
+
+            form {
+                class: "space-y-4",
+                onsubmit,
+
+                for message in &field_errors.form {
+                    p {
+                        class: "text-red-600",
+                        role: "alert",
+                        "{message}"
+                    }
+                }
+
+                FormField {
+                    id: "first_name",
+                    label: "First name",
+                    value: first_name,
+                    autocomplete: "given-name",
+                    error: field_errors.field("first_name"),
+                }
+                FormField {
+                    id: "last_name",
+                    label: "Last name",
+                    value: last_name,
+                    autocomplete: "family-name",
+                    error: field_errors.field("last_name"),
+                }
+                FormField {
+                    id: "email",
+                    label: "Email address",
+                    value: email,
+                    input_type: "email",
+                    autocomplete: "email",
+                    required: true,
+                    error: field_errors.field("email"),
+                }
+                FormField {
+                    id: "password",
+                    label: "Password",
+                    value: password,
+                    input_type: "password",
+                    autocomplete: "new-password",
+                    required: true,
+                    error: field_errors.field("password"),
+                }
+
+                // Optional billing details
+                label {
+                    class: "flex items-center space-x-2",
+                    input {
+                        r#type: "checkbox",
+                        checked: *with_billing.read(),
+                        onchange: move |event| with_billing.set(event.checked()),
+                    }
+                    span { "Add billing details" }
+                }
+                if *with_billing.read() {
+                    fieldset {
+                        class: "space-y-4",
+                        legend {
+                            class: "text-xl font-bold mb-4",
+                            "Billing details"
+                        }
+                        FormField {
+                            id: "billing_company",
+                            label: "Company",
+                            value: company,
+                            autocomplete: "organization",
+                        }
+                        FormField {
+                            id: "billing_address_1",
+                            label: "Street address",
+                            value: address_1,
+                            autocomplete: "address-line1",
+                            error: field_errors.field("billing_address_1"),
+                        }
+                        FormField {
+                            id: "billing_address_2",
+                            label: "Apartment, suite, unit, etc.",
+                            value: address_2,
+                            autocomplete: "address-line2",
+                        }
+                        FormField {
+                            id: "billing_city",
+                            label: "City",
+                            value: city,
+                            autocomplete: "address-level2",
+                            error: field_errors.field("billing_city"),
+                        }
+                        FormField {
+                            id: "billing_state",
+                            label: "State",
+                            value: state,
+                            autocomplete: "address-level1",
+                        }
+                        FormField {
+                            id: "billing_postcode",
+                            label: "Postcode",
+                            value: postcode,
+                            autocomplete: "postal-code",
+                            error: field_errors.field("billing_postcode"),
+                        }
+                        FormField {
+                            id: "billing_country",
+                            label: "Country code",
+                            value: country,
+                            autocomplete: "country",
+                            error: field_errors.field("billing_country"),
+                        }
+                        FormField {
+                            id: "billing_phone",
+                            label: "Phone",
+                            value: phone,
+                            input_type: "tel",
+                            autocomplete: "tel",
+                            error: field_errors.field("billing_phone"),
+                        }
+                    }
+                }
+
+                button {
+                    r#type: "submit",
+                    class: "w-full bg-blue-700 hover:bg-blue-800 text-white font-medium rounded-lg px-4 py-3 disabled:opacity-50",
+                    disabled: *submitting.read(),
+                    if *submitting.read() { "Creating your account..." } else { "Create account" }
+                }
+            }
+
+            p {
+                class: "mt-4",
+                "Already have an account? "
+                Link {
+                    class: "text-blue-700 hover:underline",
+                    to: Routes::LoginPage {},
+                    "Sign in"
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::Link;
+
+// Modules
+use crate::{
+    app::error::{ErrorField, FieldErrors},
+    controllers::auth::AuthController,
+    models::customer::PasswordResetLink,
+    routes::Routes,
+    views::components::common::form::FormField,
+};
+
+/// The reset password form fields, with the codes and message keywords of the errors about them
+///
+/// The reset key is checked first, as its messages also mention the password.
+const FIELDS: &[ErrorField] = &[
+    ErrorField::new(
+        "key",
+        &["invalid_key", "expired_key"],
+        &["key", "link", "expired"],
+    ),
+    ErrorField::new(
+        "password",
+        &["password_reset_empty", "password_reset_mismatch"],
+        &["password"],
+    ),
+];
+
+/// Reset password page component
+///
+/// Opened from the link in the password reset email, which carries the reset
+/// key and the account's username.
+#[component]
+pub fn ResetPasswordPage(link: PasswordResetLink) -> Element {
+    let password = use_signal(String::new);
+    let confirm_password = use_signal(String::new);
+    let mut errors = use_signal(FieldErrors::default);
+    let mut submitting = use_signal(|| false);
+    let mut reset = use_signal(|| false);
+
+    // The link is incomplete, so it can't be used
+    if link.key.is_empty() || link.login.is_empty() {
+        return rsx! {
+            div {
+                class: "container mx-auto p-4 max-w-md",
+                h1 {
+                    class: "text-3xl font-bold mb-8",
+                    "Reset your password"
+                }
+                p {
+                    role: "alert",
+                    class: "text-red-600 mb-4",
+                    "This password reset link is invalid."
+                }
+                Link {
+                    class: "text-blue-700 hover:underline",
+                    to: Routes::ForgotPasswordPage {},
+                    "Request a new link"
+                }
+            }
+        };
+    }
+
+    let onsubmit = move |event: FormEvent| {
+        event.stop_propagation();
+        if *submitting.read() {
+            return;
+        }
+        if *password.read() != *confirm_password.read() {
+            let mut field_errors = FieldErrors::default();
+            field_errors
+                .fields
+                .insert("confirm_password", "The passwords don't match.".to_string());
+            errors.set(field_errors);
+            return;
+        }
+        submitting.set(true);
+        errors.set(FieldErrors::default());
+
+        let link: PasswordResetLink = link.clone();
+        let password: String = password.read().clone();
+        spawn(async move {
+            match AuthController::new()
+                .reset_password(link.key, link.login, password)
+                .await
+            {
+                Ok(()) => reset.set(true),
+                Err(e) => errors.set(FieldErrors::from_error(&e, FIELDS)),
+            }
+            submitting.set(false);
+        });
+    };
+
+    let field_errors = errors.read();
+
+    rsx! {
+        div {
+            class: "container mx-auto p-4 max-w-md",
+            h1 {
+                class: "text-3xl font-bold mb-8",
+                "Reset your password"
+            }
+
+            if *reset.read() {
+                p {
+                    role: "status",
+                    class: "mb-4",
+                    "Your password has been reset."
+                }
+                Link {
+                    class: "text-blue-700 hover:underline",
+                    to: Routes::LoginPage {},
+                    "Sign in with your new password"
+                }
+            } else {
+                form {
+                    class: "space-y-4",
+                    onsubmit,
+
+                    if let Some(message) = field_errors.field("key") {
+                        div {
+                            role: "alert",
+                            class: "text-red-600",
+                            p { "{message}" }
+                            Link {
+                                class: "text-blue-700 hover:underline",
+                                to: Routes::ForgotPasswordPage {},
+                                "Request a new link"
+                            }
+                        }
+                    }
+                    for message in &field_errors.form {
+                        p {
+                            class: "text-red-600",
+                            role: "alert",
+                            "{message}"
+                        }
+                    }
+
+                    FormField {
+                        id: "password",
+                        label: "New password",
+                        value: password,
+                        input_type: "password",
+                        autocomplete: "new-password",
+                        required: true,
+                        error: field_errors.field("password"),
+                    }
+                    FormField {
+                        id: "confirm_password",
+                        label: "Confirm new password",
+                        value: confirm_password,
+                        input_type: "password",
+                        autocomplete: "new-password",
+                        required: true,
+                        error: field_errors.field("confirm_password"),
+                    }
+
+                    button {
+                        r#type: "submit",
+                        class: "w-full bg-blue-700 hover:bg-blue-800 text-white font-medium rounded-lg px-4 py-3 disabled:opacity-50",
+                        disabled: *submitting.read(),
+                        if *submitting.read() { "Resetting..." } else { "Reset password" }
+                    }
+                }
+            }
+        }
+    }
+}